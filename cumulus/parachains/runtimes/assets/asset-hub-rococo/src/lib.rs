@@ -116,7 +116,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 1_008_000,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 14,
+	transaction_version: 15,
 	state_version: 1,
 };
 
@@ -129,7 +129,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 1_008_000,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 14,
+	transaction_version: 15,
 	state_version: 0,
 };
 
@@ -283,6 +283,12 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub const LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	pub const MaxPoolFee: Permill = Permill::from_percent(10);
+	pub const FeeSlippageWarnThreshold: Permill = Permill::from_percent(5);
+	pub const MinAssetFee: Balance = 0;
+	pub const OnBelowMinAssetFee: pallet_asset_conversion_tx_payment::MinAssetFeePolicy =
+		pallet_asset_conversion_tx_payment::MinAssetFeePolicy::ChargeMinimum;
+	pub const MaxFeeSwapsPerBlock: u32 = 500;
 }
 
 ord_parameter_types! {
@@ -354,9 +360,15 @@ impl pallet_asset_conversion::Config for Runtime {
 	type PoolSetupFeeTarget = ResolveAssetTo<AssetConversionOrigin, Self::Assets>;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type LPFee = ConstU32<3>;
+	type MaxPoolFee = MaxPoolFee;
 	type PalletId = AssetConversionPalletId;
+	type MaxLpSupplyOrigin = EnsureRoot<AccountId>;
+	type PoolFeeOrigin = EnsureRoot<AccountId>;
 	type MaxSwapPathLength = ConstU32<3>;
 	type MintMinLiquidity = ConstU128<100>;
+	type TrackPoolVolume = ConstBool<false>;
+	type TrackPriceMoves = ConstBool<false>;
+	type OnPriceMove = ();
 	type WeightInfo = weights::pallet_asset_conversion::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = assets_common::benchmarks::AssetPairFactory<
@@ -763,6 +775,11 @@ impl pallet_asset_conversion_tx_payment::Config for Runtime {
 	type Fungibles = LocalAndForeignAssets;
 	type OnChargeAssetTransaction =
 		AssetConversionAdapter<Balances, AssetConversion, TokenLocationV3>;
+	type SlippageWarnThreshold = FeeSlippageWarnThreshold;
+	type MinAssetFee = MinAssetFee;
+	type OnBelowMinAssetFee = OnBelowMinAssetFee;
+	type AssetFeeAllowedFor = frame_support::traits::Everything;
+	type MaxFeeSwapsPerBlock = MaxFeeSwapsPerBlock;
 	type WeightInfo = weights::pallet_asset_conversion_tx_payment::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = AssetConversionTxHelper;
@@ -1236,6 +1253,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -1251,6 +1269,38 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: xcm::v3::Location, asset2: xcm::v3::Location) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(asset1, asset2).ok()
 		}
+
+		fn pool_summaries(start_after: Option<(xcm::v3::Location, xcm::v3::Location)>, limit: u32) -> Vec<pallet_asset_conversion::PoolSummary<xcm::v3::Location, Balance>> {
+			AssetConversion::pool_summaries(start_after, limit)
+		}
+
+		fn acceptable_fee_assets(start_after: Option<xcm::v3::Location>, limit: u32) -> Vec<xcm::v3::Location> {
+			AssetConversion::acceptable_fee_assets(TokenLocationV3::get(), ExistentialDeposit::get(), start_after, limit)
+		}
+
+		fn pool_exists(asset1: xcm::v3::Location, asset2: xcm::v3::Location) -> bool {
+			AssetConversion::pool_exists(asset1, asset2)
+		}
+	}
+
+	impl pallet_asset_conversion_tx_payment::AssetConversionTxPaymentApi<
+		Block,
+		Balance,
+		Balance,
+		xcm::v3::Location,
+	> for Runtime
+	{
+		fn query_fee_in(
+			call_len: u32,
+			info: frame_support::dispatch::DispatchInfo,
+			asset: Option<xcm::v3::Location>,
+		) -> pallet_asset_conversion_tx_payment::FeeQuote<Balance, Balance> {
+			AssetTxPayment::query_fee_in(call_len, &info, asset)
+		}
+
+		fn asset_fee_capabilities() -> pallet_asset_conversion_tx_payment::AssetFeeCapabilities {
+			AssetTxPayment::asset_fee_capabilities()
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
@@ -79,6 +79,10 @@ impl<T: frame_system::Config> pallet_asset_conversion_tx_payment::WeightInfo for
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetTxPayment::CollectedAssetFees` (r:1 w:1)
+	/// Proof: `AssetTxPayment::CollectedAssetFees` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `AssetTxPayment::FeeSwapsInBlock` (r:1 w:1)
+	/// Proof: `AssetTxPayment::FeeSwapsInBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn charge_asset_tx_payment_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `631`
@@ -86,7 +90,7 @@ impl<T: frame_system::Config> pallet_asset_conversion_tx_payment::WeightInfo for
 		// Minimum execution time: 1_211_000_000 picoseconds.
 		Weight::from_parts(1_243_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 }
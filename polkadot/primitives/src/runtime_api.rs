@@ -118,13 +118,15 @@ use crate::{
 	vstaging::{self, ApprovalVotingParams},
 	AsyncBackingParams, BlockNumber, CandidateCommitments, CandidateEvent, CandidateHash,
 	CommittedCandidateReceipt, CoreState, DisputeState, ExecutorParams, GroupRotationInfo, Hash,
-	OccupiedCoreAssumption, PersistedValidationData, PvfCheckStatement, ScrapedOnChainVotes,
-	SessionIndex, SessionInfo, ValidatorId, ValidatorIndex, ValidatorSignature,
+	InherentData, OccupiedCoreAssumption, PersistedValidationData, PvfCheckStatement,
+	ScrapedOnChainVotes, SessionIndex, SessionInfo, ValidatorId, ValidatorIndex,
+	ValidatorSignature,
 };
 
 use polkadot_core_primitives as pcp;
 use polkadot_parachain_primitives::primitives as ppp;
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+use sp_weights::Weight;
 
 sp_api::decl_runtime_apis! {
 	/// The API for querying the state of parachains on-chain.
@@ -281,5 +283,24 @@ sp_api::decl_runtime_apis! {
 		/// Approval voting configuration parameters
 		#[api_version(10)]
 		fn approval_voting_params() -> ApprovalVotingParams;
+
+		/***** Added in v11 *****/
+		/// The maximum weight, with its proof size adjusted to the block length, and the maximum
+		/// encoded length the runtime accepts for the parachains inherent.
+		#[api_version(11)]
+		fn inherent_weight_limits() -> (Weight, u32);
+
+		/***** Added in v12 *****/
+		/// Returns the disabled validators at the given block, along with why each was disabled.
+		/// The reason is `None` for validators whose disabling was not noted by the runtime.
+		#[api_version(12)]
+		fn disabled_validators_with_reasons(
+		) -> Vec<(ValidatorIndex, Option<vstaging::DisablingReason>)>;
+
+		/***** Added in v13 *****/
+		/// The weight of the disputes, bitfields and backed candidates, in that order, of the given
+		/// parachains inherent data, e.g. the one applied in a block.
+		#[api_version(13)]
+		fn inherent_weight_breakdown(inherent: InherentData) -> (Weight, Weight, Weight);
 	}
 }
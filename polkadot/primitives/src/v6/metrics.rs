@@ -154,7 +154,9 @@ pub mod metric_definitions {
 	};
 
 	/// Counts the `total`, `sanitized` and `included` number of parachain block candidates
-	/// in `process_inherent_data`.
+	/// in `process_inherent_data`, as well as the scheduled cores left without a candidate by the
+	/// weight limit (`weight_filtered`) and the candidates dropped from paras of which only some
+	/// candidates were accepted (`trimmed`).
 	pub const PARACHAIN_INHERENT_DATA_CANDIDATES_PROCESSED: CounterVecDefinition =
 		CounterVecDefinition {
 			name: "polkadot_parachain_inherent_data_candidates_processed",
@@ -175,7 +177,9 @@ pub mod metric_definitions {
 		};
 
 	/// Counts the number of `valid` and `invalid` bitfields signature checked in
-	/// `process_inherent_data`.
+	/// `process_inherent_data`. Signatures made for the parent of the expected parent block are
+	/// counted as `stale_parent_hash` instead of `invalid`, those of validators outside the active
+	/// set as `unknown_validator`.
 	pub const PARACHAIN_CREATE_INHERENT_BITFIELDS_SIGNATURE_CHECKS: CounterVecDefinition =
 		CounterVecDefinition {
 			name: "polkadot_parachain_create_inherent_bitfields_signature_checks",
@@ -126,6 +126,18 @@ impl<BlockNumber: Default + From<u32>> Default for SchedulerParams<BlockNumber>
 	}
 }
 
+/// Why a validator was disabled, as recorded by the parachains runtime at disabling time.
+#[derive(RuntimeDebug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum DisablingReason {
+	/// The validator backed a candidate which was then disputed and concluded invalid.
+	DisputeConcludedAgainst,
+	/// The validator was reported for an offence outside of parachain consensus, e.g. an
+	/// equivocation, and disabled by the session pallet.
+	Offence,
+	/// The validator was disabled by governance.
+	Governance,
+}
+
 use bitvec::vec::BitVec;
 
 /// Bit indices in the `HostConfiguration.node_features` that correspond to different node features.
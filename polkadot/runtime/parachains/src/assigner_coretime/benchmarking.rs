@@ -0,0 +1,64 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Coretime assigner pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::{Pallet, *};
+use crate::configuration::{HostConfiguration, Pallet as ConfigurationPallet};
+
+use frame_benchmarking::v2::*;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn force_add_assignment() -> Result<(), BenchmarkError> {
+		// Worst case: a pool assignment whose para already has an affinity, onto a core with one
+		// forced assignment short of the maximum.
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let core = CoreIndex(0);
+		let para_id = ParaId::from(111u32);
+		let mut config = HostConfiguration::default();
+		config.scheduler_params.num_cores = 1;
+		ConfigurationPallet::<T>::force_set_active_config(config);
+
+		let max = T::MaxForcedAssignments::get();
+		if max == 0 {
+			return Err(BenchmarkError::Weightless)
+		}
+		let queue = vec![Assignment::Bulk(para_id); (max - 1) as usize];
+		ForcedAssignments::<T>::insert(core, BoundedVec::truncate_from(queue));
+		assigner_on_demand::Pallet::<T>::increase_affinity(para_id, core);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, core, Assignment::Pool { para_id, core_index: core });
+
+		assert_eq!(ForcedAssignments::<T>::get(core).len() as u32, max);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(
+			crate::assigner_coretime::mock_helpers::GenesisConfigBuilder::default().build()
+		),
+		crate::mock::Test
+	);
+}
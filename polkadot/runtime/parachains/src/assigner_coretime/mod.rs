@@ -23,6 +23,7 @@
 //! `CoreDescriptor` contains pointers to the begin and the end of a list of schedules, together
 //! with the currently active assignments.
 
+mod benchmarking;
 mod mock_helpers;
 #[cfg(test)]
 mod tests;
@@ -44,6 +45,19 @@ use sp_std::prelude::*;
 
 pub use pallet::*;
 
+pub trait WeightInfo {
+	fn force_add_assignment() -> Weight;
+}
+
+/// A weight info that is only suitable for testing.
+pub struct TestWeightInfo;
+
+impl WeightInfo for TestWeightInfo {
+	fn force_add_assignment() -> Weight {
+		Weight::MAX
+	}
+}
+
 /// Fraction expressed as a nominator with an assumed denominator of 57,600.
 #[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, TypeInfo)]
 pub struct PartsOf57600(u16);
@@ -205,6 +219,18 @@ pub mod pallet {
 	pub trait Config:
 		frame_system::Config + configuration::Config + assigner_on_demand::Config
 	{
+		/// The runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to force assignments onto a core, bypassing the coretime schedule.
+		type ForceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The maximum number of forced assignments queued on a single core.
+		#[pallet::constant]
+		type MaxForcedAssignments: Get<u32>;
+
+		/// Something that provides the weight of this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Scheduled assignment sets.
@@ -234,9 +260,29 @@ pub mod pallet {
 		GetDefault,
 	>;
 
+	/// Assignments forced onto a core via `force_add_assignment`.
+	///
+	/// These are served in order before any assignment from the core's schedule. Dropped when the
+	/// core is removed at a session boundary.
+	#[pallet::storage]
+	pub(super) type ForcedAssignments<T: Config> = StorageMap<
+		_,
+		Twox256,
+		CoreIndex,
+		BoundedVec<Assignment, T::MaxForcedAssignments>,
+		ValueQuery,
+	>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An assignment was forced onto a core.
+		AssignmentForced { core: CoreIndex },
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		AssignmentsEmpty,
@@ -251,11 +297,82 @@ pub mod pallet {
 		DuplicateInsert,
 		/// Tried to add an unsorted set of assignments
 		AssignmentsNotSorted,
+		/// The core index is not below the number of cores of the current session.
+		CoreOutOfBounds,
+		/// A pool assignment was forced onto a core other than the one it names, or onto a core
+		/// other than the one its para currently has an affinity to.
+		AffinityMismatch,
+		/// The core already has `MaxForcedAssignments` forced assignments queued.
+		TooManyForcedAssignments,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Force an assignment onto a core, to be served before the core's regular schedule.
+		///
+		/// Intended for staging networks and recovery. Pool assignments must name `core` and
+		/// respect any affinity their para already has to another core.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `ForceOrigin`.
+		/// - `core`: The core to serve the assignment on.
+		/// - `assignment`: The assignment to serve.
+		///
+		/// Errors:
+		/// - `CoreOutOfBounds`
+		/// - `AffinityMismatch`
+		/// - `TooManyForcedAssignments`
+		///
+		/// Events:
+		/// - `AssignmentForced`
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::force_add_assignment())]
+		pub fn force_add_assignment(
+			origin: OriginFor<T>,
+			core: CoreIndex,
+			assignment: Assignment,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(core.0 < Self::session_core_count(), Error::<T>::CoreOutOfBounds);
+
+			if let Assignment::Pool { para_id, core_index } = assignment {
+				ensure!(core_index == core, Error::<T>::AffinityMismatch);
+				if let Some(affinity) = assigner_on_demand::Pallet::<T>::get_affinity_map(para_id) {
+					ensure!(affinity.core_idx == core, Error::<T>::AffinityMismatch);
+				}
+			}
+
+			ForcedAssignments::<T>::try_mutate(core, |queue| {
+				queue
+					.try_push(assignment.clone())
+					.map_err(|_| Error::<T>::TooManyForcedAssignments)
+			})?;
+			if let Assignment::Pool { para_id, .. } = assignment {
+				// Account for the assignment just like the on-demand assigner does for orders it
+				// hands out, so it gets released again once processed.
+				assigner_on_demand::Pallet::<T>::increase_affinity(para_id, core);
+			}
+			Self::deposit_event(Event::<T>::AssignmentForced { core });
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> AssignmentProvider<BlockNumberFor<T>> for Pallet<T> {
 	fn pop_assignment_for_core(core_idx: CoreIndex) -> Option<Assignment> {
+		// Only a single read for the common case of no forced assignments.
+		let mut forced = ForcedAssignments::<T>::get(core_idx);
+		if !forced.is_empty() {
+			let assignment = forced.remove(0);
+			if forced.is_empty() {
+				ForcedAssignments::<T>::remove(core_idx);
+			} else {
+				ForcedAssignments::<T>::insert(core_idx, forced);
+			}
+			return Some(assignment)
+		}
+
 		let now = <frame_system::Pallet<T>>::block_number();
 
 		CoreDescriptors::<T>::mutate(core_idx, |core_state| {
@@ -327,6 +444,16 @@ impl<T: Config> AssignmentProvider<BlockNumberFor<T>> for Pallet<T> {
 		let config = <configuration::Pallet<T>>::config();
 		config.scheduler_params.num_cores
 	}
+
+	/// Drop the forced assignments of the removed cores, releasing the affinity of their pool
+	/// assignments.
+	fn prune_removed_cores(from: u32, to: u32) {
+		for core_idx in to..from {
+			for assignment in ForcedAssignments::<T>::take(CoreIndex(core_idx)) {
+				Self::report_processed(assignment);
+			}
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -17,7 +17,11 @@
 use super::*;
 
 use crate::{
-	assigner_coretime::{mock_helpers::GenesisConfigBuilder, pallet::Error, Schedule},
+	assigner_coretime::{
+		mock_helpers::GenesisConfigBuilder,
+		pallet::{Error, Event},
+		Schedule,
+	},
 	initializer::SessionChangeNotification,
 	mock::{
 		new_test_ext, Balances, CoretimeAssigner, OnDemandAssigner, Paras, ParasShared,
@@ -574,6 +578,148 @@ fn pop_assignment_for_core_works() {
 	});
 }
 
+#[test]
+fn force_add_assignment_is_served_before_schedule() {
+	let core_idx = CoreIndex(0);
+	let forced_para = ParaId::from(1);
+	let scheduled_para = ParaId::from(2);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		run_to_block(1, |n| if n == 1 { Some(Default::default()) } else { None });
+
+		assert_ok!(CoretimeAssigner::assign_core(
+			core_idx,
+			BlockNumberFor::<Test>::from(11u32),
+			vec![(CoreAssignment::Task(scheduled_para.into()), PartsOf57600::FULL)],
+			None,
+		));
+
+		run_to_block(11, |n| if n == 11 { Some(Default::default()) } else { None });
+
+		assert_ok!(CoretimeAssigner::force_add_assignment(
+			RuntimeOrigin::root(),
+			core_idx,
+			Assignment::Bulk(forced_para),
+		));
+		System::assert_last_event(Event::<Test>::AssignmentForced { core: core_idx }.into());
+
+		// The forced assignment goes first, then the regular schedule resumes.
+		assert_eq!(
+			CoretimeAssigner::pop_assignment_for_core(core_idx),
+			Some(Assignment::Bulk(forced_para))
+		);
+		assert_eq!(
+			CoretimeAssigner::pop_assignment_for_core(core_idx),
+			Some(Assignment::Bulk(scheduled_para))
+		);
+		assert!(!ForcedAssignments::<Test>::contains_key(core_idx));
+	});
+}
+
+#[test]
+fn force_add_assignment_checks() {
+	let para_id = ParaId::from(1);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		run_to_block(1, |n| if n == 1 { Some(Default::default()) } else { None });
+
+		// Only `ForceOrigin` may force assignments.
+		assert_noop!(
+			CoretimeAssigner::force_add_assignment(
+				RuntimeOrigin::signed(1),
+				CoreIndex(0),
+				Assignment::Bulk(para_id),
+			),
+			DispatchError::BadOrigin
+		);
+
+		// The core needs to exist.
+		let num_cores = GenesisConfigBuilder::default().on_demand_cores;
+		assert_noop!(
+			CoretimeAssigner::force_add_assignment(
+				RuntimeOrigin::root(),
+				CoreIndex(num_cores),
+				Assignment::Bulk(para_id),
+			),
+			Error::<Test>::CoreOutOfBounds
+		);
+
+		// Pool assignments must target the core they name.
+		assert_noop!(
+			CoretimeAssigner::force_add_assignment(
+				RuntimeOrigin::root(),
+				CoreIndex(0),
+				Assignment::Pool { para_id, core_index: CoreIndex(1) },
+			),
+			Error::<Test>::AffinityMismatch
+		);
+
+		// A forced pool assignment establishes an affinity, which later ones must respect.
+		assert_ok!(CoretimeAssigner::force_add_assignment(
+			RuntimeOrigin::root(),
+			CoreIndex(1),
+			Assignment::Pool { para_id, core_index: CoreIndex(1) },
+		));
+		assert_eq!(
+			OnDemandAssigner::get_affinity_map(para_id).map(|a| (a.core_idx, a.count)),
+			Some((CoreIndex(1), 1))
+		);
+		assert_noop!(
+			CoretimeAssigner::force_add_assignment(
+				RuntimeOrigin::root(),
+				CoreIndex(0),
+				Assignment::Pool { para_id, core_index: CoreIndex(0) },
+			),
+			Error::<Test>::AffinityMismatch
+		);
+
+		// At most `MaxForcedAssignments` may be queued on a core.
+		assert_ok!(CoretimeAssigner::force_add_assignment(
+			RuntimeOrigin::root(),
+			CoreIndex(1),
+			Assignment::Bulk(para_id),
+		));
+		assert_noop!(
+			CoretimeAssigner::force_add_assignment(
+				RuntimeOrigin::root(),
+				CoreIndex(1),
+				Assignment::Pool { para_id, core_index: CoreIndex(1) },
+			),
+			Error::<Test>::TooManyForcedAssignments
+		);
+	});
+}
+
+#[test]
+fn forced_assignments_of_removed_cores_are_pruned() {
+	let para_id = ParaId::from(1);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		run_to_block(1, |n| if n == 1 { Some(Default::default()) } else { None });
+
+		assert_ok!(CoretimeAssigner::force_add_assignment(
+			RuntimeOrigin::root(),
+			CoreIndex(0),
+			Assignment::Bulk(para_id),
+		));
+		assert_ok!(CoretimeAssigner::force_add_assignment(
+			RuntimeOrigin::root(),
+			CoreIndex(1),
+			Assignment::Pool { para_id, core_index: CoreIndex(1) },
+		));
+
+		// Shrinking to a single core drops the forced assignments of core 1 and releases the
+		// affinity of its pool assignment, while those of core 0 stay.
+		CoretimeAssigner::prune_removed_cores(2, 1);
+		assert!(!ForcedAssignments::<Test>::contains_key(CoreIndex(1)));
+		assert!(OnDemandAssigner::get_affinity_map(para_id).is_none());
+		assert_eq!(
+			CoretimeAssigner::pop_assignment_for_core(CoreIndex(0)),
+			Some(Assignment::Bulk(para_id))
+		);
+	});
+}
+
 #[test]
 fn assignment_proportions_in_core_state_work() {
 	let core_idx = CoreIndex(0);
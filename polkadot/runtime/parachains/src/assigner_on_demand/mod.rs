@@ -78,8 +78,8 @@ impl WeightInfo for TestWeightInfo {
 #[derive(Encode, Decode, Default, Clone, Copy, TypeInfo)]
 #[cfg_attr(test, derive(PartialEq, RuntimeDebug))]
 pub struct CoreAffinityCount {
-	core_idx: CoreIndex,
-	count: u32,
+	pub(crate) core_idx: CoreIndex,
+	pub(crate) count: u32,
 }
 
 /// An indicator as to which end of the `OnDemandQueue` an assignment will be placed.
@@ -515,7 +515,7 @@ where
 	/// Adds to the count of the `CoreAffinityCount` if an entry is found and the core_idx matches.
 	/// A non-existant entry will be initialized with a count of 1 and uses the  supplied
 	/// `CoreIndex`.
-	fn increase_affinity(para_id: ParaId, core_idx: CoreIndex) {
+	pub(crate) fn increase_affinity(para_id: ParaId, core_idx: CoreIndex) {
 		ParaIdAffinity::<T>::mutate(para_id, |maybe_affinity| match maybe_affinity {
 			Some(affinity) =>
 				if affinity.core_idx == core_idx {
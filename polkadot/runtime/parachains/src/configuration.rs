@@ -227,6 +227,49 @@ pub struct HostConfiguration<BlockNumber> {
 	pub approval_voting_params: ApprovalVotingParams,
 	/// Scheduler parameters
 	pub scheduler_params: SchedulerParams<BlockNumber>,
+	/// The maximum number of cores a single para may occupy at once.
+	///
+	/// `None` means no maximum.
+	pub max_cores_per_para: Option<u32>,
+	/// The maximum number of new disputes that may be initiated on chain per session.
+	///
+	/// Once reached, further disputes for that session are no longer included, while statements
+	/// for disputes already in progress still are. `None` means no maximum.
+	pub max_disputes_per_session: Option<u32>,
+	/// The maximum number of availability bitfields processed per block.
+	///
+	/// Valid bitfields beyond this are dropped, keeping those with the most availability bits
+	/// set. `None` means no maximum.
+	pub max_bitfields_per_block: Option<u32>,
+	/// The maximum number of unconcluded disputes a validator may vote against the candidate in,
+	/// per session.
+	///
+	/// Further votes against candidates by that validator are not included until one of its
+	/// disputes concludes. `None` means no maximum.
+	pub max_pending_disputes_per_validator: Option<u32>,
+	/// The maximum encoded size of a single backed candidate in the inherent data, in bytes.
+	///
+	/// Larger candidates are dropped before the candidates are trimmed to fit the block. `None`
+	/// means no maximum.
+	pub max_candidate_encoded_size: Option<u32>,
+	/// The number of signed bitfields kept ahead of disputes in each block.
+	///
+	/// Disputes may not use the weight of the first that many supplied bitfields, so that
+	/// availability keeps advancing in blocks with many disputes. Zero means no reservation.
+	pub min_reserved_bitfields: u32,
+	/// The number of dispute statement sets included in each block regardless of the weight
+	/// reserved for bitfields.
+	///
+	/// That many of the supplied disputes are included even at the expense of bitfields and
+	/// backed candidates, as long as they fit the block, so that disputes keep progressing under
+	/// heavy load. Zero means no minimum.
+	pub min_disputes_included: u32,
+	/// The maximum number of dispute statements included in a block, summed over all of its
+	/// disputes.
+	///
+	/// Disputes are taken in order of priority until the next one would exceed the limit, which
+	/// bounds the signature checks done for disputes in a single block. `None` means no limit.
+	pub max_dispute_statements_per_block: Option<u32>,
 }
 
 impl<BlockNumber: Default + From<u32>> Default for HostConfiguration<BlockNumber> {
@@ -270,6 +313,14 @@ impl<BlockNumber: Default + From<u32>> Default for HostConfiguration<BlockNumber
 			minimum_backing_votes: LEGACY_MIN_BACKING_VOTES,
 			node_features: NodeFeatures::EMPTY,
 			scheduler_params: Default::default(),
+			max_cores_per_para: None,
+			max_disputes_per_session: None,
+			max_bitfields_per_block: None,
+			max_pending_disputes_per_validator: None,
+			max_candidate_encoded_size: None,
+			min_reserved_bitfields: 0,
+			min_disputes_included: 0,
+			max_dispute_statements_per_block: None,
 		}
 	}
 }
@@ -483,7 +534,11 @@ pub mod pallet {
 	/// v9-v10: <https://github.com/paritytech/polkadot-sdk/pull/2177>
 	/// v10-11: <https://github.com/paritytech/polkadot-sdk/pull/1191>
 	/// v11-12: <https://github.com/paritytech/polkadot-sdk/pull/3181>
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(12);
+	/// v12-13: add the `max_cores_per_para`, `max_disputes_per_session`,
+	///         `max_bitfields_per_block`, `max_pending_disputes_per_validator`,
+	///         `max_candidate_encoded_size`, `min_reserved_bitfields`,
+	///         `min_disputes_included` and `max_dispute_statements_per_block` limits
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(13);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -1223,6 +1278,143 @@ pub mod pallet {
 				config.scheduler_params = new;
 			})
 		}
+
+		/// Set the scheduler lookahead, i.e. the depth of the claim queue.
+		///
+		/// Zero is rejected, as it would stall scheduling.
+		#[pallet::call_index(57)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_lookahead(origin: OriginFor<T>, lookahead: u32) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!lookahead.is_zero(), Error::<T>::InvalidNewValue);
+			Self::schedule_config_update(|config| {
+				config.scheduler_params.lookahead = lookahead;
+			})
+		}
+
+		/// Set the maximum number of cores a single para may occupy at once.
+		#[pallet::call_index(58)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_option_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_max_cores_per_para(origin: OriginFor<T>, new: Option<u32>) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::schedule_config_update(|config| {
+				config.max_cores_per_para = new;
+			})
+		}
+
+		/// Set the maximum number of new disputes that may be initiated on chain per session.
+		#[pallet::call_index(59)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_option_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_max_disputes_per_session(
+			origin: OriginFor<T>,
+			new: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::schedule_config_update(|config| {
+				config.max_disputes_per_session = new;
+			})
+		}
+
+		/// Set the maximum number of availability bitfields processed per block.
+		#[pallet::call_index(60)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_option_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_max_bitfields_per_block(
+			origin: OriginFor<T>,
+			new: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::schedule_config_update(|config| {
+				config.max_bitfields_per_block = new;
+			})
+		}
+
+		/// Set the maximum number of unconcluded disputes a validator may vote against the
+		/// candidate in, per session.
+		#[pallet::call_index(61)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_option_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_max_pending_disputes_per_validator(
+			origin: OriginFor<T>,
+			new: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::schedule_config_update(|config| {
+				config.max_pending_disputes_per_validator = new;
+			})
+		}
+
+		/// Set the maximum encoded size of a single backed candidate in the inherent data.
+		#[pallet::call_index(62)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_option_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_max_candidate_encoded_size(
+			origin: OriginFor<T>,
+			new: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::schedule_config_update(|config| {
+				config.max_candidate_encoded_size = new;
+			})
+		}
+
+		/// Set the number of signed bitfields kept ahead of disputes in each block.
+		#[pallet::call_index(63)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_min_reserved_bitfields(origin: OriginFor<T>, new: u32) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::schedule_config_update(|config| {
+				config.min_reserved_bitfields = new;
+			})
+		}
+
+		/// Set the number of dispute statement sets included in each block regardless of the
+		/// weight reserved for bitfields.
+		#[pallet::call_index(64)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_min_disputes_included(origin: OriginFor<T>, new: u32) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::schedule_config_update(|config| {
+				config.min_disputes_included = new;
+			})
+		}
+
+		/// Set the maximum number of dispute statements included in a block.
+		#[pallet::call_index(66)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_option_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_max_dispute_statements_per_block(
+			origin: OriginFor<T>,
+			new: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::schedule_config_update(|config| {
+				config.max_dispute_statements_per_block = new;
+			})
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -1307,6 +1499,15 @@ impl<T: Config> Pallet<T> {
 		SessionChangeOutcome { prev_config, new_config }
 	}
 
+	/// The minimum number of backing votes a candidate needs, as configured for the current
+	/// session.
+	///
+	/// Backing groups smaller than this only need all of their votes, see
+	/// [`primitives::effective_minimum_backing_votes`].
+	pub fn minimum_backing_votes() -> u32 {
+		ActiveConfig::<T>::get().minimum_backing_votes
+	}
+
 	/// Return the session index that should be used for any future scheduled changes.
 	fn scheduled_session() -> SessionIndex {
 		shared::Pallet::<T>::scheduled_session()
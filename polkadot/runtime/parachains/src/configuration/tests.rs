@@ -326,6 +326,14 @@ fn setting_pending_config_members() {
 				on_demand_target_queue_utilization: Perbill::from_percent(25),
 				ttl: 5u32,
 			},
+			max_cores_per_para: Some(2),
+			max_disputes_per_session: Some(30),
+			max_bitfields_per_block: Some(200),
+			max_pending_disputes_per_validator: Some(5),
+			max_candidate_encoded_size: Some(10_000),
+			min_reserved_bitfields: 3,
+			min_disputes_included: 2,
+			max_dispute_statements_per_block: Some(1_000),
 		};
 
 		Configuration::set_validation_upgrade_cooldown(
@@ -493,11 +501,97 @@ fn setting_pending_config_members() {
 		Configuration::set_node_feature(RuntimeOrigin::root(), 10, true).unwrap();
 		Configuration::set_node_feature(RuntimeOrigin::root(), 10, false).unwrap();
 		Configuration::set_node_feature(RuntimeOrigin::root(), 11, true).unwrap();
+		Configuration::set_max_cores_per_para(RuntimeOrigin::root(), new_config.max_cores_per_para)
+			.unwrap();
+		Configuration::set_max_disputes_per_session(
+			RuntimeOrigin::root(),
+			new_config.max_disputes_per_session,
+		)
+		.unwrap();
+		Configuration::set_max_bitfields_per_block(
+			RuntimeOrigin::root(),
+			new_config.max_bitfields_per_block,
+		)
+		.unwrap();
+		Configuration::set_max_pending_disputes_per_validator(
+			RuntimeOrigin::root(),
+			new_config.max_pending_disputes_per_validator,
+		)
+		.unwrap();
+		Configuration::set_max_candidate_encoded_size(
+			RuntimeOrigin::root(),
+			new_config.max_candidate_encoded_size,
+		)
+		.unwrap();
+		Configuration::set_min_reserved_bitfields(
+			RuntimeOrigin::root(),
+			new_config.min_reserved_bitfields,
+		)
+		.unwrap();
+		Configuration::set_min_disputes_included(
+			RuntimeOrigin::root(),
+			new_config.min_disputes_included,
+		)
+		.unwrap();
+		Configuration::set_max_dispute_statements_per_block(
+			RuntimeOrigin::root(),
+			new_config.max_dispute_statements_per_block,
+		)
+		.unwrap();
 
 		assert_eq!(PendingConfigs::<Test>::get(), vec![(shared::SESSION_DELAY, new_config)],);
 	})
 }
 
+#[test]
+fn set_lookahead_applies_from_scheduled_session() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let old_lookahead = Configuration::config().scheduler_params.lookahead;
+		let new_lookahead = old_lookahead + 1;
+
+		assert_noop!(
+			Configuration::set_lookahead(RuntimeOrigin::signed(1), new_lookahead),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Configuration::set_lookahead(RuntimeOrigin::root(), 0),
+			Error::<Test>::InvalidNewValue
+		);
+
+		assert_ok!(Configuration::set_lookahead(RuntimeOrigin::root(), new_lookahead));
+
+		// Nothing changes until the change is applied at the session boundary.
+		assert_eq!(Configuration::config().scheduler_params.lookahead, old_lookahead);
+
+		on_new_session(1);
+		assert_eq!(Configuration::config().scheduler_params.lookahead, old_lookahead);
+
+		on_new_session(2);
+		assert_eq!(Configuration::config().scheduler_params.lookahead, new_lookahead);
+		assert_eq!(PendingConfigs::<Test>::get(), vec![]);
+	});
+}
+
+#[test]
+fn minimum_backing_votes_applies_from_scheduled_session() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let old_minimum_backing_votes = Configuration::minimum_backing_votes();
+		assert_eq!(old_minimum_backing_votes, Configuration::config().minimum_backing_votes);
+
+		assert_ok!(Configuration::set_minimum_backing_votes(
+			RuntimeOrigin::root(),
+			old_minimum_backing_votes + 1
+		));
+		assert_eq!(Configuration::minimum_backing_votes(), old_minimum_backing_votes);
+
+		on_new_session(1);
+		assert_eq!(Configuration::minimum_backing_votes(), old_minimum_backing_votes);
+
+		on_new_session(2);
+		assert_eq!(Configuration::minimum_backing_votes(), old_minimum_backing_votes + 1);
+	});
+}
+
 #[test]
 fn non_root_cannot_set_config() {
 	new_test_ext(Default::default()).execute_with(|| {
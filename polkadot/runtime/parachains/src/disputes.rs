@@ -17,7 +17,8 @@
 //! Runtime component for handling disputes of parachain candidates.
 
 use crate::{
-	configuration, initializer::SessionChangeNotification, metrics::METRICS, session_info,
+	configuration, initializer::SessionChangeNotification, metrics::METRICS, scheduler,
+	session_info, shared,
 };
 use bitvec::{bitvec, order::Lsb0 as BitOrderLsb0};
 use frame_support::{ensure, weights::Weight};
@@ -28,15 +29,20 @@ use primitives::{
 	byzantine_threshold, supermajority_threshold, vstaging::ApprovalVoteMultipleCandidates,
 	ApprovalVote, CandidateHash, CheckedDisputeStatementSet, CheckedMultiDisputeStatementSet,
 	CompactStatement, ConsensusLog, DisputeState, DisputeStatement, DisputeStatementSet,
-	ExplicitDisputeStatement, InvalidDisputeStatementKind, MultiDisputeStatementSet, SessionIndex,
-	SigningContext, ValidDisputeStatementKind, ValidatorId, ValidatorIndex, ValidatorSignature,
+	ExplicitDisputeStatement, InvalidDisputeStatementKind, MultiDisputeStatementSet,
+	ScrapedOnChainVotes, SessionIndex, SigningContext, ValidDisputeStatementKind, ValidatorId,
+	ValidatorIndex, ValidatorSignature, ValidityAttestation,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AppVerify, One, Saturating, Zero},
 	DispatchError, RuntimeDebug, SaturatedConversion,
 };
-use sp_std::{cmp::Ordering, collections::btree_set::BTreeSet, prelude::*};
+use sp_std::{
+	cmp::Ordering,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	prelude::*,
+};
 
 #[cfg(test)]
 #[allow(unused_imports)]
@@ -67,6 +73,27 @@ pub enum DisputeResult {
 	Invalid,
 }
 
+/// Resolution latency of the disputes of a session which concluded so far.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DisputeLatencyStats {
+	/// The number of concluded disputes.
+	pub concluded: u32,
+	/// The sum of the blocks it took each of the concluded disputes to conclude.
+	pub total_blocks: u32,
+}
+
+/// How to order disputes which are otherwise equally prioritized, i.e. local disputes included at
+/// the same height or remote disputes of the same session.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DisputeTieBreak {
+	/// Order by candidate hash. Deterministic, but not tied to the progress of the dispute.
+	#[default]
+	ByCandidateHash,
+	/// Prefer disputes carrying more statements, as those are closer to a conclusion. Falls back
+	/// to the candidate hash for disputes with an equal number of statements.
+	ByStatementCount,
+}
+
 /// Reward hooks for disputes.
 pub trait RewardValidators {
 	// Give each validator a reward, likely small, for participating in the dispute.
@@ -80,6 +107,18 @@ impl RewardValidators for () {
 	fn reward_dispute_statement(_: SessionIndex, _: impl IntoIterator<Item = ValidatorIndex>) {}
 }
 
+/// Source of backing votes whose signatures were already verified on chain.
+pub trait VerifiedBackingVotes<Hash: Encode + Decode> {
+	/// The backing votes of the most recently backed candidates, if any.
+	fn verified_backing_votes() -> Option<ScrapedOnChainVotes<Hash>>;
+}
+
+impl<Hash: Encode + Decode> VerifiedBackingVotes<Hash> for () {
+	fn verified_backing_votes() -> Option<ScrapedOnChainVotes<Hash>> {
+		None
+	}
+}
+
 /// Punishment hooks for disputes.
 pub trait SlashingHandler<BlockNumber> {
 	/// Punish a series of validators who were for an invalid parablock. This is
@@ -140,13 +179,24 @@ impl<BlockNumber> SlashingHandler<BlockNumber> for () {
 /// following prioritization:
 ///  1. Prioritize local disputes over remote disputes
 ///  2. Prioritize older disputes over newer disputes
+///  3. Break ties according to `tie_break`
 fn dispute_ordering_compare<T: DisputesHandler<BlockNumber>, BlockNumber: Ord>(
 	a: &DisputeStatementSet,
 	b: &DisputeStatementSet,
+	tie_break: DisputeTieBreak,
 ) -> Ordering
 where
 	T: ?Sized,
 {
+	let tie_break_compare = || match tie_break {
+		DisputeTieBreak::ByCandidateHash => a.candidate_hash.cmp(&b.candidate_hash),
+		DisputeTieBreak::ByStatementCount => b
+			.statements
+			.len()
+			.cmp(&a.statements.len())
+			.then_with(|| a.candidate_hash.cmp(&b.candidate_hash)),
+	};
+
 	let a_local_block =
 		<T as DisputesHandler<BlockNumber>>::included_state(a.session, a.candidate_hash);
 	let b_local_block =
@@ -156,18 +206,9 @@ where
 		(None, Some(_)) => Ordering::Greater,
 		(Some(_), None) => Ordering::Less,
 		// For local disputes, prioritize those that occur at an earlier height.
-		(Some(a_height), Some(b_height)) =>
-			a_height.cmp(&b_height).then_with(|| a.candidate_hash.cmp(&b.candidate_hash)),
+		(Some(a_height), Some(b_height)) => a_height.cmp(&b_height).then_with(tie_break_compare),
 		// Prioritize earlier remote disputes using session as rough proxy.
-		(None, None) => {
-			let session_ord = a.session.cmp(&b.session);
-			if session_ord == Ordering::Equal {
-				// sort by hash as last resort, to make below dedup work consistently
-				a.candidate_hash.cmp(&b.candidate_hash)
-			} else {
-				session_ord
-			}
-		},
+		(None, None) => a.session.cmp(&b.session).then_with(tie_break_compare),
 	}
 }
 
@@ -180,6 +221,16 @@ pub trait DisputesHandler<BlockNumber: Ord> {
 	/// any new parachain blocks for backing or inclusion.
 	fn is_frozen() -> bool;
 
+	/// How to order disputes which are otherwise equally prioritized.
+	fn dispute_tie_break() -> DisputeTieBreak {
+		DisputeTieBreak::ByCandidateHash
+	}
+
+	/// The dispute to put ahead of all others in the current block, if any.
+	fn prioritized_dispute() -> Option<(SessionIndex, CandidateHash)> {
+		None
+	}
+
 	/// Remove dispute statement duplicates and sort the non-duplicates based on
 	/// local (lower indicies) vs remotes (higher indices) and age (older with lower indices).
 	///
@@ -195,10 +246,29 @@ pub trait DisputesHandler<BlockNumber: Ord> {
 		// TODO: https://github.com/paritytech/polkadot/issues/4527
 		let n = statement_sets.len();
 
-		statement_sets.sort_by(dispute_ordering_compare::<Self, BlockNumber>);
+		// Duplicates are only guaranteed to be adjacent when ordering by candidate hash, so
+		// dedup with that ordering first and re-sort afterwards if configured differently.
+		statement_sets.sort_by(|a, b| {
+			dispute_ordering_compare::<Self, BlockNumber>(a, b, DisputeTieBreak::ByCandidateHash)
+		});
 		statement_sets
 			.dedup_by(|a, b| a.session == b.session && a.candidate_hash == b.candidate_hash);
 
+		let tie_break = Self::dispute_tie_break();
+		if tie_break != DisputeTieBreak::ByCandidateHash {
+			statement_sets
+				.sort_by(|a, b| dispute_ordering_compare::<Self, BlockNumber>(a, b, tie_break));
+		}
+
+		if let Some((session, candidate_hash)) = Self::prioritized_dispute() {
+			if let Some(position) = statement_sets
+				.iter()
+				.position(|set| set.session == session && set.candidate_hash == candidate_hash)
+			{
+				statement_sets[..=position].rotate_right(1);
+			}
+		}
+
 		// if there were any duplicates, indicate that to the caller.
 		if n == statement_sets.len() {
 			Ok(())
@@ -207,6 +277,26 @@ pub trait DisputesHandler<BlockNumber: Ord> {
 		}
 	}
 
+	/// Remove statement sets which would initiate a new dispute in a session that already saw
+	/// `max_disputes_per_session` disputes initiated on chain.
+	///
+	/// Statement sets for disputes already known on chain are always retained.
+	fn limit_new_disputes_per_session(
+		_statement_sets: &mut MultiDisputeStatementSet,
+		_max_disputes_per_session: u32,
+	) {
+	}
+
+	/// Remove the statements against a candidate initiating a new dispute of validators who
+	/// already initiated `max_pending_disputes_per_validator` unconcluded disputes of the session.
+	///
+	/// Statements on disputes already known on chain are always retained.
+	fn limit_pending_disputes_per_validator(
+		_statement_sets: &mut MultiDisputeStatementSet,
+		_max_pending_disputes_per_validator: u32,
+	) {
+	}
+
 	/// Filter a single dispute statement set.
 	///
 	/// Used in cases where more granular control is required, i.e. when
@@ -306,6 +396,34 @@ where
 		pallet::Pallet::<T>::is_frozen()
 	}
 
+	fn dispute_tie_break() -> DisputeTieBreak {
+		T::TieBreak::get()
+	}
+
+	fn prioritized_dispute() -> Option<(SessionIndex, CandidateHash)> {
+		pallet::Pallet::<T>::prioritized_dispute()
+	}
+
+	fn limit_new_disputes_per_session(
+		statement_sets: &mut MultiDisputeStatementSet,
+		max_disputes_per_session: u32,
+	) {
+		pallet::Pallet::<T>::limit_new_disputes_per_session(
+			statement_sets,
+			max_disputes_per_session,
+		)
+	}
+
+	fn limit_pending_disputes_per_validator(
+		statement_sets: &mut MultiDisputeStatementSet,
+		max_pending_disputes_per_validator: u32,
+	) {
+		pallet::Pallet::<T>::limit_pending_disputes_per_validator(
+			statement_sets,
+			max_pending_disputes_per_validator,
+		)
+	}
+
 	fn filter_dispute_data(
 		set: DisputeStatementSet,
 		post_conclusion_acceptance_period: BlockNumberFor<T>,
@@ -354,6 +472,7 @@ where
 
 pub trait WeightInfo {
 	fn force_unfreeze() -> Weight;
+	fn force_prioritize_dispute() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -361,6 +480,9 @@ impl WeightInfo for TestWeightInfo {
 	fn force_unfreeze() -> Weight {
 		Weight::zero()
 	}
+	fn force_prioritize_dispute() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub use pallet::*;
@@ -375,6 +497,17 @@ pub mod pallet {
 		type RewardValidators: RewardValidators;
 		type SlashingHandler: SlashingHandler<BlockNumberFor<Self>>;
 
+		/// How to order disputes which are otherwise equally prioritized when selecting them for
+		/// inclusion. `()` orders them by candidate hash.
+		type TieBreak: Get<DisputeTieBreak>;
+
+		/// Backing votes already verified on chain. Backing statements in disputes matching one of
+		/// them exactly are not verified again. `()` verifies all statements.
+		type VerifiedBackingVotes: VerifiedBackingVotes<Self::Hash>;
+
+		/// The origin allowed to put a dispute ahead of all others in the next block.
+		type PrioritizeDisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -435,6 +568,56 @@ pub mod pallet {
 	#[pallet::getter(fn last_valid_block)]
 	pub(super) type Frozen<T: Config> = StorageValue<_, Option<BlockNumberFor<T>>, ValueQuery>;
 
+	/// Resolution latency of the concluded disputes, by the session of the disputed candidate.
+	///
+	/// Pruned together with the disputes of a session.
+	#[pallet::storage]
+	pub(super) type DisputeLatencies<T> =
+		StorageMap<_, Twox64Concat, SessionIndex, DisputeLatencyStats>;
+
+	/// The number of disputes initiated on chain, by the session of the disputed candidate.
+	///
+	/// Pruned together with the disputes of a session.
+	#[pallet::storage]
+	pub(super) type DisputesInSession<T> =
+		StorageMap<_, Twox64Concat, SessionIndex, u32, ValueQuery>;
+
+	/// The number of unconcluded disputes each validator initiated, by the session of the
+	/// disputed candidate.
+	///
+	/// Pruned together with the disputes of a session.
+	#[pallet::storage]
+	pub(super) type PendingDisputesOfValidator<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		ValidatorIndex,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The validators who initiated each unconcluded dispute, i.e. voted against the candidate in
+	/// the statement set opening it.
+	///
+	/// Removed once the dispute concludes, and pruned together with the disputes of a session.
+	#[pallet::storage]
+	pub(super) type DisputeInitiators<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Blake2_128Concat,
+		CandidateHash,
+		Vec<ValidatorIndex>,
+	>;
+
+	/// A dispute to include ahead of all others, along with the block it is prioritized in.
+	///
+	/// Cleared at the end of that block.
+	#[pallet::storage]
+	pub(super) type PrioritizedDispute<T: Config> =
+		StorageValue<_, (BlockNumberFor<T>, SessionIndex, CandidateHash)>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -481,6 +664,23 @@ pub mod pallet {
 			Frozen::<T>::set(None);
 			Ok(())
 		}
+
+		/// Include the dispute of `candidate_hash` in `session` in the next block ahead of all
+		/// other disputes, even if that means dropping some of them.
+		///
+		/// Meant for emergencies only, the dispute is no longer prioritized after that block.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_prioritize_dispute())]
+		pub fn force_prioritize_dispute(
+			origin: OriginFor<T>,
+			session: SessionIndex,
+			candidate_hash: CandidateHash,
+		) -> DispatchResult {
+			T::PrioritizeDisputeOrigin::ensure_origin(origin)?;
+			let next_block = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+			PrioritizedDispute::<T>::put((next_block, session, candidate_hash));
+			Ok(())
+		}
 	}
 }
 
@@ -579,6 +779,10 @@ impl From<&DisputeStatement> for VoteKind {
 }
 
 impl VoteKind {
+	/// The number of vote kinds, i.e. the number of distinct votes a validator may cast on a
+	/// candidate.
+	const COUNT: usize = 3;
+
 	fn is_valid(&self) -> bool {
 		match self {
 			Self::Backing | Self::ExplicitValid => true,
@@ -859,7 +1063,20 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Called by the initializer to finalize the disputes pallet.
-	pub(crate) fn initializer_finalize() {}
+	pub(crate) fn initializer_finalize() {
+		let now = frame_system::Pallet::<T>::block_number();
+		if PrioritizedDispute::<T>::get().map_or(false, |(block, _, _)| block <= now) {
+			PrioritizedDispute::<T>::kill();
+		}
+	}
+
+	/// The dispute to put ahead of all others in the current block, if any.
+	pub(crate) fn prioritized_dispute() -> Option<(SessionIndex, CandidateHash)> {
+		let now = frame_system::Pallet::<T>::block_number();
+		PrioritizedDispute::<T>::get()
+			.filter(|(block, _, _)| *block == now)
+			.map(|(_, session, candidate_hash)| (session, candidate_hash))
+	}
 
 	/// Called by the initializer to note a new session in the disputes pallet.
 	pub(crate) fn initializer_on_new_session(
@@ -886,6 +1103,12 @@ impl<T: Config> Pallet<T> {
 				<Disputes<T>>::remove_prefix(to_prune, None);
 				#[allow(deprecated)]
 				<BackersOnDisputes<T>>::remove_prefix(to_prune, None);
+				<DisputeLatencies<T>>::remove(to_prune);
+				<DisputesInSession<T>>::remove(to_prune);
+				#[allow(deprecated)]
+				<PendingDisputesOfValidator<T>>::remove_prefix(to_prune, None);
+				#[allow(deprecated)]
+				<DisputeInitiators<T>>::remove_prefix(to_prune, None);
 
 				// This is larger, and will be extracted to the `shared` pallet for more proper
 				// pruning. TODO: https://github.com/paritytech/polkadot/issues/3469
@@ -897,6 +1120,67 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Remove statement sets which would initiate a new dispute in a session that already saw
+	/// `max_disputes_per_session` disputes initiated on chain, in order of `statement_sets`.
+	pub(crate) fn limit_new_disputes_per_session(
+		statement_sets: &mut MultiDisputeStatementSet,
+		max_disputes_per_session: u32,
+	) {
+		let mut initiated = BTreeMap::new();
+		statement_sets.retain(|set| {
+			if <Disputes<T>>::contains_key(&set.session, &set.candidate_hash) {
+				return true
+			}
+
+			let count = initiated
+				.entry(set.session)
+				.or_insert_with(|| <DisputesInSession<T>>::get(set.session));
+			if *count >= max_disputes_per_session {
+				return false
+			}
+			*count += 1;
+			true
+		});
+	}
+
+	/// Remove the statements against a candidate initiating a new dispute of validators who
+	/// already initiated `max_pending_disputes_per_validator` unconcluded disputes of the session,
+	/// in order of `statement_sets`.
+	///
+	/// Statements on disputes already known on chain are always retained.
+	pub(crate) fn limit_pending_disputes_per_validator(
+		statement_sets: &mut MultiDisputeStatementSet,
+		max_pending_disputes_per_validator: u32,
+	) {
+		let mut pending = BTreeMap::new();
+		for set in statement_sets.iter_mut() {
+			if <Disputes<T>>::contains_key(&set.session, &set.candidate_hash) {
+				continue
+			}
+
+			let session = set.session;
+			let mut initiators = BTreeSet::new();
+			set.statements.retain(|(statement, validator_index, _)| {
+				if VoteKind::from(statement).is_valid() {
+					return true
+				}
+				// Repeated votes are not counted twice, they are filtered later on.
+				if !initiators.insert(*validator_index) {
+					return true
+				}
+
+				let count = pending.entry((session, *validator_index)).or_insert_with(|| {
+					<PendingDisputesOfValidator<T>>::get(session, validator_index)
+				});
+				if *count >= max_pending_disputes_per_validator {
+					return false
+				}
+				*count += 1;
+				true
+			});
+		}
+	}
+
 	/// Handle sets of dispute statements corresponding to 0 or more candidates.
 	/// Returns a vector of freshly created disputes.
 	///
@@ -933,7 +1217,8 @@ impl<T: Config> Pallet<T> {
 	// It either removes the entire dispute statement set or some specific votes from it.
 	//
 	// Votes which are duplicate or already known by the chain are filtered out.
-	// The entire set is removed if the dispute is both, ancient and concluded.
+	// The entire set is removed if the dispute is both, ancient and concluded, or if it carries
+	// more statements than there can be distinct votes of the validators of the session.
 	// Disputes without enough votes to get confirmed are also filtered out.
 	fn filter_dispute_data(
 		set: &DisputeStatementSet,
@@ -956,6 +1241,21 @@ impl<T: Config> Pallet<T> {
 
 		let n_validators = session_info.validators.len();
 
+		// Reject sets carrying more statements than there can be distinct votes in the session.
+		// A validator may cast a backing, an explicit valid and an invalid vote on the same
+		// candidate, so each validator accounts for up to `VoteKind::COUNT` statements.
+		let max_statements = n_validators.saturating_mul(VoteKind::COUNT);
+		if set.statements.len() > max_statements {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropping dispute statement set for {:?}: {} statements exceed {} possible votes",
+				set.candidate_hash,
+				set.statements.len(),
+				max_statements,
+			);
+			return StatementSetFilter::RemoveAll
+		}
+
 		// Check for ancient.
 		let dispute_state = {
 			if let Some(dispute_state) = <Disputes<T>>::get(&set.session, &set.candidate_hash) {
@@ -978,6 +1278,23 @@ impl<T: Config> Pallet<T> {
 		let backers =
 			<BackersOnDisputes<T>>::get(&set.session, &set.candidate_hash).unwrap_or_default();
 
+		// The backing votes on the disputed candidate which were verified on chain already, along
+		// with the candidate's relay parent. Disputes are often raised right after inclusion, so
+		// this saves re-checking the backing statements they carry.
+		//
+		// Backing statements in the dispute are checked against `set.session`, so the recorded
+		// votes are only trusted if their relay parent is known to be in that session.
+		let verified_backing_votes = T::VerifiedBackingVotes::verified_backing_votes()
+			.filter(|on_chain_votes| on_chain_votes.session == set.session)
+			.and_then(|on_chain_votes| {
+				on_chain_votes
+					.backing_validators_per_candidate
+					.into_iter()
+					.find(|(receipt, _)| receipt.hash() == set.candidate_hash)
+			})
+			.map(|(receipt, votes)| (receipt.descriptor.relay_parent, votes))
+			.filter(|(relay_parent, _)| relay_parent_in_session::<T>(*relay_parent, set.session));
+
 		// Check and import all votes.
 		let summary = {
 			let mut importer = DisputeStateImporter::new(dispute_state, backers, now);
@@ -1011,6 +1328,20 @@ impl<T: Config> Pallet<T> {
 				//
 				// This is only really important until the post-conclusion acceptance threshold
 				// is reached, and then no part of this loop will be hit.
+				let verified_on_chain =
+					verified_backing_votes.as_ref().map_or(false, |(relay_parent, votes)| {
+						is_verified_backing_vote(
+							relay_parent,
+							votes,
+							*validator_index,
+							statement,
+							signature,
+						)
+					});
+				if verified_on_chain {
+					continue
+				}
+
 				if let Err(()) = check_signature(
 					&validator_public,
 					set.candidate_hash,
@@ -1099,6 +1430,8 @@ impl<T: Config> Pallet<T> {
 		let backers =
 			<BackersOnDisputes<T>>::get(&set.session, &set.candidate_hash).unwrap_or_default();
 
+		let was_concluded = dispute_state.concluded_at.is_some();
+
 		// Import all votes. They were pre-checked.
 		let summary = {
 			let mut importer = DisputeStateImporter::new(dispute_state, backers, now);
@@ -1134,7 +1467,13 @@ impl<T: Config> Pallet<T> {
 		let session = *session;
 		let candidate_hash = *candidate_hash;
 
+		if !was_concluded {
+			Self::note_pending_disputes(session, candidate_hash, fresh, &summary.state);
+		}
+
 		if fresh {
+			<DisputesInSession<T>>::mutate(session, |count| *count = count.saturating_add(1));
+
 			let is_local = <Included<T>>::contains_key(&session, &candidate_hash);
 
 			Self::deposit_event(Event::DisputeInitiated(
@@ -1158,6 +1497,19 @@ impl<T: Config> Pallet<T> {
 					DisputeResult::Invalid,
 				));
 			}
+
+			// Only account for the first conclusion of a dispute.
+			if summary.state.concluded_at.as_ref() == Some(&now) &&
+				summary.new_flags.intersects(
+					DisputeStateFlags::FOR_SUPERMAJORITY | DisputeStateFlags::AGAINST_SUPERMAJORITY,
+				) {
+				let latency: u32 = now.saturating_sub(summary.state.start).saturated_into();
+				<DisputeLatencies<T>>::mutate(session, |stats| {
+					let stats = stats.get_or_insert_with(Default::default);
+					stats.concluded = stats.concluded.saturating_add(1);
+					stats.total_blocks = stats.total_blocks.saturating_add(latency);
+				});
+			}
 		}
 
 		// Reward statements.
@@ -1198,6 +1550,37 @@ impl<T: Config> Pallet<T> {
 		Ok(fresh)
 	}
 
+	/// Update the pending disputes of the initiators of a dispute which was not concluded before
+	/// importing new votes.
+	///
+	/// The validators against the candidate of a fresh dispute initiated it, and get one more
+	/// pending dispute until it concludes. A dispute concluding with the votes initiating it is not
+	/// pending at all.
+	fn note_pending_disputes(
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		fresh: bool,
+		state: &DisputeState<BlockNumberFor<T>>,
+	) {
+		let concluded = state.concluded_at.is_some();
+		if fresh && !concluded {
+			let initiators: Vec<_> =
+				state.validators_against.iter_ones().map(|i| ValidatorIndex(i as _)).collect();
+			for index in &initiators {
+				<PendingDisputesOfValidator<T>>::mutate(session, index, |count| {
+					*count = count.saturating_add(1)
+				});
+			}
+			<DisputeInitiators<T>>::insert(session, candidate_hash, initiators);
+		} else if !fresh && concluded {
+			for index in <DisputeInitiators<T>>::take(session, candidate_hash).unwrap_or_default() {
+				<PendingDisputesOfValidator<T>>::mutate(session, index, |count| {
+					*count = count.saturating_sub(1)
+				});
+			}
+		}
+	}
+
 	#[allow(unused)]
 	pub(crate) fn disputes() -> Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumberFor<T>>)>
 	{
@@ -1242,6 +1625,34 @@ impl<T: Config> Pallet<T> {
 		Self::last_valid_block().is_some()
 	}
 
+	/// The average number of blocks it took disputes to conclude, over the disputes of all
+	/// sessions which weren't pruned yet.
+	///
+	/// Disputes which didn't conclude are not accounted for. Returns `None` if no dispute
+	/// concluded.
+	pub fn average_dispute_latency() -> Option<u32> {
+		let (concluded, total_blocks) = <DisputeLatencies<T>>::iter_values().fold(
+			(0u32, 0u32),
+			|(concluded, total_blocks), stats| {
+				(
+					concluded.saturating_add(stats.concluded),
+					total_blocks.saturating_add(stats.total_blocks),
+				)
+			},
+		);
+
+		total_blocks.checked_div(concluded)
+	}
+
+	/// The disputes with statements on chain which didn't conclude yet, as of the current block,
+	/// in no particular order.
+	pub fn active_unconcluded_disputes() -> Vec<(SessionIndex, CandidateHash)> {
+		<Disputes<T>>::iter()
+			.filter(|(_, _, state)| state.concluded_at.is_none())
+			.map(|(session, candidate_hash, _)| (session, candidate_hash))
+			.collect()
+	}
+
 	pub(crate) fn revert_and_freeze(revert_to: BlockNumberFor<T>) {
 		if Self::last_valid_block().map_or(true, |last| last > revert_to) {
 			Frozen::<T>::set(Some(revert_to));
@@ -1263,6 +1674,42 @@ fn has_supermajority_against<BlockNumber>(dispute: &DisputeState<BlockNumber>) -
 	dispute.validators_against.count_ones() >= supermajority_threshold
 }
 
+/// Whether `relay_parent` is an allowed relay parent of the current session, and that session is
+/// `session`.
+///
+/// Relay parents are only known for the current session: right after a session change, the
+/// allowed relay parents may still include a block of the previous session, which is rejected.
+fn relay_parent_in_session<T: Config>(relay_parent: T::Hash, session: SessionIndex) -> bool {
+	session == shared::Pallet::<T>::session_index() &&
+		shared::Pallet::<T>::allowed_relay_parents()
+			.acquire_info(relay_parent, None)
+			.map_or(false, |(_, number)| number >= scheduler::Pallet::<T>::session_start_block())
+}
+
+/// Whether `statement` of the validator at `validator_index` with `signature` is exactly one of
+/// the backing `votes` on a candidate with the given `relay_parent`, which were all verified on
+/// chain already.
+fn is_verified_backing_vote<Hash: AsRef<[u8]>>(
+	relay_parent: &Hash,
+	votes: &[(ValidatorIndex, ValidityAttestation)],
+	validator_index: ValidatorIndex,
+	statement: &DisputeStatement,
+	signature: &ValidatorSignature,
+) -> bool {
+	let (inclusion_parent, expected) = match statement {
+		DisputeStatement::Valid(ValidDisputeStatementKind::BackingSeconded(inclusion_parent)) =>
+			(inclusion_parent, ValidityAttestation::Implicit(signature.clone())),
+		DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(inclusion_parent)) =>
+			(inclusion_parent, ValidityAttestation::Explicit(signature.clone())),
+		_ => return false,
+	};
+
+	inclusion_parent.as_ref() == relay_parent.as_ref() &&
+		votes
+			.iter()
+			.any(|(index, attestation)| *index == validator_index && *attestation == expected)
+}
+
 fn check_signature(
 	validator_public: &ValidatorId,
 	candidate_hash: CandidateHash,
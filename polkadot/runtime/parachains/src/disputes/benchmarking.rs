@@ -16,7 +16,7 @@
 
 use super::*;
 
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{benchmarks, BenchmarkError};
 use frame_system::RawOrigin;
 use sp_runtime::traits::One;
 
@@ -28,6 +28,15 @@ benchmarks! {
 		assert!(Frozen::<T>::get().is_none())
 	}
 
+	force_prioritize_dispute {
+		let origin = T::PrioritizeDisputeOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let candidate_hash = CandidateHash(Default::default());
+	}: _<T::RuntimeOrigin>(origin, 0, candidate_hash)
+	verify {
+		assert!(PrioritizedDispute::<T>::get().is_some())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(Default::default()),
@@ -42,7 +42,7 @@
 //! producer can submit an unsigned transaction with `KeyOwnershipProof` of an
 //! offender and submit it to the runtime to produce an offence.
 
-use crate::{disputes, initializer::ValidatorSetCount, session_info::IdentificationTuple};
+use crate::{disputes, initializer::ValidatorSetCount, session_info::IdentificationTuple, shared};
 use frame_support::{
 	dispatch::Pays,
 	traits::{Defensive, Get, KeyOwnerProofSystem, ValidatorSet, ValidatorSetWithIdentification},
@@ -52,6 +52,7 @@ use frame_system::pallet_prelude::BlockNumberFor;
 
 use primitives::{
 	slashing::{DisputeProof, DisputesTimeSlot, PendingSlashes, SlashingOffenceKind},
+	vstaging::DisablingReason,
 	CandidateHash, SessionIndex, ValidatorId, ValidatorIndex,
 };
 use scale_info::TypeInfo;
@@ -240,6 +241,13 @@ where
 
 		let maybe = Self::maybe_identify_validators(session_index, to_punish.iter().cloned());
 		if let Some(offenders) = maybe {
+			// The offenders are disabled, see `SlashingOffence::disable_strategy`.
+			if session_index == shared::Pallet::<T>::session_index() {
+				shared::Pallet::<T>::note_disabling_reason(
+					to_punish.iter().cloned(),
+					DisablingReason::DisputeConcludedAgainst,
+				);
+			}
 			let validator_set_count = session_info.discovery_keys.len() as ValidatorSetCount;
 			let offence = SlashingOffence::new(
 				session_index,
@@ -1213,6 +1213,616 @@ fn test_provide_multi_dispute_success_and_other() {
 	})
 }
 
+#[test]
+fn test_average_dispute_latency() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v2 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v3 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v4 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v5 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v6 = <ValidatorId as CryptoType>::Pair::generate().0;
+
+		// Mapping between key pair and `ValidatorIndex`
+		// v0 -> 0
+		// v1 -> 3
+		// v2 -> 6
+		// v3 -> 5
+		// v4 -> 1
+		// v5 -> 4
+		// v6 -> 2
+		let session_change = |b| {
+			// a new session at each block
+			Some((
+				true,
+				b,
+				vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				],
+				Some(vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				]),
+			))
+		};
+
+		run_to_block(6, session_change);
+
+		let candidate_hash = CandidateHash(sp_core::H256::repeat_byte(1));
+		let inclusion_parent = sp_core::H256::repeat_byte(0xff);
+		let session = 5;
+		let against = |pair: &<ValidatorId as CryptoType>::Pair, index| {
+			(
+				DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+				ValidatorIndex(index),
+				pair.sign(
+					&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+						.signing_payload(),
+				),
+			)
+		};
+
+		// v3 backs the candidate, v2 and v6 vote against it.
+		let stmts = vec![DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(
+						inclusion_parent,
+					)),
+					ValidatorIndex(5),
+					v3.sign(&CompactStatement::Valid(candidate_hash).signing_payload(
+						&SigningContext { session_index: session, parent_hash: inclusion_parent },
+					)),
+				),
+				against(&v2, 6),
+				against(&v6, 2),
+			],
+		}];
+		let stmts = filter_dispute_set(stmts);
+		assert_ok!(
+			Pallet::<Test>::process_checked_multi_dispute_data(&stmts),
+			vec![(session, candidate_hash)],
+		);
+
+		// Disputes which didn't conclude are not accounted for.
+		assert_eq!(Pallet::<Test>::average_dispute_latency(), None);
+
+		run_to_block(8, session_change);
+
+		// v0, v4 and v5 vote against the candidate, which concludes the dispute.
+		let stmts = vec![DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![against(&v0, 0), against(&v4, 1), against(&v5, 4)],
+		}];
+		let stmts = filter_dispute_set(stmts);
+		assert_ok!(Pallet::<Test>::process_checked_multi_dispute_data(&stmts), vec![]);
+		assert!(Pallet::<Test>::concluded_invalid(session, candidate_hash));
+
+		// Started at block 6, concluded at block 8.
+		assert_eq!(Pallet::<Test>::average_dispute_latency(), Some(2));
+
+		// The latency is forgotten once the session of the dispute is pruned.
+		let dispute_period = configuration::Pallet::<Test>::config().dispute_period;
+		run_to_block(session + dispute_period, session_change);
+		assert_eq!(Pallet::<Test>::average_dispute_latency(), Some(2));
+		run_to_block(session + dispute_period + 1, session_change);
+		assert_eq!(Pallet::<Test>::average_dispute_latency(), None);
+	})
+}
+
+#[test]
+fn test_active_unconcluded_disputes() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v2 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v3 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v4 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v5 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v6 = <ValidatorId as CryptoType>::Pair::generate().0;
+
+		// Mapping between key pair and `ValidatorIndex`
+		// v0 -> 0
+		// v1 -> 3
+		// v2 -> 6
+		// v3 -> 5
+		// v4 -> 1
+		// v5 -> 4
+		// v6 -> 2
+		let session_change = |b| {
+			// a new session at each block
+			Some((
+				true,
+				b,
+				vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				],
+				Some(vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				]),
+			))
+		};
+
+		run_to_block(6, session_change);
+
+		let candidate_hash = CandidateHash(sp_core::H256::repeat_byte(1));
+		let inclusion_parent = sp_core::H256::repeat_byte(0xff);
+		let session = 5;
+		let against = |pair: &<ValidatorId as CryptoType>::Pair, index| {
+			(
+				DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+				ValidatorIndex(index),
+				pair.sign(
+					&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+						.signing_payload(),
+				),
+			)
+		};
+
+		assert!(Pallet::<Test>::active_unconcluded_disputes().is_empty());
+
+		// v3 backs the candidate, v2 and v6 vote against it.
+		let stmts = vec![DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(
+						inclusion_parent,
+					)),
+					ValidatorIndex(5),
+					v3.sign(&CompactStatement::Valid(candidate_hash).signing_payload(
+						&SigningContext { session_index: session, parent_hash: inclusion_parent },
+					)),
+				),
+				against(&v2, 6),
+				against(&v6, 2),
+			],
+		}];
+		let stmts = filter_dispute_set(stmts);
+		assert_ok!(
+			Pallet::<Test>::process_checked_multi_dispute_data(&stmts),
+			vec![(session, candidate_hash)],
+		);
+
+		assert_eq!(Pallet::<Test>::active_unconcluded_disputes(), vec![(session, candidate_hash)]);
+
+		run_to_block(8, session_change);
+		assert_eq!(Pallet::<Test>::active_unconcluded_disputes(), vec![(session, candidate_hash)]);
+
+		// v0, v4 and v5 vote against the candidate, which concludes the dispute.
+		let stmts = vec![DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![against(&v0, 0), against(&v4, 1), against(&v5, 4)],
+		}];
+		let stmts = filter_dispute_set(stmts);
+		assert_ok!(Pallet::<Test>::process_checked_multi_dispute_data(&stmts), vec![]);
+
+		assert!(Pallet::<Test>::active_unconcluded_disputes().is_empty());
+
+		// Disputes concluded in a prior block don't show up either.
+		run_to_block(9, session_change);
+		assert!(Pallet::<Test>::active_unconcluded_disputes().is_empty());
+	})
+}
+
+#[test]
+fn test_disputes_for_candidates_never_included_are_flagged() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v2 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v3 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v4 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v5 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v6 = <ValidatorId as CryptoType>::Pair::generate().0;
+
+		// Mapping between key pair and `ValidatorIndex`
+		// v0 -> 0
+		// v1 -> 3
+		// v2 -> 6
+		// v3 -> 5
+		// v4 -> 1
+		// v5 -> 4
+		// v6 -> 2
+		run_to_block(6, |b| {
+			// a new session at each block
+			Some((
+				true,
+				b,
+				vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				],
+				Some(vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				]),
+			))
+		});
+
+		let never_included = CandidateHash(sp_core::H256::repeat_byte(1));
+		let included = CandidateHash(sp_core::H256::repeat_byte(2));
+		let inclusion_parent = sp_core::H256::repeat_byte(0xff);
+		let session = 5;
+
+		Pallet::<Test>::note_included(session, included, 5);
+
+		// v3 backs each candidate and v2 votes against it.
+		let dispute = |candidate_hash| DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(
+						inclusion_parent,
+					)),
+					ValidatorIndex(5),
+					v3.sign(&CompactStatement::Valid(candidate_hash).signing_payload(
+						&SigningContext { session_index: session, parent_hash: inclusion_parent },
+					)),
+				),
+				(
+					DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+					ValidatorIndex(6),
+					v2.sign(
+						&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+							.signing_payload(),
+					),
+				),
+			],
+		};
+		let stmts = filter_dispute_set(vec![dispute(never_included), dispute(included)]);
+		assert_eq!(stmts.len(), 2);
+		assert_ok!(Pallet::<Test>::process_checked_multi_dispute_data(&stmts));
+		crate::paras_inherent::set_scrapable_on_chain_disputes::<Test>(session, stmts);
+
+		// The dispute for the candidate never included is recorded all the same.
+		assert!(Disputes::<Test>::contains_key(session, never_included));
+		assert_eq!(
+			crate::paras_inherent::Pallet::<Test>::on_chain_disputes_inclusion(),
+			vec![
+				crate::paras_inherent::ScrapedDisputeInclusion {
+					session,
+					candidate_hash: never_included,
+					candidate_included: false,
+				},
+				crate::paras_inherent::ScrapedDisputeInclusion {
+					session,
+					candidate_hash: included,
+					candidate_included: true,
+				},
+			]
+		);
+	})
+}
+
+#[test]
+fn test_max_disputes_per_session() {
+	let mock_genesis_config = MockGenesisConfig {
+		configuration: crate::configuration::GenesisConfig {
+			config: HostConfiguration { max_disputes_per_session: Some(1), ..Default::default() },
+		},
+		..Default::default()
+	};
+
+	new_test_ext(mock_genesis_config).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v2 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v3 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v4 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v5 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v6 = <ValidatorId as CryptoType>::Pair::generate().0;
+
+		// Mapping between key pair and `ValidatorIndex`
+		// v0 -> 0
+		// v1 -> 3
+		// v2 -> 6
+		// v3 -> 5
+		// v4 -> 1
+		// v5 -> 4
+		// v6 -> 2
+		let session_change = |b| {
+			// a new session at each block
+			Some((
+				true,
+				b,
+				vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				],
+				Some(vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				]),
+			))
+		};
+
+		run_to_block(6, session_change);
+
+		let session = 5;
+		let inclusion_parent = sp_core::H256::repeat_byte(0xff);
+		let against = |candidate_hash, pair: &<ValidatorId as CryptoType>::Pair, index| {
+			(
+				DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+				ValidatorIndex(index),
+				pair.sign(
+					&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+						.signing_payload(),
+				),
+			)
+		};
+		// v3 backs the candidate, v2 and v6 vote against it.
+		let new_dispute = |candidate_hash| DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(
+						inclusion_parent,
+					)),
+					ValidatorIndex(5),
+					v3.sign(&CompactStatement::Valid(candidate_hash).signing_payload(
+						&SigningContext { session_index: session, parent_hash: inclusion_parent },
+					)),
+				),
+				against(candidate_hash, &v2, 6),
+				against(candidate_hash, &v6, 2),
+			],
+		};
+		let limit_and_process = |mut stmts: MultiDisputeStatementSet| {
+			let max_disputes_per_session =
+				configuration::Pallet::<Test>::config().max_disputes_per_session.unwrap();
+			Pallet::<Test>::limit_new_disputes_per_session(&mut stmts, max_disputes_per_session);
+			let stmts = filter_dispute_set(stmts);
+			Pallet::<Test>::process_checked_multi_dispute_data(&stmts)
+		};
+
+		let candidate_hash_a = CandidateHash(sp_core::H256::repeat_byte(1));
+		let candidate_hash_b = CandidateHash(sp_core::H256::repeat_byte(2));
+		let candidate_hash_c = CandidateHash(sp_core::H256::repeat_byte(3));
+
+		// Only the first of the new disputes fits into the session.
+		assert_ok!(
+			limit_and_process(vec![new_dispute(candidate_hash_a), new_dispute(candidate_hash_b)]),
+			vec![(session, candidate_hash_a)],
+		);
+		assert_eq!(DisputesInSession::<Test>::get(session), 1);
+
+		run_to_block(7, session_change);
+
+		// New disputes of the session are excluded in later blocks, while statements for the
+		// ongoing one are still imported and conclude it.
+		assert_ok!(
+			limit_and_process(vec![
+				new_dispute(candidate_hash_c),
+				DisputeStatementSet {
+					candidate_hash: candidate_hash_a,
+					session,
+					statements: vec![
+						against(candidate_hash_a, &v0, 0),
+						against(candidate_hash_a, &v4, 1),
+						against(candidate_hash_a, &v5, 4),
+					],
+				},
+			]),
+			vec![],
+		);
+		assert!(Pallet::<Test>::concluded_invalid(session, candidate_hash_a));
+		assert!(!Disputes::<Test>::contains_key(session, candidate_hash_b));
+		assert!(!Disputes::<Test>::contains_key(session, candidate_hash_c));
+		assert_eq!(DisputesInSession::<Test>::get(session), 1);
+	})
+}
+
+#[test]
+fn test_max_pending_disputes_per_validator() {
+	let mock_genesis_config = MockGenesisConfig {
+		configuration: crate::configuration::GenesisConfig {
+			config: HostConfiguration {
+				max_pending_disputes_per_validator: Some(2),
+				..Default::default()
+			},
+		},
+		..Default::default()
+	};
+
+	new_test_ext(mock_genesis_config).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v2 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v3 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v4 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v5 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v6 = <ValidatorId as CryptoType>::Pair::generate().0;
+
+		// Mapping between key pair and `ValidatorIndex`
+		// v0 -> 0
+		// v1 -> 3
+		// v2 -> 6
+		// v3 -> 5
+		// v4 -> 1
+		// v5 -> 4
+		// v6 -> 2
+		run_to_block(6, |b| {
+			// a new session at each block
+			Some((
+				true,
+				b,
+				vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				],
+				Some(vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				]),
+			))
+		});
+
+		let session = 5;
+		let inclusion_parent = sp_core::H256::repeat_byte(0xff);
+		let against = |candidate_hash, pair: &<ValidatorId as CryptoType>::Pair, index| {
+			(
+				DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+				ValidatorIndex(index),
+				pair.sign(
+					&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+						.signing_payload(),
+				),
+			)
+		};
+		// v3 backs the candidate, the given validators vote against it.
+		let new_dispute = |candidate_hash, voters: &[(&<ValidatorId as CryptoType>::Pair, u32)]| {
+			let mut statements = vec![(
+				DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(inclusion_parent)),
+				ValidatorIndex(5),
+				v3.sign(&CompactStatement::Valid(candidate_hash).signing_payload(
+					&SigningContext { session_index: session, parent_hash: inclusion_parent },
+				)),
+			)];
+			statements
+				.extend(voters.iter().map(|(pair, index)| against(candidate_hash, *pair, *index)));
+			DisputeStatementSet { candidate_hash, session, statements }
+		};
+		let limit_and_process = |mut stmts: MultiDisputeStatementSet| {
+			let max_pending_disputes_per_validator = configuration::Pallet::<Test>::config()
+				.max_pending_disputes_per_validator
+				.unwrap();
+			Pallet::<Test>::limit_pending_disputes_per_validator(
+				&mut stmts,
+				max_pending_disputes_per_validator,
+			);
+			let stmts = filter_dispute_set(stmts);
+			Pallet::<Test>::process_checked_multi_dispute_data(&stmts)
+		};
+		let is_against = |candidate_hash, index: usize| {
+			Disputes::<Test>::get(session, candidate_hash).unwrap().validators_against[index]
+		};
+
+		let candidate_hash_a = CandidateHash(sp_core::H256::repeat_byte(1));
+		let candidate_hash_b = CandidateHash(sp_core::H256::repeat_byte(2));
+		let candidate_hash_c = CandidateHash(sp_core::H256::repeat_byte(3));
+		let candidate_hash_d = CandidateHash(sp_core::H256::repeat_byte(4));
+
+		// v2 initiates two disputes, reaching the limit, so its vote initiating a third one is
+		// dropped while the votes of the other validators are not.
+		assert_ok!(
+			limit_and_process(vec![
+				new_dispute(candidate_hash_a, &[(&v2, 6), (&v6, 2)]),
+				new_dispute(candidate_hash_b, &[(&v2, 6), (&v0, 0), (&v4, 1)]),
+				new_dispute(candidate_hash_c, &[(&v2, 6), (&v5, 4), (&v1, 3)]),
+			]),
+			vec![
+				(session, candidate_hash_a),
+				(session, candidate_hash_b),
+				(session, candidate_hash_c),
+			],
+		);
+		assert!(is_against(candidate_hash_b, 6));
+		assert!(!is_against(candidate_hash_c, 6));
+		assert!(is_against(candidate_hash_c, 4));
+		assert_eq!(PendingDisputesOfValidator::<Test>::get(session, ValidatorIndex(6)), 2);
+		assert_eq!(PendingDisputesOfValidator::<Test>::get(session, ValidatorIndex(4)), 1);
+
+		// Votes on disputes already open are not limited, nor counted. The conclusion of the
+		// first dispute frees up a slot of each of its initiators.
+		assert_ok!(
+			limit_and_process(vec![
+				DisputeStatementSet {
+					candidate_hash: candidate_hash_c,
+					session,
+					statements: vec![against(candidate_hash_c, &v2, 6)],
+				},
+				DisputeStatementSet {
+					candidate_hash: candidate_hash_a,
+					session,
+					statements: vec![
+						against(candidate_hash_a, &v0, 0),
+						against(candidate_hash_a, &v4, 1),
+						against(candidate_hash_a, &v5, 4),
+					],
+				},
+			]),
+			vec![],
+		);
+		assert!(is_against(candidate_hash_c, 6));
+		assert!(Pallet::<Test>::concluded_invalid(session, candidate_hash_a));
+		assert!(!DisputeInitiators::<Test>::contains_key(session, candidate_hash_a));
+		assert_eq!(PendingDisputesOfValidator::<Test>::get(session, ValidatorIndex(6)), 1);
+		assert_eq!(PendingDisputesOfValidator::<Test>::get(session, ValidatorIndex(2)), 0);
+		assert_eq!(PendingDisputesOfValidator::<Test>::get(session, ValidatorIndex(0)), 1);
+		assert_eq!(PendingDisputesOfValidator::<Test>::get(session, ValidatorIndex(1)), 0);
+
+		// So v2 may vote against a candidate again.
+		assert_ok!(
+			limit_and_process(vec![new_dispute(candidate_hash_d, &[(&v2, 6), (&v6, 2)])]),
+			vec![(session, candidate_hash_d)],
+		);
+		assert!(is_against(candidate_hash_d, 6));
+		assert_eq!(PendingDisputesOfValidator::<Test>::get(session, ValidatorIndex(6)), 2);
+	})
+}
+
 /// In this setup we have only one dispute concluding AGAINST.
 /// There are some votes imported post dispute conclusion.
 /// We make sure these votes are accounted for in punishment.
@@ -1982,6 +2592,124 @@ fn deduplication_and_sorting_works() {
 	})
 }
 
+#[test]
+fn tie_break_between_disputes_of_the_same_session() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+
+		let candidate_hash_a = CandidateHash(sp_core::H256::repeat_byte(1));
+		let candidate_hash_b = CandidateHash(sp_core::H256::repeat_byte(2));
+
+		let create_explicit_statement = |vidx: ValidatorIndex,
+		                                 validator: &<ValidatorId as CryptoType>::Pair,
+		                                 c_hash: &CandidateHash,
+		                                 valid,
+		                                 session| {
+			let payload = ExplicitDisputeStatement { valid, candidate_hash: *c_hash, session }
+				.signing_payload();
+			let sig = validator.sign(&payload);
+			(DisputeStatement::Valid(ValidDisputeStatementKind::Explicit), vidx, sig.clone())
+		};
+
+		// Two remote disputes of the same session, the one with the higher candidate hash
+		// carrying more statements.
+		let disputes = vec![
+			DisputeStatementSet {
+				candidate_hash: candidate_hash_a,
+				session: 1,
+				statements: vec![create_explicit_statement(
+					ValidatorIndex(0),
+					&v0,
+					&candidate_hash_a,
+					true,
+					1,
+				)],
+			},
+			DisputeStatementSet {
+				candidate_hash: candidate_hash_b,
+				session: 1,
+				statements: vec![
+					create_explicit_statement(ValidatorIndex(0), &v0, &candidate_hash_b, true, 1),
+					create_explicit_statement(ValidatorIndex(1), &v1, &candidate_hash_b, false, 1),
+				],
+			},
+		];
+
+		// Disputes are selected in order, so with room for a single one only the first is
+		// retained.
+		let first_retained = |tie_break| {
+			crate::mock::DisputesTieBreak::set(tie_break);
+			let mut sorted = disputes.clone();
+			<Pallet<Test> as DisputesHandler<BlockNumberFor<Test>>>::deduplicate_and_sort_dispute_data(
+				&mut sorted,
+			)
+			.unwrap();
+			assert_eq!(sorted.len(), 2);
+			sorted[0].candidate_hash
+		};
+
+		assert_eq!(first_retained(DisputeTieBreak::ByCandidateHash), candidate_hash_a);
+		assert_eq!(first_retained(DisputeTieBreak::ByStatementCount), candidate_hash_b);
+	})
+}
+
+#[test]
+fn force_prioritize_dispute_applies_to_the_next_block_only() {
+	new_test_ext(Default::default()).execute_with(|| {
+		run_to_block(1, |_| None);
+
+		let candidate_hash_a = CandidateHash(sp_core::H256::repeat_byte(1));
+		let candidate_hash_b = CandidateHash(sp_core::H256::repeat_byte(2));
+		// Without prioritization the dispute of the earlier session comes first.
+		let disputes = vec![
+			DisputeStatementSet {
+				candidate_hash: candidate_hash_a,
+				session: 1,
+				statements: vec![],
+			},
+			DisputeStatementSet {
+				candidate_hash: candidate_hash_b,
+				session: 2,
+				statements: vec![],
+			},
+		];
+		let first_retained = || {
+			let mut sorted = disputes.clone();
+			<Pallet<Test> as DisputesHandler<BlockNumberFor<Test>>>::deduplicate_and_sort_dispute_data(
+				&mut sorted,
+			)
+			.unwrap();
+			assert_eq!(sorted.len(), 2);
+			sorted[0].candidate_hash
+		};
+
+		assert_noop!(
+			Pallet::<Test>::force_prioritize_dispute(
+				frame_system::RawOrigin::Signed(1).into(),
+				2,
+				candidate_hash_b,
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Pallet::<Test>::force_prioritize_dispute(
+			frame_system::RawOrigin::Root.into(),
+			2,
+			candidate_hash_b,
+		));
+
+		// The dispute is only prioritized in the next block.
+		assert_eq!(first_retained(), candidate_hash_a);
+		run_to_block(2, |_| None);
+		assert_eq!(first_retained(), candidate_hash_b);
+
+		// And no longer after it.
+		run_to_block(3, |_| None);
+		assert!(PrioritizedDispute::<Test>::get().is_none());
+		assert_eq!(first_retained(), candidate_hash_a);
+	})
+}
+
 fn apply_filter_all<T: Config, I: IntoIterator<Item = DisputeStatementSet>>(
 	sets: I,
 ) -> Vec<CheckedDisputeStatementSet> {
@@ -2086,6 +2814,108 @@ fn filter_removes_duplicates_within_set() {
 	})
 }
 
+#[test]
+fn filter_removes_sets_with_more_statements_than_possible_votes() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+
+		run_to_block(3, |b| {
+			// a new session at each block
+			Some((
+				true,
+				b,
+				vec![(&0, v0.public()), (&1, v1.public())],
+				Some(vec![(&0, v0.public()), (&1, v1.public())]),
+			))
+		});
+
+		let candidate_hash = CandidateHash(sp_core::H256::repeat_byte(1));
+		let inclusion_parent = sp_core::H256::repeat_byte(0xff);
+		let session = 1;
+
+		let payload =
+			ExplicitDisputeStatement { valid: true, candidate_hash, session }.signing_payload();
+
+		let payload_against =
+			ExplicitDisputeStatement { valid: false, candidate_hash, session }.signing_payload();
+
+		let post_conclusion_acceptance_period = 10;
+
+		// A validator may both vote explicitly and back the candidate, so there are more
+		// statements than validators without any of them being superfluous.
+		let statements = DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::Explicit),
+					ValidatorIndex(0),
+					v0.sign(&payload),
+				),
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(
+						inclusion_parent,
+					)),
+					ValidatorIndex(0),
+					v0.sign(&CompactStatement::Valid(candidate_hash).signing_payload(
+						&SigningContext { session_index: session, parent_hash: inclusion_parent },
+					)),
+				),
+				(
+					DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+					ValidatorIndex(1),
+					v1.sign(&payload_against),
+				),
+			],
+		};
+
+		assert_eq!(
+			<Pallet<Test> as DisputesHandler<BlockNumberFor<Test>>>::filter_dispute_data(
+				statements.clone(),
+				post_conclusion_acceptance_period,
+			),
+			Some(CheckedDisputeStatementSet::unchecked_from_unchecked(statements))
+		);
+
+		// Two validators can cast at most six distinct votes, a seventh statement can only be a
+		// duplicate.
+		let sig_for = v0.sign(&payload);
+		let sig_against = v1.sign(&payload_against);
+		let statements = DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: [
+				vec![
+					(
+						DisputeStatement::Valid(ValidDisputeStatementKind::Explicit),
+						ValidatorIndex(0),
+						sig_for,
+					);
+					4
+				],
+				vec![
+					(
+						DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+						ValidatorIndex(1),
+						sig_against,
+					);
+					3
+				],
+			]
+			.concat(),
+		};
+
+		assert_eq!(
+			<Pallet<Test> as DisputesHandler<BlockNumberFor<Test>>>::filter_dispute_data(
+				statements,
+				post_conclusion_acceptance_period,
+			),
+			None
+		);
+	})
+}
+
 #[test]
 fn filter_bad_signatures_correctly_detects_single_sided() {
 	new_test_ext(Default::default()).execute_with(|| {
@@ -2145,6 +2975,183 @@ fn filter_bad_signatures_correctly_detects_single_sided() {
 	})
 }
 
+#[test]
+fn filter_skips_only_backing_votes_verified_on_chain() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v2 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v3 = <ValidatorId as CryptoType>::Pair::generate().0;
+
+		run_to_block(3, |b| {
+			// a new session at each block
+			Some((
+				true,
+				b,
+				vec![(&0, v0.public()), (&1, v1.public()), (&2, v2.public()), (&3, v3.public())],
+				Some(vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+				]),
+			))
+		});
+
+		let session = shared::Pallet::<Test>::session_index();
+		let relay_parent = sp_core::H256::repeat_byte(2);
+		let receipt = test_helpers::dummy_candidate_receipt(relay_parent);
+		let candidate_hash = receipt.hash();
+		let signing_context = SigningContext { session_index: session, parent_hash: relay_parent };
+
+		let seconded =
+			v0.sign(&CompactStatement::Seconded(candidate_hash).signing_payload(&signing_context));
+		let valid =
+			v1.sign(&CompactStatement::Valid(candidate_hash).signing_payload(&signing_context));
+		let against = v2.sign(
+			&ExplicitDisputeStatement { valid: false, candidate_hash, session }.signing_payload(),
+		);
+
+		let set = DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::BackingSeconded(
+						relay_parent,
+					)),
+					ValidatorIndex(0),
+					seconded.clone(),
+				),
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(relay_parent)),
+					ValidatorIndex(1),
+					valid.clone(),
+				),
+				(
+					DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+					ValidatorIndex(2),
+					against,
+				),
+			],
+		};
+		// The backing statement of validator 1 carries validator 0's signature.
+		let mut forged = set.clone();
+		forged.statements[1].2 = seconded.clone();
+
+		// Full verification, as there are no backing votes on chain.
+		let fully_verified = apply_filter_all::<Test, _>(vec![set.clone(), forged.clone()]);
+		assert_eq!(fully_verified.len(), 2);
+		assert_eq!(AsRef::<DisputeStatementSet>::as_ref(&fully_verified[1]).statements.len(), 2);
+
+		crate::paras_inherent::OnChainVotes::<Test>::put(ScrapedOnChainVotes {
+			session,
+			backing_validators_per_candidate: vec![(
+				receipt,
+				vec![
+					(ValidatorIndex(0), ValidityAttestation::Implicit(seconded)),
+					(ValidatorIndex(1), ValidityAttestation::Explicit(valid)),
+				],
+			)],
+			disputes: Vec::new(),
+		});
+
+		// The relay parent isn't known to be in the session of the dispute, nothing is trusted.
+		assert_eq!(apply_filter_all::<Test, _>(vec![set.clone(), forged.clone()]), fully_verified);
+
+		shared::AllowedRelayParents::<Test>::mutate(|tracker| {
+			tracker.update(relay_parent, Default::default(), System::block_number(), 1)
+		});
+
+		// The signatures verified on chain are trusted, anything else is still verified.
+		assert_eq!(apply_filter_all::<Test, _>(vec![set, forged]), fully_verified);
+	})
+}
+
+#[test]
+fn filter_removes_only_the_forged_statement_of_a_large_set() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v2 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v3 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v4 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v5 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v6 = <ValidatorId as CryptoType>::Pair::generate().0;
+
+		// Mapping between key pair and `ValidatorIndex`
+		// v0 -> 0
+		// v1 -> 3
+		// v2 -> 6
+		// v3 -> 5
+		// v4 -> 1
+		// v5 -> 4
+		// v6 -> 2
+		run_to_block(6, |b| {
+			// a new session at each block
+			Some((
+				true,
+				b,
+				vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				],
+				Some(vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				]),
+			))
+		});
+
+		let candidate_hash = CandidateHash(sp_core::H256::repeat_byte(1));
+		let session = 5;
+		let vote = |pair: &<ValidatorId as CryptoType>::Pair, index, valid| {
+			let statement = if valid {
+				DisputeStatement::Valid(ValidDisputeStatementKind::Explicit)
+			} else {
+				DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit)
+			};
+			let payload =
+				ExplicitDisputeStatement { valid, candidate_hash, session }.signing_payload();
+			(statement, ValidatorIndex(index), pair.sign(&payload))
+		};
+
+		// The vote of validator 4 carries validator 3's signature.
+		let forged = vote(&v1, 4, false);
+		let set = DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![
+				vote(&v0, 0, true),
+				vote(&v4, 1, true),
+				vote(&v6, 2, true),
+				vote(&v1, 3, false),
+				forged.clone(),
+				vote(&v3, 5, false),
+				vote(&v2, 6, false),
+			],
+		};
+
+		let mut expected = set.clone();
+		expected.statements.retain(|statement| statement != &forged);
+		assert_eq!(expected.statements.len(), 6);
+
+		let checked = apply_filter_all::<Test, _>(vec![set]);
+		assert_eq!(checked.len(), 1);
+		assert_eq!(AsRef::<DisputeStatementSet>::as_ref(&checked[0]), &expected);
+	})
+}
+
 #[test]
 fn filter_removes_session_out_of_bounds() {
 	new_test_ext(Default::default()).execute_with(|| {
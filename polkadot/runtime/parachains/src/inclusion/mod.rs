@@ -47,7 +47,10 @@ use scale_info::TypeInfo;
 use sp_runtime::{traits::One, DispatchError, SaturatedConversion, Saturating};
 #[cfg(feature = "std")]
 use sp_std::fmt;
-use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	prelude::*,
+};
 
 pub use pallet::*;
 
@@ -611,7 +614,7 @@ impl<T: Config> Pallet<T> {
 			return Ok(ProcessedCandidates::default())
 		}
 
-		let minimum_backing_votes = configuration::Pallet::<T>::config().minimum_backing_votes;
+		let minimum_backing_votes = configuration::Pallet::<T>::minimum_backing_votes();
 		let validators = shared::Pallet::<T>::active_validator_keys();
 
 		// Collect candidate receipts with backers.
@@ -650,6 +653,8 @@ impl<T: Config> Pallet<T> {
 				let prev_context = <paras::Pallet<T>>::para_most_recent_context(para_id);
 
 				let check_ctx = CandidateCheckContext::<T>::new(prev_context);
+				// Backing is signed for the session of the block the candidate is backed in. Right
+				// after a session change, that is the session after the one of the relay parent.
 				let signing_context = SigningContext {
 					parent_hash: relay_parent_hash,
 					session_index: shared::Pallet::<T>::session_index(),
@@ -1071,15 +1076,18 @@ impl<T: Config> Pallet<T> {
 
 	/// Cleans up all paras pending availability that are in the given list of disputed candidates.
 	///
-	/// Returns a vector of cleaned-up core IDs.
-	pub(crate) fn collect_disputed(disputed: &BTreeSet<CandidateHash>) -> Vec<CoreIndex> {
+	/// Returns a vector of cleaned-up core IDs, along with the hash of the candidate each was
+	/// occupied by.
+	pub(crate) fn collect_disputed(
+		disputed: &BTreeSet<CandidateHash>,
+	) -> Vec<(CoreIndex, CandidateHash)> {
 		let mut cleaned_up_ids = Vec::new();
 		let mut cleaned_up_cores = Vec::new();
 
 		for (para_id, pending_record) in <PendingAvailability<T>>::iter() {
 			if disputed.contains(&pending_record.hash) {
 				cleaned_up_ids.push(para_id);
-				cleaned_up_cores.push(pending_record.core);
+				cleaned_up_cores.push((pending_record.core, pending_record.hash));
 			}
 		}
 
@@ -1091,6 +1099,17 @@ impl<T: Config> Pallet<T> {
 		cleaned_up_cores
 	}
 
+	/// Returns the cores of all paras pending availability whose candidate is deemed disputed by
+	/// `is_disputed`.
+	///
+	/// Unlike [`Self::collect_disputed`], this leaves the pending candidates untouched.
+	pub(crate) fn disputed_cores(is_disputed: impl Fn(CandidateHash) -> bool) -> Vec<CoreIndex> {
+		<PendingAvailability<T>>::iter_values()
+			.filter(|pending_record| is_disputed(pending_record.hash))
+			.map(|pending_record| pending_record.core)
+			.collect()
+	}
+
 	/// Forcibly enact the candidate with the given ID as though it had been deemed available
 	/// by bitfields.
 	///
@@ -1116,6 +1135,50 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The availability progress of each core with a candidate pending availability, as the number
+	/// of availability votes the candidate collected and the number it needs to become available.
+	///
+	/// Only votes from bitfields accepted by the paras inherent are counted, so bits of invalid
+	/// bitfields or of disputed cores never make it into the numerator. Cores without a candidate
+	/// pending availability are omitted.
+	pub fn core_availability_progress() -> BTreeMap<CoreIndex, (u32, u32)> {
+		let threshold =
+			availability_threshold(shared::Pallet::<T>::active_validator_keys().len()) as u32;
+
+		<PendingAvailability<T>>::iter_values()
+			.map(|pending| {
+				(pending.core, (pending.availability_votes.count_ones() as u32, threshold))
+			})
+			.collect()
+	}
+
+	/// An estimate of the number of blocks until the candidate pending availability on `core`
+	/// becomes available, freeing the core.
+	///
+	/// This is a heuristic only: the availability votes the candidate collected since it was backed
+	/// are extrapolated at their average rate per block. Bitfields arriving in bursts make it
+	/// inaccurate, and the availability timeout is not taken into account, so the core may be
+	/// freed earlier. Returns `None` if there is no candidate pending availability on `core`, or
+	/// if it did not collect any vote yet, in which case progress is stalled and no estimate can
+	/// be made.
+	pub fn estimated_blocks_until_free(core: CoreIndex) -> Option<u32> {
+		let pending = <PendingAvailability<T>>::iter_values().find(|p| p.core == core)?;
+		let votes = pending.availability_votes.count_ones() as u64;
+		if votes == 0 {
+			return None
+		}
+
+		let threshold =
+			availability_threshold(shared::Pallet::<T>::active_validator_keys().len()) as u64;
+		let now = <frame_system::Pallet<T>>::block_number();
+		let elapsed: u64 = now.saturating_sub(pending.backed_in_number).saturated_into();
+
+		// The votes needed at the rate observed so far, rounded up to whole blocks.
+		let remaining = threshold.saturating_sub(votes);
+		let blocks = (remaining.saturating_mul(elapsed.max(1)) + votes - 1) / votes;
+		Some(blocks.saturated_into())
+	}
+
 	/// Returns the `CommittedCandidateReceipt` pending availability for the para provided, if any.
 	pub(crate) fn candidate_pending_availability(
 		para: ParaId,
@@ -1139,6 +1202,14 @@ const fn availability_threshold(n_validators: usize) -> usize {
 	supermajority_threshold(n_validators)
 }
 
+/// Whether the descriptor of `candidate` is signed by its collator.
+///
+/// This is the check backed candidates are subject to on inclusion, so off-chain code can use it
+/// to drop a badly signed candidate before forwarding it.
+pub fn verify_collator_signature<H: AsRef<[u8]>>(candidate: &CommittedCandidateReceipt<H>) -> bool {
+	candidate.descriptor().check_collator_signature().is_ok()
+}
+
 impl<BlockNumber> AcceptanceCheckErr<BlockNumber> {
 	/// Returns the same error so that it can be threaded through a needle of `DispatchError` and
 	/// ultimately returned from a `Dispatchable`.
@@ -1237,10 +1308,7 @@ impl<T: Config> CandidateCheckContext<T> {
 			);
 		}
 
-		ensure!(
-			backed_candidate_receipt.descriptor().check_collator_signature().is_ok(),
-			Error::<T>::NotCollatorSigned,
-		);
+		ensure!(verify_collator_signature(backed_candidate_receipt), Error::<T>::NotCollatorSigned);
 
 		let validation_code_hash = <paras::Pallet<T>>::current_code_hash(para_id)
 			// A candidate for a parachain without current validation code is not scheduled.
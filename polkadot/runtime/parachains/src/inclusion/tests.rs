@@ -353,9 +353,12 @@ fn simple_sanitize_bitfields(
 		unchecked_bitfields,
 		disputed_bitfield,
 		expected_bits,
+		None,
 		parent_hash,
 		session_index,
 		&validators,
+		None,
+		&mut Vec::new(),
 	)
 }
 /// Process a set of already sanitized bitfields.
@@ -671,6 +674,30 @@ fn availability_threshold_is_supermajority() {
 	assert_eq!(7, availability_threshold(9));
 }
 
+#[test]
+fn verify_collator_signature_works() {
+	let mut candidate = TestCandidateBuilder {
+		para_id: 1.into(),
+		pov_hash: Hash::repeat_byte(1),
+		..Default::default()
+	}
+	.build();
+
+	// not signed by the collator.
+	assert!(!verify_collator_signature(&candidate));
+
+	collator_sign_candidate(Sr25519Keyring::One, &mut candidate);
+	assert!(verify_collator_signature(&candidate));
+
+	// the commitments are not signed.
+	candidate.commitments.head_data = vec![1, 2, 3].into();
+	assert!(verify_collator_signature(&candidate));
+
+	// change the descriptor after signing.
+	candidate.descriptor.pov_hash = Hash::repeat_byte(2);
+	assert!(!verify_collator_signature(&candidate));
+}
+
 #[test]
 fn supermajority_bitfields_trigger_availability() {
 	let chain_a = ParaId::from(1_u32);
@@ -806,6 +833,12 @@ fn supermajority_bitfields_trigger_availability() {
 			})
 			.collect::<Vec<_>>();
 
+		// no votes yet, the parathread core has nothing pending.
+		assert_eq!(
+			Pallet::<Test>::core_availability_progress(),
+			BTreeMap::from([(CoreIndex(0), (0, 4)), (CoreIndex(1), (0, 4))])
+		);
+
 		let old_len = signed_bitfields.len();
 		let checked_bitfields = simple_sanitize_bitfields(
 			signed_bitfields,
@@ -818,6 +851,12 @@ fn supermajority_bitfields_trigger_availability() {
 		let v = process_bitfields(expected_bits(), checked_bitfields, core_lookup);
 		assert_eq!(vec![(CoreIndex(0), candidate_a.hash())], v);
 
+		// chain B's core is the only one still pending, with 3 of the 4 votes needed.
+		assert_eq!(
+			Pallet::<Test>::core_availability_progress(),
+			BTreeMap::from([(CoreIndex(1), (3, 4))])
+		);
+
 		// chain A had 4 signing off, which is >= threshold.
 		// chain B has 3 signing off, which is < threshold.
 		assert!(<PendingAvailability<Test>>::get(&chain_a).is_none());
@@ -858,6 +897,58 @@ fn supermajority_bitfields_trigger_availability() {
 	});
 }
 
+#[test]
+fn estimated_blocks_until_free_extrapolates_availability_progress() {
+	let chain_a = ParaId::from(1_u32);
+	let paras = vec![(chain_a, ParaKind::Parachain)];
+	let validators = vec![
+		Sr25519Keyring::Alice,
+		Sr25519Keyring::Bob,
+		Sr25519Keyring::Charlie,
+		Sr25519Keyring::Dave,
+		Sr25519Keyring::Ferdie,
+	];
+	let validator_public = validator_pubkeys(&validators);
+
+	new_test_ext(genesis_config(paras)).execute_with(|| {
+		shared::Pallet::<Test>::set_active_validators_ascending(validator_public.clone());
+		run_to_block(5, |_| None);
+
+		let candidate = TestCandidateBuilder { para_id: chain_a, ..Default::default() }.build();
+		<PendingAvailability<Test>>::insert(
+			&chain_a,
+			CandidatePendingAvailability {
+				core: CoreIndex::from(0),
+				hash: candidate.hash(),
+				descriptor: candidate.descriptor,
+				availability_votes: default_availability_votes(),
+				relay_parent_number: 2,
+				backed_in_number: 3,
+				backers: default_backing_bitfield(),
+				backing_group: GroupIndex::from(0),
+			},
+		);
+		let add_vote = |i| {
+			<PendingAvailability<Test>>::mutate(&chain_a, |pending| {
+				pending.as_mut().unwrap().availability_votes.set(i, true)
+			})
+		};
+
+		// free cores and cores without any progress have no estimate.
+		assert_eq!(ParaInclusion::estimated_blocks_until_free(CoreIndex::from(1)), None);
+		assert_eq!(ParaInclusion::estimated_blocks_until_free(CoreIndex::from(0)), None);
+
+		// 2 of the 4 needed votes in the 2 blocks since backing.
+		add_vote(0);
+		add_vote(1);
+		assert_eq!(ParaInclusion::estimated_blocks_until_free(CoreIndex::from(0)), Some(2));
+
+		// the last vote needed is expected within a block.
+		add_vote(2);
+		assert_eq!(ParaInclusion::estimated_blocks_until_free(CoreIndex::from(0)), Some(1));
+	});
+}
+
 #[test]
 fn candidate_checks() {
 	let chain_a = ParaId::from(1_u32);
@@ -1103,6 +1194,7 @@ fn candidate_checks() {
 
 			// change the candidate after signing.
 			candidate.descriptor.pov_hash = Hash::repeat_byte(2);
+			assert!(!verify_collator_signature(&candidate));
 
 			let backed = back_candidate(
 				candidate,
@@ -2033,6 +2125,122 @@ fn can_include_candidate_with_ok_code_upgrade() {
 	});
 }
 
+#[test]
+fn backing_signed_for_current_session_is_accepted_at_session_boundary() {
+	let chain_a = ParaId::from(1_u32);
+
+	// The block number of the relay-parent for testing.
+	const RELAY_PARENT_NUM: BlockNumber = 4;
+
+	let paras = vec![(chain_a, ParaKind::Parachain)];
+	let validators = vec![
+		Sr25519Keyring::Alice,
+		Sr25519Keyring::Bob,
+		Sr25519Keyring::Charlie,
+		Sr25519Keyring::Dave,
+		Sr25519Keyring::Ferdie,
+	];
+	let keystore: KeystorePtr = Arc::new(LocalKeystore::in_memory());
+	for validator in validators.iter() {
+		Keystore::sr25519_generate_new(
+			&*keystore,
+			PARACHAIN_KEY_TYPE_ID,
+			Some(&validator.to_seed()),
+		)
+		.unwrap();
+	}
+	let validator_public = validator_pubkeys(&validators);
+
+	new_test_ext(genesis_config(paras)).execute_with(|| {
+		shared::Pallet::<Test>::set_active_validators_ascending(validator_public.clone());
+		shared::Pallet::<Test>::set_session_index(5);
+
+		run_to_block(5, |_| None);
+
+		let group_validators = |group_index: GroupIndex| {
+			match group_index {
+				group_index if group_index == GroupIndex::from(0) => Some(vec![0, 1, 2, 3, 4]),
+				_ => panic!("Group index out of bounds for 1 parachain"),
+			}
+			.map(|vs| vs.into_iter().map(ValidatorIndex).collect::<Vec<_>>())
+		};
+
+		// When processing candidates, we compute the group index from scheduler.
+		let validator_groups = vec![vec![
+			ValidatorIndex(0),
+			ValidatorIndex(1),
+			ValidatorIndex(2),
+			ValidatorIndex(3),
+			ValidatorIndex(4),
+		]];
+		Scheduler::set_validator_groups(validator_groups);
+
+		let allowed_relay_parents = default_allowed_relay_parent_tracker();
+		let chain_a_assignment = (chain_a, CoreIndex::from(0));
+		let mut candidate_a = TestCandidateBuilder {
+			para_id: chain_a,
+			relay_parent: System::parent_hash(),
+			pov_hash: Hash::repeat_byte(1),
+			persisted_validation_data_hash: make_vdata_hash(chain_a).unwrap(),
+			hrmp_watermark: RELAY_PARENT_NUM,
+			..Default::default()
+		}
+		.build();
+		collator_sign_candidate(Sr25519Keyring::One, &mut candidate_a);
+
+		// The validators of the given set sign for the given session.
+		let back_for_session = |validators: &[Sr25519Keyring], session_index| {
+			back_candidate(
+				candidate_a.clone(),
+				validators,
+				group_validators(GroupIndex::from(0)).unwrap().as_ref(),
+				&keystore,
+				&SigningContext { parent_hash: System::parent_hash(), session_index },
+				BackingKind::Threshold,
+				None,
+			)
+		};
+
+		// Session 5 started with the current block, after the relay parent.
+		scheduler::SessionStartBlock::<Test>::set(5);
+
+		// Session 4 had the same validators, in another order. Backing signed for the session
+		// of the relay parent is rejected.
+		let mut previous_validators = validators.clone();
+		previous_validators.rotate_left(1);
+		assert_noop!(
+			ParaInclusion::process_candidates(
+				&allowed_relay_parents,
+				vec![(back_for_session(&previous_validators, 4), chain_a_assignment.1)],
+				&group_validators,
+				false
+			),
+			Error::<Test>::InvalidBacking
+		);
+		assert_noop!(
+			ParaInclusion::process_candidates(
+				&allowed_relay_parents,
+				vec![(back_for_session(&validators, 4), chain_a_assignment.1)],
+				&group_validators,
+				false
+			),
+			Error::<Test>::InvalidBacking
+		);
+
+		// Backing is signed for the session of the block the candidate is backed in.
+		let ProcessedCandidates { core_indices: occupied_cores, .. } =
+			ParaInclusion::process_candidates(
+				&allowed_relay_parents,
+				vec![(back_for_session(&validators, 5), chain_a_assignment.1)],
+				&group_validators,
+				false,
+			)
+			.expect("backing signed for the session of the current block");
+
+		assert_eq!(occupied_cores, vec![(CoreIndex::from(0), chain_a)]);
+	});
+}
+
 #[test]
 fn check_allowed_relay_parents() {
 	let chain_a = ParaId::from(1);
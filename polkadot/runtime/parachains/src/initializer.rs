@@ -352,5 +352,7 @@ impl<T: pallet_session::Config + Config> OneSessionHandler<T::AccountId> for Pal
 		<Pallet<T>>::on_new_session(changed, session_index, validators, Some(queued));
 	}
 
-	fn on_disabled(_i: u32) {}
+	fn on_disabled(i: u32) {
+		shared::Pallet::<T>::note_disabled_by_session(i);
+	}
 }
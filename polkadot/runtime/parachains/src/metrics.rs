@@ -71,6 +71,18 @@ impl Metrics {
 		self.candidates_processed.with_label_values(&["total"]).inc_by(value);
 	}
 
+	/// Increment the number of candidates dropped from paras that still got some of their
+	/// candidates through sanitization, i.e. whose batch was only partially accepted.
+	pub fn on_candidates_trimmed(&self, value: u64) {
+		self.candidates_processed.with_label_values(&["trimmed"]).inc_by(value);
+	}
+
+	/// Increment the number of scheduled cores left without a candidate, as candidates were
+	/// dropped to fit the block weight limit.
+	pub fn on_cores_weight_filtered(&self, value: u64) {
+		self.candidates_processed.with_label_values(&["weight_filtered"]).inc_by(value);
+	}
+
 	/// Sample the relay chain freeze events causing runtime to not process candidates in
 	/// `process_inherent_data`.
 	pub fn on_relay_chain_freeze(&self) {
@@ -97,6 +109,22 @@ impl Metrics {
 		self.bitfields_signature_checks.with_label_values(&["invalid"]).inc_by(1);
 	}
 
+	/// Increment the number of bitfields whose signature is valid for the parent of the expected
+	/// parent block.
+	pub fn on_stale_bitfield_signature(&self) {
+		self.bitfields_signature_checks
+			.with_label_values(&["stale_parent_hash"])
+			.inc_by(1);
+	}
+
+	/// Increment the number of bitfields whose signature could not be checked, as the validator
+	/// index is not in the active validator set.
+	pub fn on_unknown_bitfield_validator(&self) {
+		self.bitfields_signature_checks
+			.with_label_values(&["unknown_validator"])
+			.inc_by(1);
+	}
+
 	pub fn on_signature_check_complete(&self, val: u128) {
 		self.signature_timings.observe(val);
 	}
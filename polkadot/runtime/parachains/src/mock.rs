@@ -50,7 +50,7 @@ use sp_runtime::{
 	transaction_validity::TransactionPriority,
 	BuildStorage, FixedU128, Perbill, Permill,
 };
-use sp_std::collections::vec_deque::VecDeque;
+use sp_std::collections::{btree_map::BTreeMap, vec_deque::VecDeque};
 use std::{cell::RefCell, collections::HashMap};
 use xcm::v4::{Assets, Location, SendError, SendResult, SendXcm, Xcm, XcmHash};
 
@@ -258,10 +258,17 @@ impl crate::hrmp::Config for Test {
 	type WeightInfo = crate::hrmp::TestWeightInfo;
 }
 
+parameter_types! {
+	pub static DisputesTieBreak: crate::disputes::DisputeTieBreak = Default::default();
+}
+
 impl crate::disputes::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RewardValidators = Self;
 	type SlashingHandler = Self;
+	type TieBreak = DisputesTieBreak;
+	type VerifiedBackingVotes = ParaInherent;
+	type PrioritizeDisputeOrigin = frame_system::EnsureRoot<AccountId>;
 	type WeightInfo = crate::disputes::TestWeightInfo;
 }
 
@@ -314,6 +321,7 @@ impl crate::disputes::SlashingHandler<BlockNumber> for Test {
 
 impl crate::scheduler::Config for Test {
 	type AssignmentProvider = MockAssigner;
+	type RuntimeEvent = RuntimeEvent;
 }
 
 pub struct TestMessageQueueWeight;
@@ -380,7 +388,12 @@ impl assigner_on_demand::Config for Test {
 	type WeightInfo = crate::assigner_on_demand::TestWeightInfo;
 }
 
-impl assigner_coretime::Config for Test {}
+impl assigner_coretime::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type MaxForcedAssignments = ConstU32<2>;
+	type WeightInfo = crate::assigner_coretime::TestWeightInfo;
+}
 
 parameter_types! {
 	pub const BrokerId: u32 = 10u32;
@@ -416,8 +429,38 @@ impl crate::inclusion::Config for Test {
 	type MessageQueue = MessageQueue;
 }
 
+parameter_types! {
+	pub static InherentOnChainVotesRetention: crate::paras_inherent::OnChainVotesRetention =
+		Default::default();
+	pub static InherentDisabledValidatorsGracePeriod: BlockNumber = 0;
+}
+
 impl crate::paras_inherent::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = crate::paras_inherent::TestWeightInfo;
+	type OnChainVotesRetention = InherentOnChainVotesRetention;
+	type DisabledValidatorsGracePeriod = InherentDisabledValidatorsGracePeriod;
+	type ClearScrapedVotesOrigin = frame_system::EnsureRoot<AccountId>;
+	type ForceDisputedCoresOrigin = frame_system::EnsureRoot<AccountId>;
+	type OnBitfieldCredited = TestOnBitfieldCredited;
+}
+
+thread_local! {
+	pub static BITFIELDS_CREDITED: RefCell<Vec<(SessionIndex, BTreeMap<ValidatorIndex, u32>)>> =
+		RefCell::new(Vec::new());
+}
+
+/// Records the bitfield counts of the ended sessions in [`BITFIELDS_CREDITED`].
+pub struct TestOnBitfieldCredited;
+
+impl crate::paras_inherent::OnValidatorLiveness for TestOnBitfieldCredited {
+	fn on_validator_liveness(session: SessionIndex, bitfields: BTreeMap<ValidatorIndex, u32>) {
+		BITFIELDS_CREDITED.with(|r| r.borrow_mut().push((session, bitfields)))
+	}
+}
+
+pub fn bitfields_credited() -> Vec<(SessionIndex, BTreeMap<ValidatorIndex, u32>)> {
+	BITFIELDS_CREDITED.with(|r| r.borrow().clone())
 }
 
 pub struct MockValidatorSet;
@@ -465,6 +508,9 @@ pub mod mock_assigner {
 
 		#[pallet::storage]
 		pub(super) type MockCoreCount<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+		#[pallet::storage]
+		pub(super) type MockSeededCores<T: Config> = StorageValue<_, (u32, u32), OptionQuery>;
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -479,6 +525,11 @@ pub mod mock_assigner {
 		pub fn set_core_count(count: u32) {
 			MockCoreCount::<T>::set(Some(count));
 		}
+
+		/// The range of cores the scheduler last asked to seed, if any.
+		pub fn seeded_cores() -> Option<(u32, u32)> {
+			MockSeededCores::<T>::get()
+		}
 	}
 
 	impl<T: Config> AssignmentProvider<BlockNumber> for Pallet<T> {
@@ -510,6 +561,10 @@ pub mod mock_assigner {
 		fn session_core_count() -> u32 {
 			MockCoreCount::<T>::get().unwrap_or(5)
 		}
+
+		fn seed_new_cores(from: u32, to: u32) {
+			MockSeededCores::<T>::set(Some((from, to)));
+		}
 	}
 }
 
@@ -640,6 +695,7 @@ pub fn new_test_ext(state: MockGenesisConfig) -> TestExternalities {
 
 	BACKING_REWARDS.with(|r| r.borrow_mut().clear());
 	AVAILABILITY_REWARDS.with(|r| r.borrow_mut().clear());
+	BITFIELDS_CREDITED.with(|r| r.borrow_mut().clear());
 
 	let mut t = state.system.build_storage().unwrap();
 	state.configuration.assimilate_storage(&mut t).unwrap();
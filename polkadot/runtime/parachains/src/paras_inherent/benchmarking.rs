@@ -16,7 +16,7 @@
 
 use super::*;
 use crate::{inclusion, ParaId};
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
 use frame_system::RawOrigin;
 use sp_std::{cmp::min, collections::btree_map::BTreeMap};
 
@@ -76,6 +76,13 @@ benchmarks! {
 		benchmark.disputes.clear();
 
 		benchmark.bitfields.push(bitfield);
+
+		// Worst case, a bitfield of every validator was credited in the session already.
+		let validators = shared::Pallet::<T>::active_validator_keys().len() as u32;
+		BitfieldsCredited::<T>::put((
+			shared::Pallet::<T>::session_index(),
+			(0..validators).map(|index| (ValidatorIndex(index), 1)).collect::<BTreeMap<_, _>>(),
+		));
 	}: enter(RawOrigin::None, benchmark)
 	verify {
 		// Assert that the block was not discarded
@@ -218,6 +225,33 @@ benchmarks! {
 			cores_with_backed.len()
 		);
 	}
+
+	force_clear_scraped_votes {
+		let origin = T::ClearScrapedVotesOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		OnChainVotes::<T>::put(ScrapedOnChainVotes {
+			session: 0,
+			backing_validators_per_candidate: Vec::new(),
+			disputes: Vec::new(),
+		});
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(OnChainVotes::<T>::get().is_none());
+	}
+
+	force_set_disputed_cores {
+		let c in 0..100;
+
+		let origin = T::ForceDisputedCoresOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		scheduler::AvailabilityCores::<T>::set(
+			(0..c).map(|_| scheduler::CoreOccupied::Free).collect(),
+		);
+		let cores = (0..c).map(CoreIndex).collect::<Vec<_>>();
+	}: _<T::RuntimeOrigin>(origin, cores)
+	verify {
+		assert_eq!(ForcedDisputedCores::<T>::get().len(), c as usize);
+	}
 }
 
 impl_benchmark_test_suite!(
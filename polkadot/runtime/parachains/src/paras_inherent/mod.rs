@@ -23,7 +23,7 @@
 
 use crate::{
 	configuration,
-	disputes::DisputesHandler,
+	disputes::{DisputesHandler, VerifiedBackingVotes},
 	inclusion,
 	inclusion::CandidateCheckContext,
 	initializer,
@@ -38,6 +38,7 @@ use frame_support::{
 	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
 	inherent::{InherentData, InherentIdentifier, MakeFatalError, ProvideInherent},
 	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
 	traits::Randomness,
 };
 use frame_system::pallet_prelude::*;
@@ -52,9 +53,11 @@ use primitives::{
 };
 use rand::{seq::SliceRandom, SeedableRng};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{Header as HeaderT, One};
+use sp_runtime::traits::{CheckedSub, Header as HeaderT, One, Zero};
 use sp_std::{
-	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	cmp::Reverse,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet, vec_deque::VecDeque},
+	mem,
 	prelude::*,
 	vec::Vec,
 };
@@ -80,10 +83,13 @@ mod tests;
 
 const LOG_TARGET: &str = "runtime::inclusion-inherent";
 
+/// The number of recent blocks [`CoreInclusionHistory`] keeps track of.
+pub const CORE_INCLUSION_HISTORY_LENGTH: usize = 64;
+
 /// A bitfield concerning concluded disputes for candidates
 /// associated to the core index equivalent to the bit position.
 #[derive(Default, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
-pub(crate) struct DisputedBitfield(pub(crate) BitVec<u8, bitvec::order::Lsb0>);
+pub struct DisputedBitfield(pub BitVec<u8, bitvec::order::Lsb0>);
 
 impl From<BitVec<u8, bitvec::order::Lsb0>> for DisputedBitfield {
 	fn from(inner: BitVec<u8, bitvec::order::Lsb0>) -> Self {
@@ -108,6 +114,61 @@ pub enum ProcessInherentDataContext {
 	/// Checks the InherentWeight invariant.
 	Enter,
 }
+
+/// How long the votes scraped on chain are kept around.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum OnChainVotesRetention {
+	/// Only keep the votes in [`OnChainVotes`], tagged with the session they were recorded in and
+	/// replaced by those of the next block.
+	#[default]
+	Session,
+	/// Additionally keep the votes of the last given number of blocks in [`RecentOnChainVotes`],
+	/// regardless of session changes.
+	Blocks(u32),
+}
+
+/// Whether the candidate of a dispute scraped on chain was included on chain.
+///
+/// Disputes may be raised for candidates the relay chain never included, e.g. ones only backed off
+/// chain. They are recorded all the same, but consumers of the on chain votes assuming the
+/// inclusion of the disputed candidates can tell them apart with this.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ScrapedDisputeInclusion {
+	/// The session of the dispute.
+	pub session: SessionIndex,
+	/// The disputed candidate.
+	pub candidate_hash: CandidateHash,
+	/// Whether the disputed candidate was included on chain.
+	pub candidate_included: bool,
+}
+
+/// Handler for the availability participation of validators, e.g. to reward validators for the
+/// bitfields they submitted.
+pub trait OnValidatorLiveness {
+	/// `session` ended with the given number of bitfields included of each validator. Validators
+	/// without any bitfield included are left out.
+	fn on_validator_liveness(session: SessionIndex, bitfields: BTreeMap<ValidatorIndex, u32>);
+}
+
+impl OnValidatorLiveness for () {
+	fn on_validator_liveness(_session: SessionIndex, _bitfields: BTreeMap<ValidatorIndex, u32>) {}
+}
+
+/// A problem with inherent data found by [`Pallet::validate_inherent`].
+#[derive(PartialEq, Eq, RuntimeDebug)]
+pub enum InherentError {
+	/// [`Pallet::enter`] would reject the inherent data with the given error.
+	Rejected(DispatchError),
+	/// `count` of the submitted dispute statement sets would be dropped.
+	DisputesDropped { count: usize },
+	/// All `count` submitted dispute statement sets are for sessions after the current one and
+	/// would be dropped.
+	AllDisputesFuture { count: usize },
+	/// `count` of the submitted bitfields would be dropped.
+	BitfieldsDropped { count: usize },
+	/// `count` of the submitted backed candidates would be dropped.
+	CandidatesDropped { count: usize },
+}
 pub use pallet::*;
 
 #[frame_support::pallet]
@@ -123,8 +184,43 @@ pub mod pallet {
 	pub trait Config:
 		inclusion::Config + scheduler::Config + initializer::Config + pallet_babe::Config
 	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// How long the votes scraped on chain are kept around. `()` only keeps those of the
+		/// latest block.
+		type OnChainVotesRetention: Get<OnChainVotesRetention>;
+
+		/// The number of blocks backing votes of a newly disabled validator are still accepted,
+		/// so that candidates backed just before the disabling can be included. `()` drops them
+		/// right away.
+		type DisabledValidatorsGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The origin allowed to clear the votes scraped on chain.
+		type ClearScrapedVotesOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin allowed to force cores disputed.
+		type ForceDisputedCoresOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Informed of the number of bitfields included of each validator once a session ended.
+		/// `()` ignores them.
+		type OnBitfieldCredited: OnValidatorLiveness;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The backing votes of the given disabled validators were dropped from a candidate.
+		DisabledVotesDropped { candidate_hash: CandidateHash, validators: Vec<ValidatorIndex> },
+		/// All the votes scraped on chain were cleared.
+		ScrapedVotesCleared,
+		/// The given cores were forced disputed for the next block.
+		DisputedCoresForced { cores: Vec<CoreIndex> },
+		/// The given core was freed, as the candidate occupying it concluded invalid in a dispute.
+		CoreFreedByInvalidity { core: CoreIndex, candidate_hash: CandidateHash },
 	}
 
 	#[pallet::error]
@@ -142,12 +238,15 @@ pub mod pallet {
 		DisputeStatementsUnsortedOrDuplicates,
 		/// A dispute statement was invalid.
 		DisputeInvalid,
-		/// A candidate was backed by a disabled validator
+		/// A candidate was backed by a disabled validator. Not returned anymore, such votes are
+		/// dropped instead.
 		BackedByDisabled,
 		/// A candidate was backed even though the paraid was not scheduled.
 		BackedOnUnscheduledCore,
 		/// Too many candidates supplied.
 		UnscheduledCandidate,
+		/// More cores were forced disputed than there are availability cores.
+		TooManyDisputedCores,
 	}
 
 	/// Whether the paras inherent was included within this block.
@@ -159,16 +258,72 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type Included<T> = StorageValue<_, ()>;
 
+	/// The number of bitfields of each validator included in the session they were counted in.
+	/// Handed to [`Config::OnBitfieldCredited`] once the next session starts.
+	#[pallet::storage]
+	pub(crate) type BitfieldsCredited<T> =
+		StorageValue<_, (SessionIndex, BTreeMap<ValidatorIndex, u32>), ValueQuery>;
+
+	/// The block each disabled validator was first seen disabled in, in the session they were
+	/// noted in. Only kept with a non-zero [`Config::DisabledValidatorsGracePeriod`].
+	#[pallet::storage]
+	pub(crate) type DisabledSince<T: Config> =
+		StorageValue<_, (SessionIndex, BTreeMap<ValidatorIndex, BlockNumberFor<T>>), ValueQuery>;
+
+	/// The cores which received a backed candidate in each of the last
+	/// [`CORE_INCLUSION_HISTORY_LENGTH`] blocks, one bit per core and bitfield per block, oldest
+	/// first.
+	#[pallet::storage]
+	pub(crate) type CoreInclusionHistory<T> =
+		StorageValue<_, VecDeque<BitVec<u8, bitvec::order::Lsb0>>, ValueQuery>;
+
+	/// The cores forced disputed by [`Pallet::force_set_disputed_cores`], to be masked in the
+	/// bitfields of the next processed inherent data.
+	#[pallet::storage]
+	#[pallet::getter(fn forced_disputed_cores)]
+	pub(crate) type ForcedDisputedCores<T> = StorageValue<_, Vec<CoreIndex>, ValueQuery>;
+
 	/// Scraped on chain data for extracting resolved disputes as well as backing votes.
 	#[pallet::storage]
 	#[pallet::getter(fn on_chain_votes)]
 	pub(crate) type OnChainVotes<T: Config> = StorageValue<_, ScrapedOnChainVotes<T::Hash>>;
 
+	/// Whether the candidates of the disputes of [`OnChainVotes`] were included on chain, in the
+	/// order of the disputes.
+	#[pallet::storage]
+	#[pallet::getter(fn on_chain_disputes_inclusion)]
+	pub(crate) type OnChainDisputesInclusion<T: Config> =
+		StorageValue<_, Vec<ScrapedDisputeInclusion>, ValueQuery>;
+
+	/// The on chain votes of the last blocks along with the block they were recorded in, oldest
+	/// first. Only kept with [`OnChainVotesRetention::Blocks`].
+	#[pallet::storage]
+	#[pallet::getter(fn recent_on_chain_votes)]
+	pub(crate) type RecentOnChainVotes<T: Config> =
+		StorageValue<_, Vec<(BlockNumberFor<T>, ScrapedOnChainVotes<T::Hash>)>, ValueQuery>;
+
 	/// Update the disputes statements set part of the on-chain votes.
 	pub(crate) fn set_scrapable_on_chain_disputes<T: Config>(
 		session: SessionIndex,
 		checked_disputes: CheckedMultiDisputeStatementSet,
 	) {
+		let disputes_inclusion = checked_disputes
+			.iter()
+			.map(|checked| {
+				let set: &DisputeStatementSet = checked.as_ref();
+				ScrapedDisputeInclusion {
+					session: set.session,
+					candidate_hash: set.candidate_hash,
+					candidate_included: T::DisputesHandler::included_state(
+						set.session,
+						set.candidate_hash,
+					)
+					.is_some(),
+				}
+			})
+			.collect::<Vec<_>>();
+		OnChainDisputesInclusion::<T>::put(disputes_inclusion);
+
 		crate::paras_inherent::OnChainVotes::<T>::mutate(move |value| {
 			let disputes =
 				checked_disputes.into_iter().map(DisputeStatementSet::from).collect::<Vec<_>>();
@@ -208,13 +363,21 @@ pub mod pallet {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
-			T::DbWeight::get().reads_writes(1, 1) // in `on_finalize`.
+			// in `on_finalize`.
+			match T::OnChainVotesRetention::get() {
+				OnChainVotesRetention::Session => T::DbWeight::get().reads_writes(1, 1),
+				OnChainVotesRetention::Blocks(_) => T::DbWeight::get().reads_writes(3, 2),
+			}
 		}
 
-		fn on_finalize(_: BlockNumberFor<T>) {
+		fn on_finalize(now: BlockNumberFor<T>) {
 			if Included::<T>::take().is_none() {
 				panic!("Bitfields and heads must be included every block");
 			}
+
+			if let OnChainVotesRetention::Blocks(retention) = T::OnChainVotesRetention::get() {
+				note_recent_on_chain_votes::<T>(now, retention);
+			}
 		}
 	}
 
@@ -288,10 +451,213 @@ pub mod pallet {
 			Self::process_inherent_data(data, ProcessInherentDataContext::Enter)
 				.map(|(_processed, post_info)| post_info)
 		}
+
+		/// Clear the votes scraped on chain, including the ones kept for previous blocks.
+		///
+		/// Meant for recovery only, e.g. after the scraped votes of a session got corrupted. Votes
+		/// are scraped again from the next inherent on.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_clear_scraped_votes())]
+		pub fn force_clear_scraped_votes(origin: OriginFor<T>) -> DispatchResult {
+			T::ClearScrapedVotesOrigin::ensure_origin(origin)?;
+			OnChainVotes::<T>::kill();
+			OnChainDisputesInclusion::<T>::kill();
+			RecentOnChainVotes::<T>::kill();
+			Self::deposit_event(Event::ScrapedVotesCleared);
+			Ok(())
+		}
+
+		/// Mark the given cores disputed for the next block, masking their bits in the submitted
+		/// bitfields as if disputes concluded against their candidates.
+		///
+		/// Meant for recovery only and independent of organic disputes: the cores are not freed,
+		/// only no availability is accounted to them. Replaces any cores forced before. At most as
+		/// many cores as there are availability cores may be given, which bounds the weight.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_set_disputed_cores(cores.len() as u32))]
+		pub fn force_set_disputed_cores(
+			origin: OriginFor<T>,
+			cores: Vec<CoreIndex>,
+		) -> DispatchResult {
+			T::ForceDisputedCoresOrigin::ensure_origin(origin)?;
+			let n_cores = scheduler::AvailabilityCores::<T>::decode_len().unwrap_or(0);
+			ensure!(cores.len() <= n_cores, Error::<T>::TooManyDisputedCores);
+			ForcedDisputedCores::<T>::put(&cores);
+			Self::deposit_event(Event::DisputedCoresForced { cores });
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Whether `core` received a backed candidate in each of the last
+	/// [`CORE_INCLUSION_HISTORY_LENGTH`] blocks, one bit per block, oldest first.
+	pub fn core_inclusion_history(core: CoreIndex) -> BitVec<u8, bitvec::order::Lsb0> {
+		CoreInclusionHistory::<T>::get()
+			.iter()
+			.map(|block| block.get(core.0 as usize).map_or(false, |backed| *backed))
+			.collect()
+	}
+
+	/// The number of bitfields of `validator` included in the current session.
+	pub fn bitfields_credited(validator: ValidatorIndex) -> u32 {
+		let (session, counts) = BitfieldsCredited::<T>::get();
+		if session != <shared::Pallet<T>>::session_index() {
+			return 0
+		}
+		counts.get(&validator).copied().unwrap_or(0)
+	}
+
+	/// The weight of the disputes, bitfields and backed candidates, in that order, of the given
+	/// inherent data.
+	///
+	/// Computed on demand rather than kept in storage. The inherent data of a block was filtered
+	/// when the block was authored, so the breakdown of the data a block carries is the one it
+	/// applied.
+	pub fn inherent_weight_breakdown(
+		data: &ParachainsInherentData<HeaderFor<T>>,
+	) -> (Weight, Weight, Weight) {
+		(
+			multi_dispute_statement_sets_weight::<T>(&data.disputes),
+			signed_bitfields_weight::<T>(&data.bitfields),
+			backed_candidates_weight::<T>(&data.backed_candidates),
+		)
+	}
+
+	/// Check whether a candidate of `para_id` backed on `core` with the given `relay_parent` would
+	/// be kept by the sanitization of the inherent data, without needing the backed candidate.
+	///
+	/// Only the allowed relay parents, the scheduling of `para_id` and the assignment of `core` are
+	/// checked against the current state. Backing votes, disputes and the per-para core cap are
+	/// not, so an `Ok` is no guarantee that a backed candidate will be included.
+	pub fn would_accept_candidate(
+		para_id: ParaId,
+		core: CoreIndex,
+		relay_parent: T::Hash,
+	) -> Result<(), DropReason> {
+		let allowed_relay_parents = <shared::Pallet<T>>::allowed_relay_parents();
+		let Some((_, relay_parent_number)) = allowed_relay_parents.acquire_info(relay_parent, None)
+		else {
+			return Err(DropReason::RelayParentNotAllowed)
+		};
+
+		let scheduled_cores = <scheduler::Pallet<T>>::scheduled_paras()
+			.filter_map(|(core_idx, id)| (id == para_id).then_some(core_idx))
+			.collect::<BTreeSet<_>>();
+		if scheduled_cores.is_empty() {
+			return Err(DropReason::Unscheduled)
+		}
+		if !scheduled_cores.contains(&core) {
+			return Err(DropReason::CoreNotScheduled)
+		}
+
+		let core_index_enabled = configuration::Pallet::<T>::config()
+			.node_features
+			.get(FeatureIndex::ElasticScalingMVP as usize)
+			.map(|b| *b)
+			.unwrap_or(false);
+		if core_index_enabled {
+			// The backing group is looked up the same way as for an injected core index.
+			<scheduler::Pallet<T>>::group_assigned_to_core(core, relay_parent_number + One::one())
+				.ok_or(DropReason::CoreNotScheduled)?;
+		} else if scheduled_cores.len() > 1 {
+			// Without an injected core index there is no telling which core is meant.
+			return Err(DropReason::Unscheduled)
+		}
+
+		Ok(())
+	}
+
+	/// The maximum weight and encoded length of the inherent data [`Self::enter`] accepts.
+	///
+	/// The weight is the one available to the `Mandatory` dispatch class, with its proof size
+	/// adjusted to the maximum block length, as we are tracking the transaction size.
+	pub fn inherent_weight_limits() -> (Weight, u32) {
+		let dispatch_class = DispatchClass::Mandatory;
+		let max_block_weight_full = <T as frame_system::Config>::BlockWeights::get();
+		log::debug!(target: LOG_TARGET, "Max block weight: {}", max_block_weight_full.max_block);
+		// Get max block weight for the mandatory class if defined, otherwise total max weight
+		// of the block.
+		let max_weight = max_block_weight_full
+			.per_class
+			.get(dispatch_class)
+			.max_total
+			.unwrap_or(max_block_weight_full.max_block);
+		log::debug!(target: LOG_TARGET, "Used max block time weight: {}", max_weight);
+
+		let max_block_size_full = <T as frame_system::Config>::BlockLength::get();
+		let max_block_size = *max_block_size_full.max.get(dispatch_class);
+		log::debug!(target: LOG_TARGET, "Used max block size: {}", max_block_size);
+
+		// Adjust proof size to max block size as we are tracking tx size.
+		(max_weight.set_proof_size(max_block_size as u64), max_block_size)
+	}
+
+	/// The bitfield of cores whose candidate pending availability is disputed at the current block.
+	///
+	/// A core is disputed if the dispute for its candidate concluded invalid in the current
+	/// session, the same criterion [`Self::enter`] uses, or if it was forced disputed with
+	/// [`Self::force_set_disputed_cores`]. Validators must not sign availability bits for these
+	/// cores. The bitfield has one bit per availability core and is all zeros if there are no
+	/// such disputes.
+	pub fn current_disputed_bitfield() -> DisputedBitfield {
+		let current_session = <shared::Pallet<T>>::session_index();
+		let expected_bits = <scheduler::Pallet<T>>::availability_cores().len();
+		let disputed_cores = <inclusion::Pallet<T>>::disputed_cores(|candidate_hash| {
+			T::DisputesHandler::concluded_invalid(current_session, candidate_hash)
+		});
+		let forced_disputed = ForcedDisputedCores::<T>::get();
+		create_disputed_bitfield(expected_bits, disputed_cores.iter().chain(forced_disputed.iter()))
+	}
+
+	/// Check whether [`Self::enter`] would accept `data` as is, without altering any state.
+	///
+	/// This runs the same checks as [`Self::enter`] (bitfield signatures, candidate scheduling,
+	/// dispute sessions and weight) in a storage transaction that is rolled back afterwards, so
+	/// the outcome always agrees with block execution. Data that would only be accepted after
+	/// dropping some of it is reported as well, disputes first, then bitfields, then candidates.
+	pub fn validate_inherent(
+		data: ParachainsInherentData<HeaderFor<T>>,
+	) -> Result<(), InherentError> {
+		if Included::<T>::exists() {
+			return Err(InherentError::Rejected(Error::<T>::TooManyInclusionInherents.into()))
+		}
+
+		let disputes = data.disputes.len();
+		let only_future_disputes =
+			all_disputes_future(&data.disputes, <shared::Pallet<T>>::session_index());
+		let bitfields = data.bitfields.len();
+		let backed_candidates = data.backed_candidates.len();
+
+		let (processed, _) = with_transaction(|| {
+			TransactionOutcome::Rollback(Self::process_inherent_data(
+				data,
+				ProcessInherentDataContext::Enter,
+			))
+		})
+		.map_err(|err| InherentError::Rejected(err.error))?;
+
+		if only_future_disputes {
+			return Err(InherentError::AllDisputesFuture { count: disputes })
+		}
+		if processed.disputes.len() < disputes {
+			return Err(InherentError::DisputesDropped {
+				count: disputes - processed.disputes.len(),
+			})
+		}
+		if processed.bitfields.len() < bitfields {
+			return Err(InherentError::BitfieldsDropped {
+				count: bitfields - processed.bitfields.len(),
+			})
+		}
+		if processed.backed_candidates.len() < backed_candidates {
+			return Err(InherentError::CandidatesDropped {
+				count: backed_candidates - processed.backed_candidates.len(),
+			})
+		}
+		Ok(())
+	}
+
 	/// Create the `ParachainsInherentData` that gets passed to [`Self::enter`] in
 	/// [`Self::create_inherent`]. This code is pulled out of [`Self::create_inherent`] so it can be
 	/// unit tested.
@@ -401,26 +767,7 @@ impl<T: Config> Pallet<T> {
 		// dispatch class, the upper layers impose no limit on the weight of this inherent, instead
 		// we limit ourselves and make sure to stay within reasonable bounds. It might make sense
 		// to subtract BlockWeights::base_block to reduce chances of becoming overweight.
-		let max_block_weight = {
-			let dispatch_class = DispatchClass::Mandatory;
-			let max_block_weight_full = <T as frame_system::Config>::BlockWeights::get();
-			log::debug!(target: LOG_TARGET, "Max block weight: {}", max_block_weight_full.max_block);
-			// Get max block weight for the mandatory class if defined, otherwise total max weight
-			// of the block.
-			let max_weight = max_block_weight_full
-				.per_class
-				.get(dispatch_class)
-				.max_total
-				.unwrap_or(max_block_weight_full.max_block);
-			log::debug!(target: LOG_TARGET, "Used max block time weight: {}", max_weight);
-
-			let max_block_size_full = <T as frame_system::Config>::BlockLength::get();
-			let max_block_size = max_block_size_full.max.get(dispatch_class);
-			log::debug!(target: LOG_TARGET, "Used max block size: {}", max_block_size);
-
-			// Adjust proof size to max block size as we are tracking tx size.
-			max_weight.set_proof_size(*max_block_size as u64)
-		};
+		let (max_block_weight, _) = Self::inherent_weight_limits();
 		log::debug!(target: LOG_TARGET, "Used max block weight: {}", max_block_weight);
 
 		let entropy = compute_entropy::<T>(parent_hash);
@@ -431,22 +778,88 @@ impl<T: Config> Pallet<T> {
 			log::debug!(target: LOG_TARGET, "Found duplicate statement sets, retaining the first");
 		}
 
+		if all_disputes_future(&disputes, current_session) {
+			log::warn!(
+				target: LOG_TARGET,
+				"All {} dispute statement sets are for future sessions and will be dropped",
+				disputes.len(),
+			);
+		}
+
+		// Stop initiating disputes in sessions which reached their maximum, while still accepting
+		// statements for ongoing ones.
+		if let Some(max_disputes_per_session) = config.max_disputes_per_session {
+			T::DisputesHandler::limit_new_disputes_per_session(
+				&mut disputes,
+				max_disputes_per_session,
+			);
+		}
+
+		// Stop including votes against candidates of validators with too many unconcluded
+		// disputes, so that a single validator cannot flood the dispute pipeline.
+		if let Some(max_pending_disputes) = config.max_pending_disputes_per_validator {
+			T::DisputesHandler::limit_pending_disputes_per_validator(
+				&mut disputes,
+				max_pending_disputes,
+			);
+		}
+
+		// Bound the statements to check across all disputes, dropping the disputes of the lowest
+		// priority first.
+		if let Some(max_statements) = config.max_dispute_statements_per_block {
+			limit_dispute_statements(&mut disputes, max_statements);
+		}
+
 		let post_conclusion_acceptance_period = config.dispute_post_conclusion_acceptance_period;
 
 		let dispute_statement_set_valid = move |set: DisputeStatementSet| {
 			T::DisputesHandler::filter_dispute_data(set, post_conclusion_acceptance_period)
 		};
 
+		// Keep the weight of some of the bitfields out of reach of the disputes, so that
+		// availability keeps advancing in blocks with many disputes.
+		let reserved_bitfields_weight = if context == ProcessInherentDataContext::ProvideInherent {
+			bitfields
+				.iter()
+				.take(config.min_reserved_bitfields as usize)
+				.fold(Weight::zero(), |acc, bitfield| {
+					acc.saturating_add(signed_bitfield_weight::<T>(bitfield))
+				})
+		} else {
+			Weight::zero()
+		};
+
+		// The reserved bitfields give way to a minimum number of disputes though, so that disputes
+		// keep progressing under heavy load.
+		let min_disputes_weight = disputes.iter().take(config.min_disputes_included as usize).fold(
+			Weight::zero(),
+			|acc, set| {
+				acc.saturating_add(dispute_statement_set_weight::<T, &DisputeStatementSet>(set))
+			},
+		);
+		let max_disputes_weight = max_block_weight
+			.saturating_sub(reserved_bitfields_weight)
+			.max(min_disputes_weight)
+			.min(max_block_weight);
+
 		// Limit the disputes first, since the following statements depend on the votes include
 		// here.
 		let (checked_disputes_sets, checked_disputes_sets_consumed_weight) =
 			limit_and_sanitize_disputes::<T, _>(
 				disputes,
 				dispute_statement_set_valid,
-				max_block_weight,
+				max_disputes_weight,
 			);
 
+		// Paras which lost at least one backed candidate to the weight limit.
+		let mut weight_filtered_paras = BTreeMap::<ParaId, usize>::new();
+
 		let all_weight_after = if context == ProcessInherentDataContext::ProvideInherent {
+			for backed_candidate in &backed_candidates {
+				let para_id = backed_candidate.descriptor().para_id;
+				*weight_filtered_paras.entry(para_id).or_default() += 1;
+			}
+
 			// Assure the maximum block weight is adhered, by limiting bitfields and backed
 			// candidates. Dispute statement sets were already limited before.
 			let non_disputes_weight = apply_weight_limit::<T>(
@@ -456,6 +869,14 @@ impl<T: Config> Pallet<T> {
 				&mut rng,
 			);
 
+			for backed_candidate in &backed_candidates {
+				let para_id = backed_candidate.descriptor().para_id;
+				if let Some(count) = weight_filtered_paras.get_mut(&para_id) {
+					*count = count.saturating_sub(1);
+				}
+			}
+			weight_filtered_paras.retain(|_, dropped| *dropped > 0);
+
 			let all_weight_after =
 				non_disputes_weight.saturating_add(checked_disputes_sets_consumed_weight);
 
@@ -542,32 +963,69 @@ impl<T: Config> Pallet<T> {
 			.map(|(_session, candidate)| candidate)
 			.collect::<BTreeSet<CandidateHash>>();
 
+		// Cores occupied by a candidate concluded invalid are freed right away. Unlike timed out
+		// cores, the claim they were serving is dropped rather than pushed back into the claim
+		// queue, so the core is up for reassignment in the next block.
 		let freed_disputed: BTreeMap<CoreIndex, FreedReason> =
 			<inclusion::Pallet<T>>::collect_disputed(&current_concluded_invalid_disputes)
 				.into_iter()
-				.map(|core| (core, FreedReason::Concluded))
+				.map(|(core, candidate_hash)| {
+					Self::deposit_event(Event::CoreFreedByInvalidity { core, candidate_hash });
+					(core, FreedReason::Concluded)
+				})
 				.collect();
 
 		// Create a bit index from the set of core indices where each index corresponds to
 		// a core index that was freed due to a dispute.
 		//
 		// I.e. 010100 would indicate, the candidates on Core 1 and 3 would be disputed.
-		let disputed_bitfield = create_disputed_bitfield(expected_bits, freed_disputed.keys());
+		// Cores forced disputed by governance are masked as well, without being freed.
+		let forced_disputed = ForcedDisputedCores::<T>::take();
+		let disputed_bitfield = create_disputed_bitfield(
+			expected_bits,
+			freed_disputed.keys().chain(forced_disputed.iter()),
+		);
 
 		if !freed_disputed.is_empty() {
 			<scheduler::Pallet<T>>::free_cores_and_fill_claimqueue(freed_disputed.clone(), now);
 		}
 
+		// Bitfields signed one block behind are reported separately, as they hint at validators
+		// lagging behind the chain. This verifies invalid signatures a second time, which the
+		// weight of `enter` does not account for, so it is only done while building the block.
+		let stale_parent_hash = (context == ProcessInherentDataContext::ProvideInherent)
+			.then(|| now.checked_sub(&2u32.into()).map(<frame_system::Pallet<T>>::block_hash))
+			.flatten();
+		let mut dropped_bitfields = Vec::new();
 		let bitfields = sanitize_bitfields::<T>(
 			bitfields,
 			disputed_bitfield,
 			expected_bits,
+			config.max_bitfields_per_block.map(|max| max as usize),
 			parent_hash,
 			current_session,
 			&validator_public[..],
+			stale_parent_hash,
+			&mut dropped_bitfields,
 		);
 		METRICS.on_bitfields_processed(bitfields.len() as u64);
 
+		for (validator_index, reason) in dropped_bitfields {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropped bitfield of validator {:?}: {:?}",
+				validator_index,
+				reason,
+			);
+		}
+
+		if context == ProcessInherentDataContext::Enter {
+			credit_bitfields::<T>(
+				current_session,
+				bitfields.iter().map(|bitfield| bitfield.validator_index()),
+			);
+		}
+
 		// Process new availability bitfields, yielding any availability cores whose
 		// work has now concluded.
 		let freed_concluded =
@@ -605,35 +1063,55 @@ impl<T: Config> Pallet<T> {
 			scheduled.entry(para_id).or_default().insert(core_idx);
 		}
 
+		let mut overweight_filtered_cores = weight_filtered_paras
+			.keys()
+			.filter_map(|para_id| scheduled.get(para_id))
+			.flatten()
+			.copied()
+			.collect::<BTreeSet<_>>();
+
+		// With nothing scheduled none of the candidates could be mapped to a core, so there is no
+		// point in checking them before dropping them all.
+		let nothing_scheduled =
+			context == ProcessInherentDataContext::ProvideInherent && total_scheduled_cores == 0;
 		let SanitizedBackedCandidates {
 			backed_candidates_with_core,
-			votes_from_disabled_were_dropped,
+			// Votes of disabled validators are dropped in `Enter` context as well, rather than
+			// rejecting the block, so that `Event::DisabledVotesDropped` is kept on chain.
+			votes_from_disabled_were_dropped: _,
 			dropped_unscheduled_candidates,
-		} = sanitize_backed_candidates::<T, _>(
-			backed_candidates,
-			&allowed_relay_parents,
-			|candidate_idx: usize,
-			 backed_candidate: &BackedCandidate<<T as frame_system::Config>::Hash>|
-			 -> bool {
-				let para_id = backed_candidate.descriptor().para_id;
-				let prev_context = <paras::Pallet<T>>::para_most_recent_context(para_id);
-				let check_ctx = CandidateCheckContext::<T>::new(prev_context);
-
-				// never include a concluded-invalid candidate
-				current_concluded_invalid_disputes.contains(&backed_candidate.hash()) ||
-					// Instead of checking the candidates with code upgrades twice
-					// move the checking up here and skip it in the training wheels fallback.
-					// That way we avoid possible duplicate checks while assuring all
-					// backed candidates fine to pass on.
-					//
-					// NOTE: this is the only place where we check the relay-parent.
-					check_ctx
-						.verify_backed_candidate(&allowed_relay_parents, candidate_idx, backed_candidate.candidate())
-						.is_err()
-			},
-			scheduled,
-			core_index_enabled,
-		);
+			trimmed_paras,
+			dropped_candidates,
+		} = if nothing_scheduled {
+			drop_unscheduled_backed_candidates::<T>(backed_candidates)
+		} else {
+			sanitize_backed_candidates::<T, _>(
+				backed_candidates,
+				&allowed_relay_parents,
+				|candidate_idx: usize,
+				 backed_candidate: &BackedCandidate<<T as frame_system::Config>::Hash>|
+				 -> bool {
+					let para_id = backed_candidate.descriptor().para_id;
+					let prev_context = <paras::Pallet<T>>::para_most_recent_context(para_id);
+					let check_ctx = CandidateCheckContext::<T>::new(prev_context);
+
+					// never include a concluded-invalid candidate
+					current_concluded_invalid_disputes.contains(&backed_candidate.hash()) ||
+						// Instead of checking the candidates with code upgrades twice
+						// move the checking up here and skip it in the training wheels fallback.
+						// That way we avoid possible duplicate checks while assuring all
+						// backed candidates fine to pass on.
+						//
+						// NOTE: the relay-parent is checked here and when mapping candidates with
+						// an injected core index to their core.
+						check_ctx
+							.verify_backed_candidate(&allowed_relay_parents, candidate_idx, backed_candidate.candidate())
+							.is_err()
+				},
+				scheduled,
+				core_index_enabled,
+			)
+		};
 
 		ensure!(
 			backed_candidates_with_core.len() <= total_scheduled_cores,
@@ -642,13 +1120,40 @@ impl<T: Config> Pallet<T> {
 
 		METRICS.on_candidates_sanitized(backed_candidates_with_core.len() as u64);
 
-		// In `Enter` context (invoked during execution) there should be no backing votes from
-		// disabled validators because they should have been filtered out during inherent data
-		// preparation (`ProvideInherent` context). Abort in such cases.
-		if context == ProcessInherentDataContext::Enter {
-			ensure!(!votes_from_disabled_were_dropped, Error::<T>::BackedByDisabled);
+		METRICS.on_candidates_trimmed(
+			trimmed_paras
+				.values()
+				.filter(|counts| counts.included > 0)
+				.map(|counts| counts.submitted.saturating_sub(counts.included) as u64)
+				.sum(),
+		);
+		for (para_id, counts) in trimmed_paras {
+			log::debug!(
+				target: LOG_TARGET,
+				"Para {:?} submitted {} candidates, included {}",
+				para_id,
+				counts.submitted,
+				counts.included,
+			);
+		}
+
+		for (candidate_hash, reason) in dropped_candidates {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropped candidate {:?}: {:?}",
+				candidate_hash,
+				reason,
+			);
 		}
 
+		// Only cores left without a candidate count as filtered because of the weight limit. This
+		// is only known while providing the inherent, so it is reported to the node rather than
+		// kept in storage.
+		for (_, core_idx) in &backed_candidates_with_core {
+			overweight_filtered_cores.remove(core_idx);
+		}
+		METRICS.on_cores_weight_filtered(overweight_filtered_cores.len() as u64);
+
 		// In `Enter` context (invoked during execution) we shouldn't have filtered any candidates
 		// due to a para not being scheduled. They have been filtered during inherent data
 		// preparation (`ProvideInherent` context). Abort in such cases.
@@ -669,6 +1174,12 @@ impl<T: Config> Pallet<T> {
 		// Note which of the scheduled cores were actually occupied by a backed candidate.
 		<scheduler::Pallet<T>>::occupied(occupied.into_iter().map(|e| (e.0, e.1)).collect());
 
+		if context == ProcessInherentDataContext::Enter {
+			let backed_cores =
+				backed_candidates_with_core.iter().map(|(_, core_idx)| *core_idx).collect();
+			note_core_inclusions::<T>(expected_bits, &backed_cores);
+		}
+
 		set_scrapable_on_chain_backings::<T>(
 			current_session,
 			candidate_receipt_with_backing_validator_indices,
@@ -694,6 +1205,12 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> VerifiedBackingVotes<T::Hash> for Pallet<T> {
+	fn verified_backing_votes() -> Option<ScrapedOnChainVotes<T::Hash>> {
+		Self::on_chain_votes()
+	}
+}
+
 /// Derive a bitfield from dispute
 pub(super) fn create_disputed_bitfield<'a, I>(
 	expected_bits: usize,
@@ -779,11 +1296,14 @@ fn random_sel<X, F: Fn(&X) -> Weight>(
 /// Otherwise tries to include all disputes, and then tries to fill the remaining space with
 /// bitfields and then candidates.
 ///
-/// The selection process is random. For candidates, there is an exception for code upgrades as they
-/// are preferred. And for disputes, local and older disputes are preferred (see
-/// `limit_and_sanitize_disputes`). for backed candidates, since with a increasing number of
-/// parachains their chances of inclusion become slim. All backed candidates  are checked
-/// beforehands in `fn create_inherent_inner` which guarantees sanity.
+/// The selection process is random, so that no para is systematically left out when the block is
+/// full. For candidates, there is an exception for code upgrades as they are preferred: they tend
+/// to be large and would otherwise rarely fit. For disputes, local and older disputes are preferred
+/// (see `limit_and_sanitize_disputes`). All backed candidates are checked beforehand in
+/// `fn create_inherent_inner`, which guarantees sanity.
+///
+/// Candidates dropped here are not retained for the next block: the state written while providing
+/// the inherent is discarded by the block builder, so retrying them first is up to the node.
 ///
 /// Assumes disputes are already filtered by the time this is called.
 ///
@@ -865,14 +1385,29 @@ fn apply_weight_limit<T: Config + inclusion::Config>(
 ///  4. signature is valid
 ///  5. remove any disputed core indices
 ///
-/// If any of those is not passed, the bitfield is dropped.
+/// If any of those is not passed, the bitfield is dropped. Of the remaining bitfields at most
+/// `max_bitfields` are kept, preferring those with the most availability bits set and, among
+/// those setting as many bits, the ones of lower validator indices, so that every node keeps the
+/// same ones. Bitfields dropped for their signature are recorded in `dropped_bitfields`, as
+/// [`BitfieldDropReason::StaleParentHash`] if they were signed for `stale_parent_hash` instead of
+/// `parent_hash`. That verifies the signature of such bitfields twice, so `stale_parent_hash` must
+/// be `None` wherever only one verification per bitfield is weighed.
+///
+/// `validators` are the keys of the active validator set, i.e. of
+/// `shared::Pallet::active_validator_indices()`, so bitfields of validator indices beyond them are
+/// recorded as [`BitfieldDropReason::UnknownValidator`].
+///
+/// With an empty `validators` set no bitfield can be valid, so all of them are dropped.
 pub(crate) fn sanitize_bitfields<T: crate::inclusion::Config>(
 	unchecked_bitfields: UncheckedSignedAvailabilityBitfields,
 	disputed_bitfield: DisputedBitfield,
 	expected_bits: usize,
+	max_bitfields: Option<usize>,
 	parent_hash: T::Hash,
 	session_index: SessionIndex,
 	validators: &[ValidatorId],
+	stale_parent_hash: Option<T::Hash>,
+	dropped_bitfields: &mut Vec<(ValidatorIndex, BitfieldDropReason)>,
 ) -> SignedAvailabilityBitfields {
 	let mut bitfields = Vec::with_capacity(unchecked_bitfields.len());
 
@@ -885,8 +1420,20 @@ pub(crate) fn sanitize_bitfields<T: crate::inclusion::Config>(
 		return vec![]
 	}
 
+	if validators.is_empty() {
+		log::error!(
+			target: LOG_TARGET,
+			"No active validators, dropping all {} bitfields",
+			unchecked_bitfields.len(),
+		);
+		return vec![]
+	}
+
 	let all_zeros = BitVec::<u8, bitvec::order::Lsb0>::repeat(false, expected_bits);
 	let signing_context = SigningContext { parent_hash, session_index };
+	let stale_signing_context = stale_parent_hash
+		.filter(|stale_parent_hash| *stale_parent_hash != parent_hash)
+		.map(|parent_hash| SigningContext { parent_hash, session_index });
 	for unchecked_bitfield in unchecked_bitfields {
 		// Find and skip invalid bitfields.
 		if unchecked_bitfield.unchecked_payload().0.len() != expected_bits {
@@ -929,27 +1476,99 @@ pub(crate) fn sanitize_bitfields<T: crate::inclusion::Config>(
 				validator_index.0,
 				validators.len(),
 			);
+			dropped_bitfields.push((validator_index, BitfieldDropReason::UnknownValidator));
+			METRICS.on_unknown_bitfield_validator();
 			continue
 		}
 
 		let validator_public = &validators[validator_index.0 as usize];
 
 		// Validate bitfield signature.
-		if let Ok(signed_bitfield) =
-			unchecked_bitfield.try_into_checked(&signing_context, validator_public)
-		{
-			bitfields.push(signed_bitfield);
-			METRICS.on_valid_bitfield_signature();
-		} else {
-			log::warn!(target: LOG_TARGET, "Invalid bitfield signature");
-			METRICS.on_invalid_bitfield_signature();
+		match unchecked_bitfield.try_into_checked(&signing_context, validator_public) {
+			Ok(signed_bitfield) => {
+				bitfields.push(signed_bitfield);
+				METRICS.on_valid_bitfield_signature();
+			},
+			Err(unchecked_bitfield) => {
+				let signed_for_stale_parent =
+					stale_signing_context.as_ref().map_or(false, |context| {
+						unchecked_bitfield.check_signature(context, validator_public).is_ok()
+					});
+				if signed_for_stale_parent {
+					log::warn!(target: LOG_TARGET, "Bitfield signed for a stale parent hash");
+					dropped_bitfields.push((validator_index, BitfieldDropReason::StaleParentHash));
+					METRICS.on_stale_bitfield_signature();
+				} else {
+					log::warn!(target: LOG_TARGET, "Invalid bitfield signature");
+					dropped_bitfields.push((validator_index, BitfieldDropReason::InvalidSignature));
+					METRICS.on_invalid_bitfield_signature();
+				}
+			},
 		};
 
 		last_index = Some(validator_index);
 	}
+
+	if let Some(max_bitfields) = max_bitfields.filter(|max| bitfields.len() > *max) {
+		log::debug!(
+			target: LOG_TARGET,
+			"Keeping {} of {} valid bitfields",
+			max_bitfields,
+			bitfields.len(),
+		);
+		// Keep the bitfields with the most availability bits set, preferring lower validator
+		// indices on ties, without changing their order.
+		let mut by_value = (0..bitfields.len()).collect::<Vec<_>>();
+		by_value.sort_by_key(|idx| {
+			let bitfield = &bitfields[*idx];
+			(Reverse(bitfield.payload().0.count_ones()), bitfield.validator_index())
+		});
+		let mut kept = by_value.into_iter().take(max_bitfields).collect::<Vec<_>>();
+		kept.sort_unstable();
+		bitfields.indexed_retain(|idx, _bitfield| kept.binary_search(&idx).is_ok());
+	}
+
 	bitfields
 }
 
+/// The reason a backed candidate was dropped while sanitizing the inherent data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropReason {
+	/// Another candidate of the same para claimed the same core and was preferred over this one.
+	CoreConflict,
+	/// The very same candidate was already supplied earlier in the inherent.
+	DuplicateCandidate,
+	/// The para already had candidates on `max_cores_per_para` lower cores.
+	CoreCapExceeded,
+	/// The para is not scheduled on any core, or on several without the candidate telling which.
+	Unscheduled,
+	/// The relay parent is not among the allowed relay parents.
+	RelayParentNotAllowed,
+	/// The para is scheduled, but not on the core the candidate was backed on.
+	CoreNotScheduled,
+	/// The persisted validation data hash doesn't match the one expected for the para at the
+	/// relay parent.
+	InvalidValidationDataHash,
+	/// The encoded candidate is larger than the configured `max_candidate_encoded_size`.
+	CandidateTooLarge,
+	/// The candidate carries fewer backing votes than `effective_minimum_backing_votes`, even
+	/// though none of them were dropped for coming from disabled validators.
+	BelowBackingThreshold,
+	/// The HRMP watermark is beyond the block number of the relay parent.
+	InvalidHrmpWatermark,
+}
+
+/// The reason a signed bitfield was dropped while sanitizing the inherent data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitfieldDropReason {
+	/// The signature does not match the bitfield.
+	InvalidSignature,
+	/// The bitfield was signed for the parent of the expected parent block.
+	StaleParentHash,
+	/// The validator index is not in the active validator set.
+	UnknownValidator,
+}
+
 // Result from `sanitize_backed_candidates`
 #[derive(Debug, PartialEq)]
 struct SanitizedBackedCandidates<Hash> {
@@ -961,14 +1580,47 @@ struct SanitizedBackedCandidates<Hash> {
 	// Set to true if any candidates were dropped due to filtering done in
 	// `map_candidates_to_cores`
 	dropped_unscheduled_candidates: bool,
+	// Paras which had at least one of their submitted candidates dropped, along with how many
+	// were submitted and how many made it through sanitization.
+	trimmed_paras: BTreeMap<ParaId, ParaCandidateCounts>,
+	// Candidates that were dropped for a reason worth reporting, in the order they were dropped.
+	dropped_candidates: Vec<(CandidateHash, DropReason)>,
+}
+
+// Number of candidates a para submitted and had included, as reported by
+// `sanitize_backed_candidates`.
+#[derive(Debug, PartialEq)]
+struct ParaCandidateCounts {
+	submitted: u32,
+	included: u32,
 }
 
 /// Filter out:
-/// 1. any candidates that have a concluded invalid dispute
-/// 2. any unscheduled candidates, as well as candidates whose paraid has multiple cores assigned
-///    but have no injected core index.
-/// 3. all backing votes from disabled validators
-/// 4. any candidates that end up with less than `effective_minimum_backing_votes` backing votes
+/// 1. any repeated occurrence of a candidate, keeping only the first one
+/// 2. any candidates larger than `max_candidate_encoded_size`, once encoded
+/// 3. any candidates that have a concluded invalid dispute, as well as any candidates whose relay
+///    parent is outside of the allowed relay parents, as both are reported by
+///    `candidate_has_concluded_invalid_dispute_or_is_invalid`. They are dropped rather than
+///    deferred: the state written while providing the inherent is discarded by the block builder,
+///    so holding them back for a later block is up to the node.
+/// 4. any candidates whose persisted validation data hash doesn't match the expected one
+/// 5. if core indices are enabled, all but one of the candidates of a para claiming the same core.
+///    See [`resolve_core_conflicts`] for which one is kept.
+/// 6. any unscheduled candidates, as well as candidates whose paraid has multiple cores assigned
+///    but have no injected core index. Candidates with an injected core index are bound to the core
+///    they declare, regardless of the order they were submitted in.
+/// 7. all backing votes from disabled validators
+/// 8. any candidates that end up with less than `effective_minimum_backing_votes` backing votes.
+///    Those that were submitted with too few votes, rather than brought under the threshold by the
+///    previous step, are dropped with [`DropReason::BelowBackingThreshold`].
+/// 9. any candidates of a para beyond its `max_cores_per_para` lowest cores
+///
+/// The backing signatures are not checked here, but once in `process_candidates`, against the
+/// validators of the current session. That holds for candidates built on the relay parent of the
+/// previous session as well, which is left in the allowed relay parents right after a session
+/// change: they are backed for the session of the block they are backed in.
+///
+/// If there are no active validators, all candidates are dropped as none of them can be backed.
 ///
 /// `scheduled` follows the same naming scheme as provided in the
 /// guide: Currently `free` but might become `occupied`.
@@ -980,22 +1632,75 @@ struct SanitizedBackedCandidates<Hash> {
 ///
 /// Returns struct `SanitizedBackedCandidates` where `backed_candidates` are sorted according to the
 /// occupied core index.
-fn sanitize_backed_candidates<
-	T: crate::inclusion::Config,
-	F: FnMut(usize, &BackedCandidate<T::Hash>) -> bool,
->(
+fn sanitize_backed_candidates<T: Config, F: FnMut(usize, &BackedCandidate<T::Hash>) -> bool>(
 	mut backed_candidates: Vec<BackedCandidate<T::Hash>>,
 	allowed_relay_parents: &AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>,
 	mut candidate_has_concluded_invalid_dispute_or_is_invalid: F,
 	scheduled: BTreeMap<ParaId, BTreeSet<CoreIndex>>,
 	core_index_enabled: bool,
 ) -> SanitizedBackedCandidates<T::Hash> {
+	let mut submitted_per_para: BTreeMap<ParaId, u32> = BTreeMap::new();
+	for backed_candidate in &backed_candidates {
+		*submitted_per_para.entry(backed_candidate.descriptor().para_id).or_default() += 1;
+	}
+
+	if shared::Pallet::<T>::active_validator_indices().is_empty() {
+		log::error!(
+			target: LOG_TARGET,
+			"No active validators, dropping all {} backed candidates",
+			backed_candidates.len(),
+		);
+		return SanitizedBackedCandidates {
+			backed_candidates_with_core: Vec::new(),
+			votes_from_disabled_were_dropped: false,
+			dropped_unscheduled_candidates: false,
+			trimmed_paras: submitted_per_para
+				.into_iter()
+				.map(|(para_id, submitted)| {
+					(para_id, ParaCandidateCounts { submitted, included: 0 })
+				})
+				.collect(),
+			dropped_candidates: Vec::new(),
+		}
+	}
+
+	let mut dropped_candidates = Vec::new();
+	drop_duplicate_candidates(&mut backed_candidates, &mut dropped_candidates);
+
+	// Drop oversized candidates ahead of the trimming to the block size limit, so that a single
+	// candidate cannot crowd out all the others.
+	if let Some(max_candidate_encoded_size) =
+		configuration::Pallet::<T>::config().max_candidate_encoded_size
+	{
+		drop_oversized_candidates(
+			&mut backed_candidates,
+			max_candidate_encoded_size,
+			&mut dropped_candidates,
+		);
+	}
+
 	// Remove any candidates that were concluded invalid.
 	// This does not assume sorting.
 	backed_candidates.indexed_retain(move |candidate_idx, backed_candidate| {
 		!candidate_has_concluded_invalid_dispute_or_is_invalid(candidate_idx, backed_candidate)
 	});
 
+	drop_invalid_validation_data_hashes::<T>(
+		&mut backed_candidates,
+		allowed_relay_parents,
+		&mut dropped_candidates,
+	);
+
+	drop_future_hrmp_watermarks::<T>(
+		&mut backed_candidates,
+		allowed_relay_parents,
+		&mut dropped_candidates,
+	);
+
+	if core_index_enabled {
+		resolve_core_conflicts(&mut backed_candidates, &mut dropped_candidates);
+	}
+
 	let initial_candidate_count = backed_candidates.len();
 	// Map candidates to scheduled cores. Filter out any unscheduled candidates.
 	let mut backed_candidates_with_core = map_candidates_to_cores::<T>(
@@ -1003,6 +1708,7 @@ fn sanitize_backed_candidates<
 		scheduled,
 		core_index_enabled,
 		backed_candidates,
+		&mut dropped_candidates,
 	);
 
 	let dropped_unscheduled_candidates =
@@ -1015,6 +1721,12 @@ fn sanitize_backed_candidates<
 		core_index_enabled,
 	);
 
+	drop_candidates_below_backing_threshold::<T>(
+		&mut backed_candidates_with_core,
+		allowed_relay_parents,
+		&mut dropped_candidates,
+	);
+
 	// Sort the `Vec` last, once there is a guarantee that these
 	// `BackedCandidates` references the expected relay chain parent,
 	// but more importantly are scheduled for a free core.
@@ -1022,13 +1734,388 @@ fn sanitize_backed_candidates<
 	// but also allows this to be done in place.
 	backed_candidates_with_core.sort_by(|(_x, core_x), (_y, core_y)| core_x.cmp(&core_y));
 
+	if let Some(max_cores_per_para) = configuration::Pallet::<T>::config().max_cores_per_para {
+		enforce_max_cores_per_para(
+			&mut backed_candidates_with_core,
+			max_cores_per_para,
+			&mut dropped_candidates,
+		);
+	}
+
+	let mut included_per_para: BTreeMap<ParaId, u32> = BTreeMap::new();
+	for (backed_candidate, _) in &backed_candidates_with_core {
+		*included_per_para.entry(backed_candidate.descriptor().para_id).or_default() += 1;
+	}
+
+	// Report paras that only got part (or none) of their candidates through, so that collators
+	// submitting several candidates at once can tell their batch was trimmed.
+	let trimmed_paras = submitted_per_para
+		.into_iter()
+		.filter_map(|(para_id, submitted)| {
+			let included = included_per_para.get(&para_id).copied().unwrap_or(0);
+			(included < submitted).then(|| (para_id, ParaCandidateCounts { submitted, included }))
+		})
+		.collect();
+
 	SanitizedBackedCandidates {
 		dropped_unscheduled_candidates,
 		votes_from_disabled_were_dropped,
 		backed_candidates_with_core,
+		trimmed_paras,
+		dropped_candidates,
+	}
+}
+
+/// Drop all `backed_candidates` as unscheduled, without sanitizing them.
+///
+/// The shortcut of [`sanitize_backed_candidates`] for when nothing is scheduled and no candidate
+/// can be mapped to a core: the outcome is the same, as long as none of the candidates would have
+/// been dropped for another reason first.
+fn drop_unscheduled_backed_candidates<T: Config>(
+	backed_candidates: Vec<BackedCandidate<T::Hash>>,
+) -> SanitizedBackedCandidates<T::Hash> {
+	let mut submitted_per_para: BTreeMap<ParaId, u32> = BTreeMap::new();
+	for backed_candidate in &backed_candidates {
+		*submitted_per_para.entry(backed_candidate.descriptor().para_id).or_default() += 1;
+	}
+	let dropped_unscheduled_candidates = !backed_candidates.is_empty();
+
+	SanitizedBackedCandidates {
+		backed_candidates_with_core: Vec::new(),
+		votes_from_disabled_were_dropped: false,
+		dropped_unscheduled_candidates,
+		trimmed_paras: submitted_per_para
+			.into_iter()
+			.map(|(para_id, submitted)| (para_id, ParaCandidateCounts { submitted, included: 0 }))
+			.collect(),
+		dropped_candidates: Vec::new(),
 	}
 }
 
+/// Drop the candidates whose persisted validation data hash doesn't match the one computed from
+/// their para's head and relay parent, with [`DropReason::InvalidValidationDataHash`].
+///
+/// Candidates of paras without a head, or with a relay parent outside of the allowed window, are
+/// left to the other checks.
+fn drop_invalid_validation_data_hashes<T: Config>(
+	backed_candidates: &mut Vec<BackedCandidate<T::Hash>>,
+	allowed_relay_parents: &AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>,
+	dropped_candidates: &mut Vec<(CandidateHash, DropReason)>,
+) {
+	backed_candidates.retain(|backed_candidate| {
+		let descriptor = backed_candidate.descriptor();
+		let Some((storage_root, relay_parent_number)) =
+			allowed_relay_parents.acquire_info(descriptor.relay_parent, None)
+		else {
+			return true
+		};
+		let Some(expected) = crate::util::make_persisted_validation_data::<T>(
+			descriptor.para_id,
+			relay_parent_number,
+			storage_root,
+		) else {
+			return true
+		};
+
+		if expected.hash() != descriptor.persisted_validation_data_hash {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropping candidate {:?} of para {:?} with an invalid validation data hash.",
+				backed_candidate.hash(),
+				descriptor.para_id,
+			);
+			dropped_candidates
+				.push((backed_candidate.hash(), DropReason::InvalidValidationDataHash));
+			return false
+		}
+		true
+	});
+}
+
+/// Drop the candidates whose HRMP watermark is beyond the block number of their relay parent, as
+/// no message could have been processed up to it.
+fn drop_future_hrmp_watermarks<T: Config>(
+	backed_candidates: &mut Vec<BackedCandidate<T::Hash>>,
+	allowed_relay_parents: &AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>,
+	dropped_candidates: &mut Vec<(CandidateHash, DropReason)>,
+) {
+	backed_candidates.retain(|backed_candidate| {
+		let Some((_, relay_parent_number)) =
+			allowed_relay_parents.acquire_info(backed_candidate.descriptor().relay_parent, None)
+		else {
+			return true
+		};
+
+		let hrmp_watermark = backed_candidate.candidate().commitments.hrmp_watermark;
+		if BlockNumberFor::<T>::from(hrmp_watermark) > relay_parent_number {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropping candidate {:?} with HRMP watermark {} beyond its relay parent number {:?}.",
+				backed_candidate.hash(),
+				hrmp_watermark,
+				relay_parent_number,
+			);
+			dropped_candidates.push((backed_candidate.hash(), DropReason::InvalidHrmpWatermark));
+			return false
+		}
+		true
+	});
+}
+
+/// Count the included bitfields of `validators` in `current_session`, handing the counts of the
+/// previous session to [`Config::OnBitfieldCredited`] first if it just ended.
+fn credit_bitfields<T: Config>(
+	current_session: SessionIndex,
+	validators: impl IntoIterator<Item = ValidatorIndex>,
+) {
+	BitfieldsCredited::<T>::mutate(|(session, counts)| {
+		if *session != current_session {
+			let ended_counts = mem::take(counts);
+			if !ended_counts.is_empty() {
+				T::OnBitfieldCredited::on_validator_liveness(*session, ended_counts);
+			}
+			*session = current_session;
+		}
+
+		for validator in validators {
+			let count = counts.entry(validator).or_default();
+			*count = count.saturating_add(1);
+		}
+	});
+}
+
+/// Keep the candidates of each para on at most `max_cores_per_para` cores, dropping the others
+/// with [`DropReason::CoreCapExceeded`].
+///
+/// `backed_candidates_with_core` must be sorted by core index, so that the lowest cores are kept.
+fn enforce_max_cores_per_para<Hash: Clone + Encode>(
+	backed_candidates_with_core: &mut Vec<(BackedCandidate<Hash>, CoreIndex)>,
+	max_cores_per_para: u32,
+	dropped_candidates: &mut Vec<(CandidateHash, DropReason)>,
+) {
+	let mut cores_per_para: BTreeMap<ParaId, u32> = BTreeMap::new();
+	backed_candidates_with_core.retain(|(backed_candidate, core_idx)| {
+		let para_id = backed_candidate.descriptor().para_id;
+		let cores = cores_per_para.entry(para_id).or_default();
+		if *cores >= max_cores_per_para {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropping candidate {:?} of para {:?} on core {:?}, exceeding the core cap.",
+				backed_candidate.hash(),
+				para_id,
+				core_idx,
+			);
+			dropped_candidates.push((backed_candidate.hash(), DropReason::CoreCapExceeded));
+			return false
+		}
+		*cores += 1;
+		true
+	});
+}
+
+/// Drop the candidates with less than `effective_minimum_backing_votes` backing votes with
+/// [`DropReason::BelowBackingThreshold`].
+///
+/// Candidates whose backing group can't be determined are left to `process_candidates`.
+fn drop_candidates_below_backing_threshold<T: Config>(
+	backed_candidates_with_core: &mut Vec<(BackedCandidate<T::Hash>, CoreIndex)>,
+	allowed_relay_parents: &AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>,
+	dropped_candidates: &mut Vec<(CandidateHash, DropReason)>,
+) {
+	let minimum_backing_votes = configuration::Pallet::<T>::minimum_backing_votes();
+	backed_candidates_with_core.retain(|(backed_candidate, core_index)| {
+		let Some((_, relay_parent_number)) =
+			allowed_relay_parents.acquire_info(backed_candidate.descriptor().relay_parent, None)
+		else {
+			return true
+		};
+		let Some(group_idx) = <scheduler::Pallet<T>>::group_assigned_to_core(
+			*core_index,
+			relay_parent_number + One::one(),
+		) else {
+			return true
+		};
+		let Some(validator_group) = <scheduler::Pallet<T>>::group_validators(group_idx) else {
+			return true
+		};
+
+		let votes = backed_candidate.validity_votes().len();
+		if votes < effective_minimum_backing_votes(validator_group.len(), minimum_backing_votes) {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropping candidate {:?} of para {:?} backed by only {} validators.",
+				backed_candidate.hash(),
+				backed_candidate.descriptor().para_id,
+				votes,
+			);
+			dropped_candidates.push((backed_candidate.hash(), DropReason::BelowBackingThreshold));
+			return false
+		}
+		true
+	});
+}
+
+/// Drop the candidates whose encoded size exceeds `max_candidate_encoded_size` with
+/// [`DropReason::CandidateTooLarge`].
+fn drop_oversized_candidates<Hash: Clone + Encode>(
+	backed_candidates: &mut Vec<BackedCandidate<Hash>>,
+	max_candidate_encoded_size: u32,
+	dropped_candidates: &mut Vec<(CandidateHash, DropReason)>,
+) {
+	backed_candidates.retain(|backed_candidate| {
+		let encoded_size = backed_candidate.encoded_size();
+		if encoded_size > max_candidate_encoded_size as usize {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropping candidate {:?} of para {:?} with an encoded size of {} bytes.",
+				backed_candidate.hash(),
+				backed_candidate.descriptor().para_id,
+				encoded_size,
+			);
+			dropped_candidates.push((backed_candidate.hash(), DropReason::CandidateTooLarge));
+			return false
+		}
+		true
+	});
+}
+
+/// Drop every candidate whose hash was already seen earlier in `backed_candidates` with
+/// [`DropReason::DuplicateCandidate`], keeping the first occurrence.
+fn drop_duplicate_candidates<Hash: Clone + Encode>(
+	backed_candidates: &mut Vec<BackedCandidate<Hash>>,
+	dropped_candidates: &mut Vec<(CandidateHash, DropReason)>,
+) {
+	let mut seen = BTreeSet::new();
+	backed_candidates.retain(|backed_candidate| {
+		let candidate_hash = backed_candidate.hash();
+		let first = seen.insert(candidate_hash);
+		if !first {
+			log::debug!(
+				target: LOG_TARGET,
+				"Candidate {:?} of para {:?} was supplied more than once.",
+				candidate_hash,
+				backed_candidate.descriptor().para_id,
+			);
+			dropped_candidates.push((candidate_hash, DropReason::DuplicateCandidate));
+		}
+		first
+	});
+}
+
+/// Keep a single candidate per para and claimed core, dropping the others with
+/// [`DropReason::CoreConflict`].
+///
+/// The candidate with the most validity votes is kept. Ties are broken in favour of the lower
+/// `CandidateHash`, so that the outcome does not depend on the order the candidates were supplied
+/// in. Candidates without an injected core index are left untouched.
+fn resolve_core_conflicts<Hash: Clone + Encode>(
+	backed_candidates: &mut Vec<BackedCandidate<Hash>>,
+	dropped_candidates: &mut Vec<(CandidateHash, DropReason)>,
+) {
+	let claim = |backed_candidate: &BackedCandidate<Hash>| {
+		backed_candidate
+			.validator_indices_and_core_index(true)
+			.1
+			.map(|core_idx| (backed_candidate.descriptor().para_id, core_idx))
+	};
+
+	let mut preferred: BTreeMap<(ParaId, CoreIndex), (usize, Reverse<CandidateHash>)> =
+		BTreeMap::new();
+	for backed_candidate in backed_candidates.iter() {
+		let Some(claim) = claim(backed_candidate) else { continue };
+		let rank = (backed_candidate.validity_votes().len(), Reverse(backed_candidate.hash()));
+		preferred
+			.entry(claim)
+			.and_modify(|best| {
+				if rank > *best {
+					*best = rank
+				}
+			})
+			.or_insert(rank);
+	}
+
+	backed_candidates.retain(|backed_candidate| {
+		let Some(claim) = claim(backed_candidate) else { return true };
+		let candidate_hash = backed_candidate.hash();
+		let keep = preferred.get(&claim).map_or(true, |best| best.1 .0 == candidate_hash);
+		if !keep {
+			log::debug!(
+				target: LOG_TARGET,
+				"Candidate {:?} of para {:?} lost core {:?} to another candidate of the same para.",
+				candidate_hash,
+				claim.0,
+				claim.1,
+			);
+			dropped_candidates.push((candidate_hash, DropReason::CoreConflict));
+		}
+		keep
+	});
+}
+
+/// The disabled validators whose backing votes are to be dropped, i.e. those disabled for at least
+/// [`Config::DisabledValidatorsGracePeriod`] blocks. Newly disabled validators are noted in
+/// [`DisabledSince`].
+fn validators_disabled_beyond_grace_period<T: Config>() -> BTreeSet<ValidatorIndex> {
+	let disabled_validators = shared::Pallet::<T>::disabled_validators();
+	let grace_period = T::DisabledValidatorsGracePeriod::get();
+	if grace_period.is_zero() {
+		return BTreeSet::from_iter(disabled_validators.into_iter())
+	}
+
+	let now = <frame_system::Pallet<T>>::block_number();
+	let current_session = <shared::Pallet<T>>::session_index();
+	DisabledSince::<T>::mutate(|(session, disabled_since)| {
+		if *session != current_session {
+			*session = current_session;
+			disabled_since.clear();
+		}
+
+		disabled_since.retain(|validator, _| disabled_validators.contains(validator));
+		disabled_validators
+			.into_iter()
+			.filter(|validator| {
+				let since = *disabled_since.entry(*validator).or_insert(now);
+				now.saturating_sub(since) >= grace_period
+			})
+			.collect()
+	})
+}
+
+/// Record in [`CoreInclusionHistory`] which of the first `n_cores` cores received a backed
+/// candidate in this block, forgetting the blocks beyond [`CORE_INCLUSION_HISTORY_LENGTH`].
+fn note_core_inclusions<T: Config>(n_cores: usize, backed_cores: &BTreeSet<CoreIndex>) {
+	let mut block = BitVec::repeat(false, n_cores);
+	for core_idx in backed_cores {
+		if let Some(mut backed) = block.get_mut(core_idx.0 as usize) {
+			*backed = true;
+		}
+	}
+
+	CoreInclusionHistory::<T>::mutate(|history| {
+		if history.len() >= CORE_INCLUSION_HISTORY_LENGTH {
+			history.pop_front();
+		}
+		history.push_back(block);
+	});
+}
+
+/// Record the on chain votes of block `now` in [`RecentOnChainVotes`] and prune the votes recorded
+/// `retention` or more blocks ago.
+fn note_recent_on_chain_votes<T: Config>(now: BlockNumberFor<T>, retention: u32) {
+	RecentOnChainVotes::<T>::mutate(|recent| {
+		recent.retain(|(recorded_at, _)| now.saturating_sub(*recorded_at) < retention.into());
+		if let Some(votes) = OnChainVotes::<T>::get().filter(|_| retention > 0) {
+			recent.push((now, votes));
+		}
+	});
+}
+
+/// Whether there is at least one dispute statement set and all of them are for sessions after
+/// `current_session`.
+fn all_disputes_future(disputes: &MultiDisputeStatementSet, current_session: SessionIndex) -> bool {
+	!disputes.is_empty() && disputes.iter().all(|set| set.session > current_session)
+}
+
 /// Derive entropy from babe provided per block randomness.
 ///
 /// In the odd case none is available, uses the `parent_hash` and
@@ -1052,6 +2139,29 @@ fn compute_entropy<T: Config>(parent_hash: T::Hash) -> [u8; 32] {
 	entropy
 }
 
+/// Retain the disputes in order until their statements add up to more than `max_statements`,
+/// dropping that dispute and all of the following ones.
+fn limit_dispute_statements(disputes: &mut MultiDisputeStatementSet, max_statements: u32) {
+	let mut statements_acc = 0usize;
+	let retained = disputes
+		.iter()
+		.take_while(|set| {
+			statements_acc = statements_acc.saturating_add(set.statements.len());
+			statements_acc <= max_statements as usize
+		})
+		.count();
+
+	if retained < disputes.len() {
+		log::debug!(
+			target: LOG_TARGET,
+			"Dropping {} disputes above the limit of {} statements per block",
+			disputes.len() - retained,
+			max_statements,
+		);
+	}
+	disputes.truncate(retained);
+}
+
 /// Limit disputes in place.
 ///
 /// Assumes ordering of disputes, retains sorting of the statement.
@@ -1113,7 +2223,7 @@ fn limit_and_sanitize_disputes<
 // Filters statements from disabled validators in `BackedCandidate`, non-scheduled candidates and
 // few more sanity checks. Returns `true` if at least one statement is removed and `false`
 // otherwise.
-fn filter_backed_statements_from_disabled_validators<T: shared::Config + scheduler::Config>(
+fn filter_backed_statements_from_disabled_validators<T: Config>(
 	backed_candidates_with_core: &mut Vec<(
 		BackedCandidate<<T as frame_system::Config>::Hash>,
 		CoreIndex,
@@ -1121,8 +2231,13 @@ fn filter_backed_statements_from_disabled_validators<T: shared::Config + schedul
 	allowed_relay_parents: &AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>,
 	core_index_enabled: bool,
 ) -> bool {
-	let disabled_validators =
-		BTreeSet::<_>::from_iter(shared::Pallet::<T>::disabled_validators().into_iter());
+	// The disabled validators are only noted for blocks with backed candidates, which are weighed
+	// with it.
+	if backed_candidates_with_core.is_empty() {
+		return false
+	}
+
+	let disabled_validators = validators_disabled_beyond_grace_period::<T>();
 
 	if disabled_validators.is_empty() {
 		// No disabled validators - nothing to do
@@ -1134,7 +2249,7 @@ fn filter_backed_statements_from_disabled_validators<T: shared::Config + schedul
 	// Flag which will be returned. Set to `true` if at least one vote is filtered.
 	let mut filtered = false;
 
-	let minimum_backing_votes = configuration::Pallet::<T>::config().minimum_backing_votes;
+	let minimum_backing_votes = configuration::Pallet::<T>::minimum_backing_votes();
 
 	// Process all backed candidates. `validator_indices` in `BackedCandidates` are indices within
 	// the validator group assigned to the parachain. To obtain this group we need:
@@ -1192,6 +2307,10 @@ fn filter_backed_statements_from_disabled_validators<T: shared::Config + schedul
 		// If at least one statement was dropped we need to return `true`
 		if indices_to_drop.count_ones() > 0 {
 			filtered = true;
+			Pallet::<T>::deposit_event(Event::DisabledVotesDropped {
+				candidate_hash: bc.hash(),
+				validators: indices_to_drop.iter_ones().map(|idx| validator_group[idx]).collect(),
+			});
 		}
 
 		// By filtering votes we might render the candidate invalid and cause a failure in
@@ -1215,33 +2334,48 @@ fn filter_backed_statements_from_disabled_validators<T: shared::Config + schedul
 /// If the para only has one scheduled core and no `CoreIndex` is injected, map the candidate to the
 /// single core. If the para has multiple cores scheduled, only map the candidates which have a
 /// proper core injected. Filter out the rest.
-/// Also returns whether or not we dropped any candidates.
+///
+/// Candidates with an injected `CoreIndex` are bound to the core they declare, regardless of their
+/// position in `candidates`, and before any candidate without one. Those declaring a core which
+/// isn't scheduled for their para are dropped with [`DropReason::CoreNotScheduled`].
 fn map_candidates_to_cores<T: configuration::Config + scheduler::Config + inclusion::Config>(
 	allowed_relay_parents: &AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>,
 	mut scheduled: BTreeMap<ParaId, BTreeSet<CoreIndex>>,
 	core_index_enabled: bool,
 	candidates: Vec<BackedCandidate<T::Hash>>,
+	dropped_candidates: &mut Vec<(CandidateHash, DropReason)>,
 ) -> Vec<(BackedCandidate<T::Hash>, CoreIndex)> {
 	let mut backed_candidates_with_core = Vec::with_capacity(candidates.len());
+	let mut candidates_without_core = Vec::new();
 
-	// We keep a candidate if the parachain has only one core assigned or if
-	// a core index is provided by block author and it's indeed scheduled.
+	// We keep a candidate if a core index is provided by block author and it's indeed scheduled.
 	for backed_candidate in candidates {
-		let maybe_injected_core_index = get_injected_core_index::<T>(
+		let Some(core_idx) = get_injected_core_index::<T>(
 			allowed_relay_parents,
 			&backed_candidate,
 			core_index_enabled,
-		);
+		) else {
+			candidates_without_core.push(backed_candidate);
+			continue
+		};
 
-		let scheduled_cores = scheduled.get_mut(&backed_candidate.descriptor().para_id);
 		// Candidates without scheduled cores are silently filtered out.
+		let Some(scheduled_cores) = scheduled.get_mut(&backed_candidate.descriptor().para_id)
+		else {
+			continue
+		};
+		if scheduled_cores.remove(&core_idx) {
+			backed_candidates_with_core.push((backed_candidate, core_idx));
+		} else {
+			dropped_candidates.push((backed_candidate.hash(), DropReason::CoreNotScheduled));
+		}
+	}
+
+	// The remaining candidates are kept if the parachain has only one core left.
+	for backed_candidate in candidates_without_core {
+		let scheduled_cores = scheduled.get_mut(&backed_candidate.descriptor().para_id);
 		if let Some(scheduled_cores) = scheduled_cores {
-			if let Some(core_idx) = maybe_injected_core_index {
-				if scheduled_cores.contains(&core_idx) {
-					scheduled_cores.remove(&core_idx);
-					backed_candidates_with_core.push((backed_candidate, core_idx));
-				}
-			} else if scheduled_cores.len() == 1 {
+			if scheduled_cores.len() == 1 {
 				backed_candidates_with_core
 					.push((backed_candidate, scheduled_cores.pop_first().expect("Length is 1")));
 			}
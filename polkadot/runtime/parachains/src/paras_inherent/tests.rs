@@ -25,14 +25,19 @@ mod enter {
 	use super::*;
 	use crate::{
 		builder::{Bench, BenchBuilder},
-		mock::{mock_assigner, new_test_ext, BlockLength, BlockWeights, MockGenesisConfig, Test},
+		mock::{
+			bitfields_credited, mock_assigner, new_test_ext, BlockLength, BlockWeights,
+			InherentOnChainVotesRetention, MockGenesisConfig, RuntimeEvent, RuntimeOrigin, System,
+			Test,
+		},
 		scheduler::{
 			common::{Assignment, AssignmentProvider},
 			ParasEntry,
 		},
 	};
 	use assert_matches::assert_matches;
-	use frame_support::assert_ok;
+	use bitvec::{bitvec, order::Lsb0};
+	use frame_support::{assert_noop, assert_ok};
 	use frame_system::limits;
 	use primitives::vstaging::SchedulerParams;
 	use sp_runtime::Perbill;
@@ -148,6 +153,216 @@ mod enter {
 		});
 	}
 
+	#[test]
+	fn current_disputed_bitfield_without_disputes() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut backed_and_concluding = BTreeMap::new();
+			backed_and_concluding.insert(0, 1);
+			backed_and_concluding.insert(1, 1);
+
+			let _scenario = make_inherent_data(TestConfig {
+				dispute_statements: BTreeMap::new(),
+				dispute_sessions: vec![], // No disputes
+				backed_and_concluding,
+				num_validators_per_core: 1,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			// Both cores have a candidate pending availability, but none of them is disputed.
+			assert_eq!(<scheduler::Pallet<Test>>::availability_cores().len(), 2);
+			assert_eq!(Pallet::<Test>::current_disputed_bitfield(), DisputedBitfield::zeros(2));
+		});
+	}
+
+	#[test]
+	fn validate_inherent_agrees_with_enter() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut backed_and_concluding = BTreeMap::new();
+			backed_and_concluding.insert(0, 1);
+			backed_and_concluding.insert(1, 1);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements: BTreeMap::new(),
+				dispute_sessions: vec![], // No disputes
+				backed_and_concluding,
+				num_validators_per_core: 1,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			// A parent header that doesn't match is rejected, just like `enter` would.
+			let mut invalid = scenario.data.clone();
+			invalid.parent_header.number += 1;
+			assert_eq!(
+				Pallet::<Test>::validate_inherent(invalid),
+				Err(InherentError::Rejected(Error::<Test>::InvalidParentHeader.into()))
+			);
+
+			// Valid data passes the check without any state being altered.
+			assert_eq!(Pallet::<Test>::validate_inherent(scenario.data.clone()), Ok(()));
+			assert!(<scheduler::Pallet<Test>>::claimqueue_is_empty());
+			assert!(Pallet::<Test>::on_chain_votes().is_none());
+
+			assert_ok!(Pallet::<Test>::enter(
+				frame_system::RawOrigin::None.into(),
+				scenario.data.clone(),
+			));
+			assert_eq!(
+				Pallet::<Test>::on_chain_votes().unwrap().backing_validators_per_candidate.len(),
+				2
+			);
+
+			// The inherent can only be entered once per block.
+			assert_eq!(
+				Pallet::<Test>::validate_inherent(scenario.data),
+				Err(InherentError::Rejected(Error::<Test>::TooManyInclusionInherents.into()))
+			);
+		});
+	}
+
+	#[test]
+	fn core_inclusion_history_tracks_backed_cores() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut backed_and_concluding = BTreeMap::new();
+			backed_and_concluding.insert(0, 1);
+			backed_and_concluding.insert(1, 1);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements: BTreeMap::new(),
+				dispute_sessions: vec![], // No disputes
+				backed_and_concluding,
+				num_validators_per_core: 1,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			// Only core 0 receives a candidate, the one of para 1 is left out.
+			let mut data = scenario.data.clone();
+			data.backed_candidates
+				.retain(|candidate| candidate.descriptor().para_id != ParaId::from(1));
+			assert_eq!(data.backed_candidates.len(), 1);
+
+			assert_ok!(Pallet::<Test>::enter(frame_system::RawOrigin::None.into(), data));
+			assert_eq!(Pallet::<Test>::core_inclusion_history(CoreIndex(0)), bitvec![u8, Lsb0; 1]);
+			assert_eq!(Pallet::<Test>::core_inclusion_history(CoreIndex(1)), bitvec![u8, Lsb0; 0]);
+
+			// The following blocks are appended, core 1 receiving a candidate every other block.
+			for block in 0..3 {
+				let backed_cores = [CoreIndex(0)]
+					.into_iter()
+					.chain((block % 2 == 0).then_some(CoreIndex(1)))
+					.collect();
+				note_core_inclusions::<Test>(2, &backed_cores);
+			}
+			assert_eq!(
+				Pallet::<Test>::core_inclusion_history(CoreIndex(0)),
+				bitvec![u8, Lsb0; 1, 1, 1, 1]
+			);
+			assert_eq!(
+				Pallet::<Test>::core_inclusion_history(CoreIndex(1)),
+				bitvec![u8, Lsb0; 0, 1, 0, 1]
+			);
+
+			// Only the most recent blocks are kept.
+			for _ in 0..CORE_INCLUSION_HISTORY_LENGTH {
+				note_core_inclusions::<Test>(2, &[CoreIndex(0)].into_iter().collect());
+			}
+			let history = Pallet::<Test>::core_inclusion_history(CoreIndex(1));
+			assert_eq!(history.len(), CORE_INCLUSION_HISTORY_LENGTH);
+			assert!(history.not_any());
+		});
+	}
+
+	#[test]
+	fn all_disputes_future_is_reported_without_failing_the_inherent() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut backed_and_concluding = BTreeMap::new();
+			backed_and_concluding.insert(0, 1);
+			backed_and_concluding.insert(1, 1);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements: BTreeMap::new(),
+				dispute_sessions: vec![], // No disputes
+				backed_and_concluding,
+				num_validators_per_core: 1,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			let future_session = <shared::Pallet<Test>>::session_index() + 1;
+			let mut data = scenario.data.clone();
+			data.disputes = (1..=2u8)
+				.map(|i| DisputeStatementSet {
+					candidate_hash: CandidateHash(sp_core::H256::repeat_byte(i)),
+					session: future_session,
+					statements: Vec::new(),
+				})
+				.collect();
+
+			assert_eq!(
+				Pallet::<Test>::validate_inherent(data.clone()),
+				Err(InherentError::AllDisputesFuture { count: 2 })
+			);
+
+			// The disputes are dropped, everything else is still included.
+			assert_ok!(Pallet::<Test>::enter(frame_system::RawOrigin::None.into(), data));
+			assert_eq!(
+				Pallet::<Test>::on_chain_votes().unwrap().backing_validators_per_candidate.len(),
+				2
+			);
+		});
+	}
+
+	#[test]
+	fn dropped_votes_from_disabled_are_reported_by_enter() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut backed_and_concluding = BTreeMap::new();
+			backed_and_concluding.insert(0, 1);
+			backed_and_concluding.insert(1, 1);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements: BTreeMap::new(),
+				dispute_sessions: vec![], // No disputes
+				backed_and_concluding,
+				num_validators_per_core: 1,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			crate::mock::set_disabled_validators(vec![0]);
+			let disabled = <shared::Pallet<Test>>::disabled_validators();
+			assert_eq!(disabled.len(), 1);
+			System::reset_events();
+
+			// The only vote of one of the candidates is dropped along with it, the block is still
+			// accepted.
+			assert_ok!(Pallet::<Test>::enter(
+				frame_system::RawOrigin::None.into(),
+				scenario.data.clone()
+			));
+			let events = System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::ParaInherent(Event::DisabledVotesDropped {
+						candidate_hash,
+						validators,
+					}) => Some((candidate_hash, validators)),
+					_ => None,
+				})
+				.collect::<Vec<_>>();
+			assert_eq!(events.len(), 1);
+			let (dropped, validators) = &events[0];
+			assert_eq!(validators, &disabled);
+			assert!(scenario.data.backed_candidates.iter().any(|c| c.hash() == *dropped));
+
+			let backing_validators =
+				Pallet::<Test>::on_chain_votes().unwrap().backing_validators_per_candidate;
+			assert_eq!(backing_validators.len(), 1);
+			assert_ne!(backing_validators[0].0.hash(), *dropped);
+		});
+	}
+
 	#[test]
 	fn test_session_is_tracked_in_on_chain_scraping() {
 		use crate::disputes::run_to_block;
@@ -239,6 +454,202 @@ mod enter {
 		});
 	}
 
+	#[test]
+	fn recent_on_chain_votes_are_pruned_by_block_count() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let votes = ScrapedOnChainVotes {
+				session: 1,
+				backing_validators_per_candidate: Vec::new(),
+				disputes: Vec::new(),
+			};
+			let finalize = |block: u32| {
+				OnChainVotes::<Test>::put(votes.clone());
+				Included::<Test>::set(Some(()));
+				Pallet::<Test>::on_finalize(block);
+			};
+
+			// By default only the votes of the latest block are kept.
+			finalize(1);
+			assert!(Pallet::<Test>::recent_on_chain_votes().is_empty());
+
+			InherentOnChainVotesRetention::set(OnChainVotesRetention::Blocks(2));
+			for block in 2..=4 {
+				finalize(block);
+			}
+
+			// All votes are from the same session, still only those of the last 2 blocks are kept.
+			assert_eq!(
+				Pallet::<Test>::recent_on_chain_votes(),
+				vec![(3, votes.clone()), (4, votes.clone())]
+			);
+		});
+	}
+
+	#[test]
+	fn force_clear_scraped_votes_clears_all_votes() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut backed_and_concluding = BTreeMap::new();
+			backed_and_concluding.insert(0, 1);
+			backed_and_concluding.insert(1, 1);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements: BTreeMap::new(),
+				dispute_sessions: vec![], // No disputes
+				backed_and_concluding,
+				num_validators_per_core: 1,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			InherentOnChainVotesRetention::set(OnChainVotesRetention::Blocks(2));
+			assert_ok!(Pallet::<Test>::enter(
+				frame_system::RawOrigin::None.into(),
+				scenario.data.clone(),
+			));
+			Pallet::<Test>::on_finalize(System::block_number());
+			assert_eq!(
+				Pallet::<Test>::on_chain_votes().unwrap().backing_validators_per_candidate.len(),
+				2
+			);
+			assert_eq!(Pallet::<Test>::recent_on_chain_votes().len(), 1);
+
+			assert_noop!(
+				Pallet::<Test>::force_clear_scraped_votes(RuntimeOrigin::signed(1)),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Pallet::<Test>::force_clear_scraped_votes(RuntimeOrigin::root()));
+			assert!(Pallet::<Test>::on_chain_votes().is_none());
+			assert!(Pallet::<Test>::on_chain_disputes_inclusion().is_empty());
+			assert!(Pallet::<Test>::recent_on_chain_votes().is_empty());
+			System::assert_last_event(RuntimeEvent::ParaInherent(Event::ScrapedVotesCleared));
+
+			// The votes of the following inherent are scraped again.
+			let mut data = scenario.data;
+			data.bitfields.clear();
+			data.backed_candidates.clear();
+			assert_ok!(Pallet::<Test>::enter(frame_system::RawOrigin::None.into(), data));
+			assert_eq!(
+				Pallet::<Test>::on_chain_votes().unwrap().session,
+				<shared::Pallet<Test>>::session_index()
+			);
+		});
+	}
+
+	#[test]
+	fn bitfields_are_credited_at_session_end() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut backed_and_concluding = BTreeMap::new();
+			backed_and_concluding.insert(0, 1);
+			backed_and_concluding.insert(1, 1);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements: BTreeMap::new(),
+				dispute_sessions: vec![], // No disputes
+				backed_and_concluding,
+				num_validators_per_core: 1,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+			let session = <shared::Pallet<Test>>::session_index();
+
+			// 1 bitfield per validator (2 validators) in each of the two blocks.
+			assert_ok!(Pallet::<Test>::enter(
+				frame_system::RawOrigin::None.into(),
+				scenario.data.clone(),
+			));
+			Pallet::<Test>::on_finalize(System::block_number());
+			let mut data = scenario.data.clone();
+			data.backed_candidates.clear();
+			assert_ok!(Pallet::<Test>::enter(frame_system::RawOrigin::None.into(), data.clone()));
+			Pallet::<Test>::on_finalize(System::block_number());
+
+			assert_eq!(Pallet::<Test>::bitfields_credited(ValidatorIndex(0)), 2);
+			assert_eq!(Pallet::<Test>::bitfields_credited(ValidatorIndex(1)), 2);
+			assert!(bitfields_credited().is_empty());
+
+			// The counts are handed over once the next session starts, and then start over. The
+			// bitfields signed in the previous session are dropped.
+			<shared::Pallet<Test>>::set_session_index(session + 1);
+			assert_ok!(Pallet::<Test>::enter(frame_system::RawOrigin::None.into(), data));
+			assert_eq!(
+				bitfields_credited(),
+				vec![(session, [(ValidatorIndex(0), 2), (ValidatorIndex(1), 2)].into())]
+			);
+			assert_eq!(Pallet::<Test>::bitfields_credited(ValidatorIndex(0)), 0);
+		});
+	}
+
+	#[test]
+	fn force_set_disputed_cores_masks_bitfields() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut backed_and_concluding = BTreeMap::new();
+			backed_and_concluding.insert(0, 1);
+			backed_and_concluding.insert(1, 1);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements: BTreeMap::new(),
+				dispute_sessions: vec![], // No disputes
+				backed_and_concluding,
+				num_validators_per_core: 1,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			let parent_hash = scenario.data.parent_header.hash();
+			let session_index = <shared::Pallet<Test>>::session_index();
+			let validators = <shared::Pallet<Test>>::active_validator_keys();
+			let sanitize = |disputed_bitfield| {
+				sanitize_bitfields::<Test>(
+					scenario.data.bitfields.clone(),
+					disputed_bitfield,
+					2,
+					None,
+					parent_hash,
+					session_index,
+					&validators[..],
+					None,
+					&mut Vec::new(),
+				)
+			};
+			assert_eq!(sanitize(Pallet::<Test>::current_disputed_bitfield()).len(), 2);
+
+			assert_noop!(
+				Pallet::<Test>::force_set_disputed_cores(
+					RuntimeOrigin::signed(1),
+					vec![CoreIndex(0)]
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Pallet::<Test>::force_set_disputed_cores(
+					RuntimeOrigin::root(),
+					vec![CoreIndex(0), CoreIndex(1), CoreIndex(2)]
+				),
+				Error::<Test>::TooManyDisputedCores
+			);
+			assert_ok!(Pallet::<Test>::force_set_disputed_cores(
+				RuntimeOrigin::root(),
+				vec![CoreIndex(0)]
+			));
+			System::assert_last_event(RuntimeEvent::ParaInherent(Event::DisputedCoresForced {
+				cores: vec![CoreIndex(0)],
+			}));
+
+			let mut disputed_bitfield = DisputedBitfield::zeros(2);
+			disputed_bitfield.0.set(0, true);
+			assert_eq!(Pallet::<Test>::current_disputed_bitfield(), disputed_bitfield);
+			// Both bitfields set the bit of the forced core, so both are dropped.
+			assert!(sanitize(Pallet::<Test>::current_disputed_bitfield()).is_empty());
+
+			// The cores are only forced disputed for the next inherent.
+			assert_ok!(Pallet::<Test>::enter(
+				frame_system::RawOrigin::None.into(),
+				scenario.data.clone(),
+			));
+			assert!(Pallet::<Test>::forced_disputed_cores().is_empty());
+		});
+	}
+
 	#[test]
 	// Ensure that disputes are filtered out if the session is in the future.
 	fn filter_multi_dispute_data() {
@@ -309,28 +720,106 @@ mod enter {
 	}
 
 	#[test]
-	// Ensure that when dispute data establishes an over weight block that we adequately
-	// filter out disputes according to our prioritization rule
-	fn limit_dispute_data() {
-		sp_tracing::try_init_simple();
-		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
-			// Create the inherent data for this block
-			let dispute_statements = BTreeMap::new();
-			// No backed and concluding cores, so all cores will be filled with disputes.
-			let backed_and_concluding = BTreeMap::new();
+	// Ensure that a core occupied by a candidate concluded invalid in a dispute is freed and can
+	// be reassigned in the next block.
+	fn dispute_concluded_invalid_frees_core() {
+		use primitives::{
+			DisputeStatement, InvalidDisputeStatementKind, ValidDisputeStatementKind,
+		};
+		use sp_runtime::RuntimeAppPublic;
 
-			let scenario = make_inherent_data(TestConfig {
-				dispute_statements,
-				dispute_sessions: vec![2, 2, 1], // 3 cores with disputes
-				backed_and_concluding,
-				num_validators_per_core: 6,
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut scenario = make_inherent_data(TestConfig {
+				dispute_statements: BTreeMap::new(),
+				dispute_sessions: vec![2], // 1 core with a dispute in the current session
+				backed_and_concluding: BTreeMap::new(),
+				num_validators_per_core: 5,
 				code_upgrade: None,
 				fill_claimqueue: false,
 			});
 
-			let expected_para_inherent_data = scenario.data.clone();
+			let core = CoreIndex(0);
+			let para_id = ParaId::from(0);
+			let candidate_hash = scenario.data.disputes[0].candidate_hash;
+			assert_eq!(
+				<inclusion::Pallet<Test>>::pending_availability(para_id)
+					.unwrap()
+					.candidate_hash(),
+				candidate_hash
+			);
 
-			// Check the para inherent data is as expected:
+			// Sign the dispute over so that a supermajority of the validators votes against the
+			// candidate, keeping a single backing vote for the dispute to be accepted.
+			let validators = <shared::Pallet<Test>>::active_validator_keys();
+			let dispute = &mut scenario.data.disputes[0];
+			let relay_parent = match dispute.statements[1].0 {
+				DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(relay_parent)) =>
+					relay_parent,
+				_ => panic!("the builder creates backing votes; qed"),
+			};
+			let session = dispute.session;
+			dispute.statements = validators
+				.iter()
+				.enumerate()
+				.map(|(index, validator)| {
+					let statement = if index == 0 {
+						DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(
+							relay_parent,
+						))
+					} else {
+						DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit)
+					};
+					let payload = statement.payload_data(candidate_hash, session).unwrap();
+					let signature = validator.sign(&payload).unwrap();
+					(statement, ValidatorIndex(index as u32), signature)
+				})
+				.collect();
+
+			// The core is up for reassignment once freed.
+			mock_assigner::Pallet::<Test>::add_test_assignment(Assignment::Bulk(para_id));
+
+			assert_ok!(Pallet::<Test>::enter(
+				frame_system::RawOrigin::None.into(),
+				scenario.data.clone(),
+			));
+
+			assert!(<Test as Config>::DisputesHandler::concluded_invalid(session, candidate_hash));
+			let freed =
+				RuntimeEvent::ParaInherent(Event::CoreFreedByInvalidity { core, candidate_hash });
+			assert!(System::events().into_iter().any(|record| record.event == freed));
+			assert!(<inclusion::Pallet<Test>>::pending_availability(para_id).is_none());
+			assert_matches!(
+				<scheduler::Pallet<Test>>::availability_cores()[core.0 as usize],
+				scheduler::CoreOccupied::Free
+			);
+			assert!(<scheduler::Pallet<Test>>::scheduled_paras()
+				.any(|scheduled| scheduled == (core, para_id)));
+		});
+	}
+
+	#[test]
+	// Ensure that when dispute data establishes an over weight block that we adequately
+	// filter out disputes according to our prioritization rule
+	fn limit_dispute_data() {
+		sp_tracing::try_init_simple();
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			// Create the inherent data for this block
+			let dispute_statements = BTreeMap::new();
+			// No backed and concluding cores, so all cores will be filled with disputes.
+			let backed_and_concluding = BTreeMap::new();
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements,
+				dispute_sessions: vec![2, 2, 1], // 3 cores with disputes
+				backed_and_concluding,
+				num_validators_per_core: 6,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			let expected_para_inherent_data = scenario.data.clone();
+
+			// Check the para inherent data is as expected:
 			// * 1 bitfield per validator (6 validators per core, 3 disputes => 18 validators)
 			assert_eq!(expected_para_inherent_data.bitfields.len(), 18);
 			// * 0 backed candidate per core
@@ -355,10 +844,19 @@ mod enter {
 			assert_eq!(limit_inherent_data.disputes[0].session, 1);
 			assert_eq!(limit_inherent_data.disputes[1].session, 2);
 
+			// Only the applied disputes are weighed, and they dominate the inherent weight.
+			let (disputes_weight, bitfields_weight, candidates_weight) =
+				Pallet::<Test>::inherent_weight_breakdown(&limit_inherent_data);
+
 			assert_ok!(Pallet::<Test>::enter(
 				frame_system::RawOrigin::None.into(),
 				limit_inherent_data,
 			));
+			assert!(disputes_weight.any_lt(multi_dispute_statement_sets_weight::<Test>(
+				&expected_para_inherent_data.disputes
+			)));
+			assert!(disputes_weight.all_gt(bitfields_weight));
+			assert_eq!(candidates_weight, Weight::zero());
 
 			assert_eq!(
 				// Ensure that our inherent data did not included backed candidates as expected
@@ -727,6 +1225,20 @@ mod enter {
 		});
 	}
 
+	#[test]
+	fn inherent_weight_limits_are_proof_size_adjusted() {
+		BlockLength::set(limits::BlockLength::max_with_normal_ratio(
+			600,
+			Perbill::from_percent(75),
+		));
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			assert_eq!(
+				Pallet::<Test>::inherent_weight_limits(),
+				(max_block_weight_proof_size_adjusted(), 600)
+			);
+		});
+	}
+
 	#[test]
 	fn disputes_are_size_limited() {
 		BlockLength::set(limits::BlockLength::max_with_normal_ratio(
@@ -793,6 +1305,166 @@ mod enter {
 		});
 	}
 
+	#[test]
+	fn reserved_bitfields_survive_disputes() {
+		BlockLength::set(limits::BlockLength::max_with_normal_ratio(
+			600,
+			Perbill::from_percent(75),
+		));
+		// Virtually no time based limit:
+		BlockWeights::set(frame_system::limits::BlockWeights::simple_max(Weight::from_parts(
+			u64::MAX,
+			u64::MAX,
+		)));
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			// Same scenario as in `disputes_are_size_limited`, where all bitfields are dropped.
+			let mut dispute_statements = BTreeMap::new();
+			dispute_statements.insert(2, 7);
+			dispute_statements.insert(3, 7);
+			dispute_statements.insert(4, 7);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements,
+				dispute_sessions: vec![2, 2, 1], // 3 cores with disputes
+				backed_and_concluding: BTreeMap::new(),
+				num_validators_per_core: 5,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			let mut hc = configuration::Pallet::<Test>::config();
+			hc.min_reserved_bitfields = 2;
+			configuration::Pallet::<Test>::force_set_active_config(hc);
+
+			let expected_para_inherent_data = scenario.data.clone();
+			assert_eq!(expected_para_inherent_data.bitfields.len(), 15);
+			assert_eq!(expected_para_inherent_data.disputes.len(), 3);
+			let mut inherent_data = InherentData::new();
+			inherent_data
+				.put_data(PARACHAINS_INHERENT_IDENTIFIER, &expected_para_inherent_data)
+				.unwrap();
+			let limit_inherent_data =
+				Pallet::<Test>::create_inherent_inner(&inherent_data.clone()).unwrap();
+			assert!(
+				max_block_weight_proof_size_adjusted()
+					.all_gte(inherent_data_weight(&limit_inherent_data)),
+				"Post limiting exceeded block weight: max={} vs. inherent={}",
+				max_block_weight_proof_size_adjusted(),
+				inherent_data_weight(&limit_inherent_data)
+			);
+
+			// The disputes had to make room for at least the reserved bitfields.
+			assert!(limit_inherent_data.bitfields.len() >= 2);
+			assert!(limit_inherent_data.disputes.len() <= 1);
+		});
+	}
+
+	#[test]
+	fn min_disputes_are_included_despite_reserved_bitfields() {
+		BlockLength::set(limits::BlockLength::max_with_normal_ratio(
+			600,
+			Perbill::from_percent(75),
+		));
+		// Virtually no time based limit:
+		BlockWeights::set(frame_system::limits::BlockWeights::simple_max(Weight::from_parts(
+			u64::MAX,
+			u64::MAX,
+		)));
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			// Same scenario as in `disputes_are_size_limited`, where a single dispute fits.
+			let mut dispute_statements = BTreeMap::new();
+			dispute_statements.insert(2, 7);
+			dispute_statements.insert(3, 7);
+			dispute_statements.insert(4, 7);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements,
+				dispute_sessions: vec![2, 2, 1], // 3 cores with disputes
+				backed_and_concluding: BTreeMap::new(),
+				num_validators_per_core: 5,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			// All of the bitfields are reserved, which leaves little to no weight to the disputes.
+			let mut hc = configuration::Pallet::<Test>::config();
+			hc.min_reserved_bitfields = 15;
+			hc.min_disputes_included = 1;
+			configuration::Pallet::<Test>::force_set_active_config(hc);
+
+			let expected_para_inherent_data = scenario.data.clone();
+			assert_eq!(expected_para_inherent_data.bitfields.len(), 15);
+			assert_eq!(expected_para_inherent_data.disputes.len(), 3);
+			let mut inherent_data = InherentData::new();
+			inherent_data
+				.put_data(PARACHAINS_INHERENT_IDENTIFIER, &expected_para_inherent_data)
+				.unwrap();
+			let limit_inherent_data =
+				Pallet::<Test>::create_inherent_inner(&inherent_data.clone()).unwrap();
+			assert!(
+				max_block_weight_proof_size_adjusted()
+					.all_gte(inherent_data_weight(&limit_inherent_data)),
+				"Post limiting exceeded block weight: max={} vs. inherent={}",
+				max_block_weight_proof_size_adjusted(),
+				inherent_data_weight(&limit_inherent_data)
+			);
+
+			// The minimum number of disputes is included at the expense of the reserved bitfields.
+			assert_eq!(limit_inherent_data.disputes.len(), 1);
+			assert_eq!(limit_inherent_data.bitfields.len(), 0);
+		});
+	}
+
+	#[test]
+	fn dispute_statements_are_limited_per_block() {
+		// Virtually no time based limit:
+		BlockWeights::set(frame_system::limits::BlockWeights::simple_max(Weight::from_parts(
+			u64::MAX,
+			u64::MAX,
+		)));
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			let mut dispute_statements = BTreeMap::new();
+			dispute_statements.insert(0, 5);
+			dispute_statements.insert(1, 5);
+			dispute_statements.insert(2, 5);
+
+			let scenario = make_inherent_data(TestConfig {
+				dispute_statements,
+				dispute_sessions: vec![2, 2, 1], // 3 cores with disputes
+				backed_and_concluding: BTreeMap::new(),
+				num_validators_per_core: 5,
+				code_upgrade: None,
+				fill_claimqueue: false,
+			});
+
+			// Room for exactly two of the disputes.
+			let mut hc = configuration::Pallet::<Test>::config();
+			hc.max_dispute_statements_per_block = Some(10);
+			configuration::Pallet::<Test>::force_set_active_config(hc);
+
+			let expected_para_inherent_data = scenario.data.clone();
+			assert_eq!(expected_para_inherent_data.disputes.len(), 3);
+			let mut inherent_data = InherentData::new();
+			inherent_data
+				.put_data(PARACHAINS_INHERENT_IDENTIFIER, &expected_para_inherent_data)
+				.unwrap();
+			let limit_inherent_data =
+				Pallet::<Test>::create_inherent_inner(&inherent_data.clone()).unwrap();
+
+			// The dispute of the older session comes first, the two of the same session are
+			// ordered by candidate hash, so the last one of those is dropped.
+			let disputes = &expected_para_inherent_data.disputes;
+			assert_eq!(
+				limit_inherent_data
+					.disputes
+					.iter()
+					.map(|set| set.candidate_hash)
+					.collect::<Vec<_>>(),
+				vec![disputes[2].candidate_hash, disputes[0].candidate_hash]
+			);
+		});
+	}
+
 	#[test]
 	fn bitfields_are_size_limited() {
 		BlockLength::set(limits::BlockLength::max_with_normal_ratio(
@@ -1084,9 +1756,12 @@ mod sanitizers {
 					unchecked_bitfields.clone(),
 					disputed_bitfield.clone(),
 					expected_bits,
+					None,
 					parent_hash,
 					session_index,
 					&validator_public[..],
+					None,
+					&mut Vec::new(),
 				),
 				checked_bitfields.clone()
 			);
@@ -1095,9 +1770,12 @@ mod sanitizers {
 					unchecked_bitfields.clone(),
 					disputed_bitfield.clone(),
 					expected_bits,
+					None,
 					parent_hash,
 					session_index,
 					&validator_public[..],
+					None,
+					&mut Vec::new(),
 				),
 				checked_bitfields.clone()
 			);
@@ -1115,9 +1793,12 @@ mod sanitizers {
 					unchecked_bitfields.clone(),
 					disputed_bitfield.clone(),
 					expected_bits,
+					None,
 					parent_hash,
 					session_index,
 					&validator_public[..],
+					None,
+					&mut Vec::new(),
 				)
 				.len(),
 				1
@@ -1127,9 +1808,12 @@ mod sanitizers {
 					unchecked_bitfields.clone(),
 					disputed_bitfield.clone(),
 					expected_bits,
+					None,
 					parent_hash,
 					session_index,
 					&validator_public[..],
+					None,
+					&mut Vec::new(),
 				)
 				.len(),
 				1
@@ -1142,18 +1826,24 @@ mod sanitizers {
 				unchecked_bitfields.clone(),
 				disputed_bitfield.clone(),
 				expected_bits + 1,
+				None,
 				parent_hash,
 				session_index,
 				&validator_public[..],
+				None,
+				&mut Vec::new(),
 			)
 			.is_empty());
 			assert!(sanitize_bitfields::<Test>(
 				unchecked_bitfields.clone(),
 				disputed_bitfield.clone(),
 				expected_bits + 1,
+				None,
 				parent_hash,
 				session_index,
 				&validator_public[..],
+				None,
+				&mut Vec::new(),
 			)
 			.is_empty());
 		}
@@ -1166,9 +1856,12 @@ mod sanitizers {
 					unchecked_bitfields.clone(),
 					disputed_bitfield.clone(),
 					expected_bits,
+					None,
 					parent_hash,
 					session_index,
 					&validator_public[..shortened],
+					None,
+					&mut Vec::new(),
 				)[..],
 				&checked_bitfields[..shortened]
 			);
@@ -1177,9 +1870,12 @@ mod sanitizers {
 					unchecked_bitfields.clone(),
 					disputed_bitfield.clone(),
 					expected_bits,
+					None,
 					parent_hash,
 					session_index,
 					&validator_public[..shortened],
+					None,
+					&mut Vec::new(),
 				)[..],
 				&checked_bitfields[..shortened]
 			);
@@ -1194,9 +1890,12 @@ mod sanitizers {
 				unchecked_bitfields.clone(),
 				disputed_bitfield.clone(),
 				expected_bits,
+				None,
 				parent_hash,
 				session_index,
 				&validator_public[..],
+				None,
+				&mut Vec::new(),
 			)
 			.into_iter()
 			.map(|v| v.into_unchecked())
@@ -1219,9 +1918,12 @@ mod sanitizers {
 					unchecked_bitfields.clone(),
 					disputed_bitfield.clone(),
 					expected_bits,
+					None,
 					parent_hash,
 					session_index,
 					&validator_public[..],
+					None,
+					&mut Vec::new(),
 				)[..],
 				&checked_bitfields[..last_bit_idx]
 			);
@@ -1241,46 +1943,217 @@ mod sanitizers {
 					unchecked_bitfields.clone().into_iter().chain(unchecked_bitfields).collect(),
 					disputed_bitfield.clone(),
 					expected_bits,
+					None,
 					parent_hash,
 					session_index,
 					&validator_public[..],
+					None,
+					&mut Vec::new(),
 				)[..],
 				&checked_bitfields[..last_bit_idx]
 			);
 		}
-	}
 
-	mod candidates {
-		use crate::{
-			mock::set_disabled_validators,
-			scheduler::{common::Assignment, ParasEntry},
-		};
-		use sp_std::collections::vec_deque::VecDeque;
+		// bitfields signed for a stale parent hash are dropped and reported as such
+		{
+			let stale_parent_hash = Hash::repeat_byte(0xAB);
+			let stale_signing_context =
+				SigningContext { parent_hash: stale_parent_hash, session_index };
+			let last_bit_idx = unchecked_bitfields.len() - 1;
+			let mut unchecked_bitfields = unchecked_bitfields.clone();
+			unchecked_bitfields[last_bit_idx] = SignedAvailabilityBitfield::sign(
+				&crypto_store,
+				unchecked_bitfields[last_bit_idx].unchecked_payload().clone(),
+				&stale_signing_context,
+				ValidatorIndex(last_bit_idx as u32),
+				&validator_public[last_bit_idx],
+			)
+			.unwrap()
+			.unwrap()
+			.into_unchecked();
 
-		use super::*;
+			let mut dropped_bitfields = Vec::new();
+			assert_eq!(
+				&sanitize_bitfields::<Test>(
+					unchecked_bitfields.clone(),
+					disputed_bitfield.clone(),
+					expected_bits,
+					None,
+					parent_hash,
+					session_index,
+					&validator_public[..],
+					Some(stale_parent_hash),
+					&mut dropped_bitfields,
+				)[..],
+				&checked_bitfields[..last_bit_idx]
+			);
+			assert_eq!(
+				dropped_bitfields,
+				vec![(ValidatorIndex(last_bit_idx as u32), BitfieldDropReason::StaleParentHash)]
+			);
 
-		// Backed candidates and scheduled parachains used for `sanitize_backed_candidates` testing
-		struct TestData {
-			backed_candidates: Vec<BackedCandidate>,
-			all_backed_candidates_with_core: Vec<(BackedCandidate, CoreIndex)>,
-			scheduled_paras: BTreeMap<primitives::Id, BTreeSet<CoreIndex>>,
+			// without knowing the stale parent hash, the signature is merely invalid
+			let mut dropped_bitfields = Vec::new();
+			sanitize_bitfields::<Test>(
+				unchecked_bitfields,
+				disputed_bitfield.clone(),
+				expected_bits,
+				None,
+				parent_hash,
+				session_index,
+				&validator_public[..],
+				None,
+				&mut dropped_bitfields,
+			);
+			assert_eq!(
+				dropped_bitfields,
+				vec![(ValidatorIndex(last_bit_idx as u32), BitfieldDropReason::InvalidSignature)]
+			);
 		}
 
-		// Generate test data for the candidates and assert that the evnironment is set as expected
-		// (check the comments for details)
-		fn get_test_data(core_index_enabled: bool) -> TestData {
-			const RELAY_PARENT_NUM: u32 = 3;
-
-			// Add the relay parent to `shared` pallet. Otherwise some code (e.g. filtering backing
-			// votes) won't behave correctly
-			shared::Pallet::<Test>::add_allowed_relay_parent(
-				default_header().hash(),
-				Default::default(),
-				RELAY_PARENT_NUM,
-				1,
+		// bitfields of validators outside the active set are rejected explicitly
+		{
+			let active = checked_bitfields.len() - 1;
+			let mut dropped_bitfields = Vec::new();
+			assert_eq!(
+				&sanitize_bitfields::<Test>(
+					unchecked_bitfields.clone(),
+					disputed_bitfield.clone(),
+					expected_bits,
+					None,
+					parent_hash,
+					session_index,
+					&validator_public[..active],
+					None,
+					&mut dropped_bitfields,
+				)[..],
+				&checked_bitfields[..active]
 			);
-
-			let header = default_header();
+			assert_eq!(
+				dropped_bitfields,
+				vec![(ValidatorIndex(active as u32), BitfieldDropReason::UnknownValidator)]
+			);
+		}
+
+		// beyond `max_bitfields`, the bitfields with the most availability bits set are kept in
+		// their original order.
+		{
+			let bitfields_by_value = [
+				{
+					let mut bv = BitVec::<u8, Lsb0>::repeat(false, expected_bits);
+					bv.set(expected_bits - 1, true);
+					bv
+				},
+				BitVec::<u8, Lsb0>::repeat(true, expected_bits),
+				BitVec::<u8, Lsb0>::repeat(false, expected_bits),
+			]
+			.iter()
+			.enumerate()
+			.map(|(vi, ab)| {
+				SignedAvailabilityBitfield::sign(
+					&crypto_store,
+					AvailabilityBitfield::from(ab.clone()),
+					&signing_context,
+					ValidatorIndex::from(vi as u32),
+					&validator_public[vi],
+				)
+				.unwrap()
+				.unwrap()
+			})
+			.collect::<Vec<SignedAvailabilityBitfield>>();
+			let unchecked_bitfields = bitfields_by_value
+				.iter()
+				.cloned()
+				.map(|v| v.into_unchecked())
+				.collect::<Vec<_>>();
+
+			let sanitize_with_max = |max_bitfields| {
+				sanitize_bitfields::<Test>(
+					unchecked_bitfields.clone(),
+					disputed_bitfield.clone(),
+					expected_bits,
+					Some(max_bitfields),
+					parent_hash,
+					session_index,
+					&validator_public[..],
+					None,
+					&mut Vec::new(),
+				)
+			};
+
+			assert_eq!(sanitize_with_max(1), vec![bitfields_by_value[1].clone()]);
+			assert_eq!(sanitize_with_max(2), bitfields_by_value[..2].to_vec());
+			assert_eq!(sanitize_with_max(3), bitfields_by_value.to_vec());
+		}
+
+		// on ties under `max_bitfields`, the bitfield of the lower validator index is kept.
+		{
+			let tied_bitfields = [2, 3]
+				.into_iter()
+				.map(|vi| {
+					SignedAvailabilityBitfield::sign(
+						&crypto_store,
+						AvailabilityBitfield::from(BitVec::<u8, Lsb0>::repeat(true, expected_bits)),
+						&signing_context,
+						ValidatorIndex::from(vi as u32),
+						&validator_public[vi],
+					)
+					.unwrap()
+					.unwrap()
+				})
+				.collect::<Vec<SignedAvailabilityBitfield>>();
+
+			assert_eq!(
+				sanitize_bitfields::<Test>(
+					tied_bitfields.iter().cloned().map(|v| v.into_unchecked()).collect(),
+					disputed_bitfield.clone(),
+					expected_bits,
+					Some(1),
+					parent_hash,
+					session_index,
+					&validator_public[..],
+					None,
+					&mut Vec::new(),
+				),
+				vec![tied_bitfields[0].clone()]
+			);
+		}
+	}
+
+	mod candidates {
+		use crate::{
+			mock::{
+				set_disabled_validators, InherentDisabledValidatorsGracePeriod, RuntimeEvent,
+				System,
+			},
+			scheduler::{common::Assignment, ParasEntry},
+		};
+		use sp_std::collections::vec_deque::VecDeque;
+
+		use super::*;
+
+		// Backed candidates and scheduled parachains used for `sanitize_backed_candidates` testing
+		struct TestData {
+			backed_candidates: Vec<BackedCandidate>,
+			all_backed_candidates_with_core: Vec<(BackedCandidate, CoreIndex)>,
+			scheduled_paras: BTreeMap<primitives::Id, BTreeSet<CoreIndex>>,
+		}
+
+		// Generate test data for the candidates and assert that the evnironment is set as expected
+		// (check the comments for details)
+		fn get_test_data(core_index_enabled: bool) -> TestData {
+			const RELAY_PARENT_NUM: u32 = 3;
+
+			// Add the relay parent to `shared` pallet. Otherwise some code (e.g. filtering backing
+			// votes) won't behave correctly
+			shared::Pallet::<Test>::add_allowed_relay_parent(
+				default_header().hash(),
+				Default::default(),
+				RELAY_PARENT_NUM,
+				1,
+			);
+
+			let header = default_header();
 			let relay_parent = header.hash();
 			let session_index = SessionIndex::from(0_u32);
 
@@ -1691,7 +2564,10 @@ mod sanitizers {
 					None,
 				);
 				backed_candidates.push(backed.clone());
-				all_backed_candidates_with_core.push((backed, CoreIndex(5)));
+				// The candidate declaring its core takes precedence, if core indices are enabled.
+				if !core_index_enabled {
+					all_backed_candidates_with_core.push((backed, CoreIndex(5)));
+				}
 
 				let mut candidate = TestCandidateBuilder {
 					para_id: ParaId::from(4),
@@ -1715,6 +2591,9 @@ mod sanitizers {
 					core_index_enabled.then_some(CoreIndex(5 as u32)),
 				);
 				backed_candidates.push(backed.clone());
+				if core_index_enabled {
+					all_backed_candidates_with_core.push((backed, CoreIndex(5)));
+				}
 			}
 
 			// No candidate for para 5.
@@ -1732,6 +2611,16 @@ mod sanitizers {
 					(CoreIndex(6), ParaId::from(5)),
 				]
 			);
+			assert_eq!(
+				<scheduler::Pallet<Test>>::scheduled_cores_per_para(),
+				BTreeMap::from([
+					(ParaId::from(1), 2),
+					(ParaId::from(2), 2),
+					(ParaId::from(3), 1),
+					(ParaId::from(4), 1),
+					(ParaId::from(5), 1),
+				])
+			);
 			let mut scheduled: BTreeMap<ParaId, BTreeSet<CoreIndex>> = BTreeMap::new();
 			for (core_idx, para_id) in <scheduler::Pallet<Test>>::scheduled_paras() {
 				scheduled.entry(para_id).or_default().insert(core_idx);
@@ -1782,7 +2671,9 @@ mod sanitizers {
 					SanitizedBackedCandidates {
 						backed_candidates_with_core: all_backed_candidates_with_core,
 						votes_from_disabled_were_dropped: false,
-						dropped_unscheduled_candidates: false
+						dropped_unscheduled_candidates: false,
+						trimmed_paras: BTreeMap::new(),
+						dropped_candidates: Vec::new(),
 					}
 				);
 			});
@@ -1802,6 +2693,19 @@ mod sanitizers {
 				let has_concluded_invalid =
 					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
 
+				// Para 4 submits two candidates but only has one core, so only one of them is
+				// included. Without core indices, paras 1 and 2 have no candidates included as
+				// they have multiple cores assigned.
+				let mut expected_trimmed_paras = BTreeMap::new();
+				expected_trimmed_paras
+					.insert(ParaId::from(4), ParaCandidateCounts { submitted: 2, included: 1 });
+				if !core_index_enabled {
+					expected_trimmed_paras
+						.insert(ParaId::from(1), ParaCandidateCounts { submitted: 2, included: 0 });
+					expected_trimmed_paras
+						.insert(ParaId::from(2), ParaCandidateCounts { submitted: 1, included: 0 });
+				}
+
 				assert_eq!(
 					sanitize_backed_candidates::<Test, _>(
 						backed_candidates.clone(),
@@ -1813,12 +2717,211 @@ mod sanitizers {
 					SanitizedBackedCandidates {
 						backed_candidates_with_core: expected_all_backed_candidates_with_core,
 						votes_from_disabled_were_dropped: false,
-						dropped_unscheduled_candidates: true
+						dropped_unscheduled_candidates: true,
+						trimmed_paras: expected_trimmed_paras,
+						dropped_candidates: Vec::new(),
 					}
 				);
 			});
 		}
 
+		// candidates larger than `max_candidate_encoded_size` are dropped, regardless of the others
+		#[test]
+		fn oversized_candidates_are_dropped() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData {
+					mut backed_candidates,
+					all_backed_candidates_with_core,
+					scheduled_paras: scheduled,
+				} = get_test_data_multiple_cores_per_para(true);
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				let max_candidate_encoded_size = backed_candidates
+					.iter()
+					.map(|candidate| candidate.encoded_size())
+					.max()
+					.unwrap();
+				let mut hc = configuration::Pallet::<Test>::config();
+				hc.max_candidate_encoded_size = Some(max_candidate_encoded_size as u32);
+				configuration::Pallet::<Test>::force_set_active_config(hc);
+
+				// Para 3 submits a candidate with a huge head instead.
+				let para_3 = backed_candidates[3].clone();
+				assert_eq!(para_3.descriptor().para_id, ParaId::from(3));
+				let (validator_indices, core_index) = para_3.validator_indices_and_core_index(true);
+				let mut candidate = para_3.candidate().clone();
+				candidate.commitments.head_data = primitives::HeadData(vec![0xFF; 1024]);
+				let oversized = BackedCandidate::new(
+					candidate,
+					para_3.validity_votes().to_vec(),
+					validator_indices.to_bitvec(),
+					core_index,
+				);
+				assert!(oversized.encoded_size() > max_candidate_encoded_size);
+				backed_candidates[3] = oversized.clone();
+
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core,
+					dropped_candidates,
+					..
+				} = sanitize_backed_candidates::<Test, _>(
+					backed_candidates,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled,
+					true,
+				);
+
+				assert_eq!(
+					backed_candidates_with_core,
+					all_backed_candidates_with_core
+						.into_iter()
+						.filter(|(candidate, _)| *candidate != para_3)
+						.collect::<Vec<_>>()
+				);
+				assert_eq!(
+					dropped_candidates,
+					vec![(oversized.hash(), DropReason::CandidateTooLarge)]
+				);
+			});
+		}
+
+		// candidates submitted with one vote short of the backing threshold are dropped, even if
+		// none of the validators are disabled
+		#[test]
+		fn candidates_below_backing_threshold_are_dropped() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData {
+					mut backed_candidates,
+					all_backed_candidates_with_core,
+					scheduled_paras: scheduled,
+				} = get_test_data(true);
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				// Drop the last vote of the candidate of para 1, which was backed with exactly
+				// `effective_minimum_backing_votes` votes.
+				let para_1 = backed_candidates[0].clone();
+				assert_eq!(para_1.descriptor().para_id, ParaId::from(1));
+				assert_eq!(
+					para_1.validity_votes().len(),
+					effective_minimum_backing_votes(
+						2,
+						configuration::Pallet::<Test>::minimum_backing_votes()
+					)
+				);
+				let (validator_indices, core_index) = para_1.validator_indices_and_core_index(true);
+				let mut validator_indices = validator_indices.to_bitvec();
+				let last_voter = validator_indices.last_one().unwrap();
+				validator_indices.set(last_voter, false);
+				let mut validity_votes = para_1.validity_votes().to_vec();
+				validity_votes.pop();
+				let under_threshold = BackedCandidate::new(
+					para_1.candidate().clone(),
+					validity_votes,
+					validator_indices,
+					core_index,
+				);
+				backed_candidates[0] = under_threshold.clone();
+
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core,
+					votes_from_disabled_were_dropped,
+					dropped_candidates,
+					..
+				} = sanitize_backed_candidates::<Test, _>(
+					backed_candidates,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled,
+					true,
+				);
+
+				assert_eq!(
+					backed_candidates_with_core,
+					all_backed_candidates_with_core
+						.into_iter()
+						.filter(|(candidate, _)| *candidate != para_1)
+						.collect::<Vec<_>>()
+				);
+				assert!(!votes_from_disabled_were_dropped);
+				assert_eq!(
+					dropped_candidates,
+					vec![(under_threshold.hash(), DropReason::BelowBackingThreshold)]
+				);
+			});
+		}
+
+		// candidates of a para beyond `max_cores_per_para` are dropped, keeping the lowest cores
+		#[test]
+		fn max_cores_per_para_is_enforced() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData {
+					backed_candidates,
+					all_backed_candidates_with_core,
+					scheduled_paras: scheduled,
+				} = get_test_data_multiple_cores_per_para(true);
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				// Para 1 has candidates on cores 0 and 1, only the lower one is kept.
+				let mut hc = configuration::Pallet::<Test>::config();
+				hc.max_cores_per_para = Some(1);
+				configuration::Pallet::<Test>::force_set_active_config(hc);
+
+				let (expected, dropped): (Vec<_>, Vec<_>) = all_backed_candidates_with_core
+					.clone()
+					.into_iter()
+					.partition(|(_, core_idx)| *core_idx != CoreIndex(1));
+
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core,
+					dropped_candidates,
+					..
+				} = sanitize_backed_candidates::<Test, _>(
+					backed_candidates,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled,
+					true,
+				);
+
+				assert_eq!(backed_candidates_with_core, expected);
+				assert_eq!(
+					dropped_candidates,
+					dropped
+						.iter()
+						.map(|(candidate, _)| (candidate.hash(), DropReason::CoreCapExceeded))
+						.collect::<Vec<_>>()
+				);
+
+				// A para on three cores with a cap of two keeps the two lowest cores.
+				let (first, _) = all_backed_candidates_with_core[0].clone();
+				let (second, _) = all_backed_candidates_with_core[1].clone();
+				assert_eq!(first.descriptor().para_id, second.descriptor().para_id);
+				let mut candidate = second.candidate().clone();
+				candidate.descriptor.pov_hash = Hash::repeat_byte(0xEE);
+				let (validator_indices, core_index) = second.validator_indices_and_core_index(true);
+				let third = BackedCandidate::new(
+					candidate,
+					second.validity_votes().to_vec(),
+					validator_indices.to_bitvec(),
+					core_index,
+				);
+
+				let mut candidates = vec![
+					(first.clone(), CoreIndex(0)),
+					(second.clone(), CoreIndex(1)),
+					(third.clone(), CoreIndex(2)),
+				];
+				let mut dropped_candidates = Vec::new();
+				enforce_max_cores_per_para(&mut candidates, 2, &mut dropped_candidates);
+				assert_eq!(candidates, vec![(first, CoreIndex(0)), (second, CoreIndex(1))]);
+				assert_eq!(dropped_candidates, vec![(third.hash(), DropReason::CoreCapExceeded)]);
+			});
+		}
+
 		// nothing is scheduled, so no paraids match, thus all backed candidates are skipped
 		#[rstest]
 		#[case(false, false)]
@@ -1843,6 +2946,7 @@ mod sanitizers {
 					backed_candidates_with_core: sanitized_backed_candidates,
 					votes_from_disabled_were_dropped,
 					dropped_unscheduled_candidates,
+					..
 				} = sanitize_backed_candidates::<Test, _>(
 					backed_candidates.clone(),
 					&<shared::Pallet<Test>>::allowed_relay_parents(),
@@ -1857,6 +2961,141 @@ mod sanitizers {
 			});
 		}
 
+		// with nothing scheduled, dropping all candidates right away gives the same result as the
+		// full sanitization
+		#[rstest]
+		#[case(false, false)]
+		#[case(true, true)]
+		#[case(false, true)]
+		#[case(true, false)]
+		fn nothing_scheduled_shortcut_matches_sanitization(
+			#[case] core_index_enabled: bool,
+			#[case] multiple_cores_per_para: bool,
+		) {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { backed_candidates, .. } = if multiple_cores_per_para {
+					get_test_data_multiple_cores_per_para(core_index_enabled)
+				} else {
+					get_test_data(core_index_enabled)
+				};
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				let sanitized = sanitize_backed_candidates::<Test, _>(
+					backed_candidates.clone(),
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					BTreeMap::new(),
+					core_index_enabled,
+				);
+				let shortcut = drop_unscheduled_backed_candidates::<Test>(backed_candidates);
+
+				assert!(shortcut.backed_candidates_with_core.is_empty());
+				assert!(shortcut.dropped_unscheduled_candidates);
+				assert_eq!(shortcut, sanitized);
+			});
+		}
+
+		// the scheduling checks can be done ahead of backing a candidate
+		#[test]
+		fn would_accept_candidate_checks_scheduling() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let _ = get_test_data(false);
+				let relay_parent = default_header().hash();
+
+				assert_eq!(
+					Pallet::<Test>::would_accept_candidate(
+						ParaId::from(1),
+						CoreIndex(0),
+						relay_parent,
+					),
+					Ok(())
+				);
+				assert_eq!(
+					Pallet::<Test>::would_accept_candidate(
+						ParaId::from(3),
+						CoreIndex(0),
+						relay_parent,
+					),
+					Err(DropReason::Unscheduled)
+				);
+				assert_eq!(
+					Pallet::<Test>::would_accept_candidate(
+						ParaId::from(1),
+						CoreIndex(1),
+						relay_parent,
+					),
+					Err(DropReason::CoreNotScheduled)
+				);
+				assert_eq!(
+					Pallet::<Test>::would_accept_candidate(
+						ParaId::from(1),
+						CoreIndex(0),
+						Hash::repeat_byte(0xAA),
+					),
+					Err(DropReason::RelayParentNotAllowed)
+				);
+			});
+		}
+
+		// with no active validators, neither bitfields nor backed candidates are kept
+		#[test]
+		fn empty_validator_set_drops_everything() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { backed_candidates, scheduled_paras, .. } = get_test_data(false);
+				shared::Pallet::<Test>::set_active_validators_ascending(vec![]);
+				assert!(shared::Pallet::<Test>::active_validator_indices().is_empty());
+
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core, trimmed_paras, ..
+				} = sanitize_backed_candidates::<Test, _>(
+					backed_candidates.clone(),
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled_paras,
+					false,
+				);
+				assert!(backed_candidates_with_core.is_empty());
+				assert!(!trimmed_paras.is_empty());
+				assert!(trimmed_paras.values().all(|counts| counts.included == 0));
+
+				let keystore = Arc::new(LocalKeystore::in_memory()) as KeystorePtr;
+				let validator = Sr25519Keyring::Alice;
+				Keystore::sr25519_generate_new(
+					&*keystore,
+					PARACHAIN_KEY_TYPE_ID,
+					Some(&validator.to_seed()),
+				)
+				.unwrap();
+				let parent_hash = default_header().hash();
+				let session_index = SessionIndex::from(0_u32);
+				let bitfield = SignedAvailabilityBitfield::sign(
+					&keystore,
+					AvailabilityBitfield::from(BitVec::<u8, Lsb0>::repeat(true, 2)),
+					&SigningContext { parent_hash, session_index },
+					ValidatorIndex(0),
+					&validator.public().into(),
+				)
+				.unwrap()
+				.unwrap();
+
+				assert!(sanitize_bitfields::<Test>(
+					vec![bitfield.into_unchecked()],
+					DisputedBitfield::zeros(2),
+					2,
+					None,
+					parent_hash,
+					session_index,
+					&[],
+					None,
+					&mut Vec::new(),
+				)
+				.is_empty());
+			});
+		}
+
 		// candidates that have concluded as invalid are filtered out
 		#[rstest]
 		#[case(false)]
@@ -1882,6 +3121,7 @@ mod sanitizers {
 					backed_candidates_with_core: sanitized_backed_candidates,
 					votes_from_disabled_were_dropped,
 					dropped_unscheduled_candidates,
+					..
 				} = sanitize_backed_candidates::<Test, _>(
 					backed_candidates.clone(),
 					&<shared::Pallet<Test>>::allowed_relay_parents(),
@@ -1896,6 +3136,358 @@ mod sanitizers {
 			});
 		}
 
+		// candidates built on the relay parent of the previous session are not dropped for their
+		// backing, which is only checked once, when processing the candidates
+		#[rstest]
+		#[case(false)]
+		#[case(true)]
+		fn candidates_backed_in_previous_session(#[case] core_index_enabled: bool) {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { backed_candidates, scheduled_paras: scheduled, .. } =
+					get_test_data(core_index_enabled);
+
+				// Cross the session boundary right after the relay parent, the validators of
+				// session 1 being the ones of session 0 in another order. The candidates are
+				// backed under the keys of session 0, which don't verify for session 1.
+				let mut validators = shared::Pallet::<Test>::active_validator_keys();
+				validators.rotate_left(1);
+				shared::Pallet::<Test>::set_active_validators_ascending(validators);
+				shared::Pallet::<Test>::set_session_index(1);
+				scheduler::SessionStartBlock::<Test>::set(4);
+
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core: sanitized_backed_candidates,
+					dropped_candidates,
+					..
+				} = sanitize_backed_candidates::<Test, _>(
+					backed_candidates.clone(),
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled,
+					core_index_enabled,
+				);
+				assert_eq!(sanitized_backed_candidates.len(), backed_candidates.len());
+				assert!(dropped_candidates.is_empty());
+			});
+		}
+
+		// candidates of the same para claiming the same core are resolved deterministically in
+		// favour of the one with the most validity votes, and then the lowest candidate hash
+		#[test]
+		fn candidates_claiming_the_same_core() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { backed_candidates, scheduled_paras: scheduled, .. } =
+					get_test_data(true);
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				// A competitor of para 1's candidate, claiming core 0 as well.
+				let competitor = |original: &BackedCandidate, pov_byte: u8, votes: usize| {
+					let mut candidate = original.candidate().clone();
+					candidate.descriptor.pov_hash = Hash::repeat_byte(pov_byte);
+					let (validator_indices, core_index) =
+						original.validator_indices_and_core_index(true);
+					let mut validator_indices = validator_indices.to_bitvec();
+					for idx in votes..validator_indices.len() {
+						validator_indices.set(idx, false);
+					}
+					BackedCandidate::new(
+						candidate,
+						original.validity_votes()[..votes].to_vec(),
+						validator_indices,
+						core_index,
+					)
+				};
+
+				let better_backed = backed_candidates[0].clone();
+				assert_eq!(better_backed.validity_votes().len(), 2);
+				let worse_backed = competitor(&better_backed, 0xBB, 1);
+
+				for candidates in [
+					vec![worse_backed.clone(), better_backed.clone()],
+					vec![better_backed.clone(), worse_backed.clone()],
+				] {
+					let SanitizedBackedCandidates {
+						backed_candidates_with_core,
+						dropped_candidates,
+						..
+					} = sanitize_backed_candidates::<Test, _>(
+						candidates,
+						&<shared::Pallet<Test>>::allowed_relay_parents(),
+						has_concluded_invalid,
+						scheduled.clone(),
+						true,
+					);
+
+					assert_eq!(
+						backed_candidates_with_core,
+						vec![(better_backed.clone(), CoreIndex(0))]
+					);
+					assert_eq!(
+						dropped_candidates,
+						vec![(worse_backed.hash(), DropReason::CoreConflict)]
+					);
+				}
+
+				// With an equal number of votes, the lower candidate hash wins.
+				let (first, second) = (competitor(&better_backed, 0xCC, 2), better_backed);
+				let (winner, loser) =
+					if first.hash() < second.hash() { (first, second) } else { (second, first) };
+
+				for candidates in
+					[vec![winner.clone(), loser.clone()], vec![loser.clone(), winner.clone()]]
+				{
+					let SanitizedBackedCandidates {
+						backed_candidates_with_core,
+						dropped_candidates,
+						..
+					} = sanitize_backed_candidates::<Test, _>(
+						candidates,
+						&<shared::Pallet<Test>>::allowed_relay_parents(),
+						has_concluded_invalid,
+						scheduled.clone(),
+						true,
+					);
+
+					assert_eq!(backed_candidates_with_core, vec![(winner.clone(), CoreIndex(0))]);
+					assert_eq!(dropped_candidates, vec![(loser.hash(), DropReason::CoreConflict)]);
+				}
+			});
+		}
+
+		// candidates are bound to the core they declare, regardless of the submission order
+		#[test]
+		fn declared_core_index_determines_binding() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { backed_candidates, scheduled_paras: scheduled, .. } =
+					get_test_data_multiple_cores_per_para(true);
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				// Para 1 on cores 0 and 1, para 4 on core 5 without and with a core index.
+				let para_1_core_0 = backed_candidates[0].clone();
+				let para_1_core_1 = backed_candidates[1].clone();
+				let para_4_without_core = backed_candidates[4].clone();
+				let para_4_core_5 = backed_candidates[5].clone();
+
+				// A candidate of para 1 declaring the core of para 3.
+				let (validator_indices, _) = para_1_core_0.validator_indices_and_core_index(true);
+				let mut candidate = para_1_core_0.candidate().clone();
+				candidate.descriptor.pov_hash = Hash::repeat_byte(0xCC);
+				let misplaced = BackedCandidate::new(
+					candidate,
+					para_1_core_0.validity_votes().to_vec(),
+					validator_indices.to_bitvec(),
+					Some(CoreIndex(4)),
+				);
+
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core,
+					dropped_candidates,
+					..
+				} = sanitize_backed_candidates::<Test, _>(
+					vec![
+						para_1_core_1.clone(),
+						para_4_without_core,
+						misplaced.clone(),
+						para_1_core_0.clone(),
+						para_4_core_5.clone(),
+					],
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled,
+					true,
+				);
+
+				assert_eq!(
+					backed_candidates_with_core,
+					vec![
+						(para_1_core_0, CoreIndex(0)),
+						(para_1_core_1, CoreIndex(1)),
+						(para_4_core_5, CoreIndex(5)),
+					]
+				);
+				assert_eq!(
+					dropped_candidates,
+					vec![(misplaced.hash(), DropReason::CoreNotScheduled)]
+				);
+			});
+		}
+
+		// candidates with a validation data hash not matching their para's head are dropped
+		#[rstest]
+		#[case(false)]
+		#[case(true)]
+		fn invalid_validation_data_hash_is_dropped(#[case] core_index_enabled: bool) {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData {
+					backed_candidates,
+					all_backed_candidates_with_core,
+					scheduled_paras: scheduled,
+				} = get_test_data(core_index_enabled);
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				// The test candidates carry a made up validation data hash, which can only be
+				// checked once the head of their para is known.
+				paras::Pallet::<Test>::set_current_head(
+					ParaId::from(1),
+					primitives::HeadData(vec![1, 2, 3]),
+				);
+
+				let (expected, invalid): (Vec<_>, Vec<_>) = all_backed_candidates_with_core
+					.into_iter()
+					.partition(|(candidate, _)| candidate.descriptor().para_id != ParaId::from(1));
+				assert_eq!(invalid.len(), 1);
+
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core,
+					dropped_candidates,
+					..
+				} = sanitize_backed_candidates::<Test, _>(
+					backed_candidates,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled,
+					core_index_enabled,
+				);
+
+				assert_eq!(backed_candidates_with_core, expected);
+				assert_eq!(
+					dropped_candidates,
+					vec![(invalid[0].0.hash(), DropReason::InvalidValidationDataHash)]
+				);
+			});
+		}
+
+		// candidates with an hrmp watermark beyond their relay parent number are dropped
+		#[rstest]
+		#[case(false)]
+		#[case(true)]
+		fn future_hrmp_watermark_is_dropped(#[case] core_index_enabled: bool) {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData {
+					mut backed_candidates,
+					mut all_backed_candidates_with_core,
+					scheduled_paras: scheduled,
+				} = get_test_data(core_index_enabled);
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				// The test candidates have their watermark at exactly the relay parent number.
+				let (_, relay_parent_number) = shared::Pallet::<Test>::allowed_relay_parents()
+					.acquire_info(backed_candidates[0].descriptor().relay_parent, None)
+					.unwrap();
+				assert!(backed_candidates
+					.iter()
+					.all(|c| c.candidate().commitments.hrmp_watermark == relay_parent_number));
+
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core,
+					dropped_candidates,
+					..
+				} = sanitize_backed_candidates::<Test, _>(
+					backed_candidates.clone(),
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled.clone(),
+					core_index_enabled,
+				);
+				assert_eq!(backed_candidates_with_core, all_backed_candidates_with_core);
+				assert!(dropped_candidates.is_empty());
+
+				// Move the watermark of the first candidate one block past its relay parent.
+				let (validator_indices, core_index) =
+					backed_candidates[0].validator_indices_and_core_index(core_index_enabled);
+				let mut candidate = backed_candidates[0].candidate().clone();
+				candidate.commitments.hrmp_watermark = relay_parent_number + 1;
+				let future = BackedCandidate::new(
+					candidate,
+					backed_candidates[0].validity_votes().to_vec(),
+					validator_indices.to_bitvec(),
+					core_index,
+				);
+				backed_candidates[0] = future.clone();
+				all_backed_candidates_with_core.remove(0);
+
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core,
+					dropped_candidates,
+					..
+				} = sanitize_backed_candidates::<Test, _>(
+					backed_candidates,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled,
+					core_index_enabled,
+				);
+				assert_eq!(backed_candidates_with_core, all_backed_candidates_with_core);
+				assert_eq!(
+					dropped_candidates,
+					vec![(future.hash(), DropReason::InvalidHrmpWatermark)]
+				);
+			});
+		}
+
+		// the same candidate supplied twice is only processed once
+		#[rstest]
+		#[case(false)]
+		#[case(true)]
+		fn duplicate_candidates_are_filtered_out(#[case] core_index_enabled: bool) {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData {
+					mut backed_candidates,
+					all_backed_candidates_with_core,
+					scheduled_paras: scheduled,
+				} = get_test_data(core_index_enabled);
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				let duplicate = backed_candidates[0].clone();
+				backed_candidates.push(duplicate.clone());
+
+				let SanitizedBackedCandidates {
+					backed_candidates_with_core,
+					dropped_candidates,
+					..
+				} = sanitize_backed_candidates::<Test, _>(
+					backed_candidates,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					has_concluded_invalid,
+					scheduled,
+					core_index_enabled,
+				);
+
+				assert_eq!(backed_candidates_with_core, all_backed_candidates_with_core);
+				assert_eq!(
+					dropped_candidates,
+					vec![(duplicate.hash(), DropReason::DuplicateCandidate)]
+				);
+
+				// Distinct candidates of the same para are not duplicates of each other.
+				let original = duplicate;
+				let mut candidate = original.candidate().clone();
+				candidate.descriptor.pov_hash = Hash::repeat_byte(0xDD);
+				let (validator_indices, core_index) =
+					original.validator_indices_and_core_index(core_index_enabled);
+				let other = BackedCandidate::new(
+					candidate,
+					original.validity_votes().to_vec(),
+					validator_indices.to_bitvec(),
+					core_index,
+				);
+				assert_ne!(original.hash(), other.hash());
+
+				let mut candidates = vec![original.clone(), other.clone()];
+				let mut dropped_candidates = Vec::new();
+				drop_duplicate_candidates(&mut candidates, &mut dropped_candidates);
+				assert_eq!(candidates, vec![original, other]);
+				assert!(dropped_candidates.is_empty());
+			});
+		}
+
 		#[rstest]
 		#[case(false)]
 		#[case(true)]
@@ -1991,6 +3583,46 @@ mod sanitizers {
 			});
 		}
 
+		#[rstest]
+		#[case(false)]
+		#[case(true)]
+		fn dropped_votes_from_disabled_are_reported(#[case] core_index_enabled: bool) {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { mut all_backed_candidates_with_core, .. } =
+					get_test_data(core_index_enabled);
+				// Events are not recorded in the genesis block.
+				System::set_block_number(1);
+
+				// Eve didn't sign anything, so there is nothing to report.
+				set_disabled_validators(vec![4]);
+				assert!(!filter_backed_statements_from_disabled_validators::<Test>(
+					&mut all_backed_candidates_with_core,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					core_index_enabled
+				));
+				assert!(System::events().is_empty());
+
+				// Alice's vote is dropped from the first candidate only.
+				set_disabled_validators(vec![0, 4]);
+				let mut hc = configuration::Pallet::<Test>::config();
+				hc.minimum_backing_votes = 1;
+				configuration::Pallet::<Test>::force_set_active_config(hc);
+
+				assert!(filter_backed_statements_from_disabled_validators::<Test>(
+					&mut all_backed_candidates_with_core,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					core_index_enabled
+				));
+				assert_eq!(
+					System::events().into_iter().map(|record| record.event).collect::<Vec<_>>(),
+					vec![RuntimeEvent::ParaInherent(Event::DisabledVotesDropped {
+						candidate_hash: all_backed_candidates_with_core[0].0.hash(),
+						validators: vec![ValidatorIndex(0)],
+					})]
+				);
+			});
+		}
+
 		#[rstest]
 		#[case(false)]
 		#[case(true)]
@@ -2019,5 +3651,42 @@ mod sanitizers {
 				assert_eq!(all_backed_candidates_with_core.get(0).unwrap().0, untouched);
 			});
 		}
+
+		#[rstest]
+		#[case(false)]
+		#[case(true)]
+		fn disabled_validators_votes_are_counted_during_grace_period(
+			#[case] core_index_enabled: bool,
+		) {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { mut all_backed_candidates_with_core, .. } =
+					get_test_data(core_index_enabled);
+
+				InherentDisabledValidatorsGracePeriod::set(1);
+
+				// Disable Alice and Bob
+				set_disabled_validators(vec![0, 1]);
+
+				// Their votes are still counted in the block they got disabled in.
+				let before = all_backed_candidates_with_core.clone();
+				assert!(!filter_backed_statements_from_disabled_validators::<Test>(
+					&mut all_backed_candidates_with_core,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					core_index_enabled
+				));
+				assert_eq!(all_backed_candidates_with_core, before);
+
+				// Once the grace period is over, they are dropped along with the candidate.
+				System::set_block_number(System::block_number() + 1);
+				let untouched = all_backed_candidates_with_core.get(1).unwrap().0.clone();
+				assert!(filter_backed_statements_from_disabled_validators::<Test>(
+					&mut all_backed_candidates_with_core,
+					&<shared::Pallet<Test>>::allowed_relay_parents(),
+					core_index_enabled
+				));
+				assert_eq!(all_backed_candidates_with_core.len(), 1);
+				assert_eq!(all_backed_candidates_with_core.get(0).unwrap().0, untouched);
+			});
+		}
 	}
 }
@@ -19,11 +19,13 @@
 //! the relay chain, but we do care about the size of the block, by putting the tx in the
 //! proof_size we can use the already existing weight limiting code to limit the used size as well.
 
+use frame_support::traits::Get;
 use parity_scale_codec::{Encode, WrapperTypeEncode};
 use primitives::{
 	CheckedMultiDisputeStatementSet, MultiDisputeStatementSet, UncheckedSignedAvailabilityBitfield,
 	UncheckedSignedAvailabilityBitfields,
 };
+use sp_runtime::traits::Zero;
 
 use super::{BackedCandidate, Config, DisputeStatementSet, Weight};
 
@@ -38,6 +40,10 @@ pub trait WeightInfo {
 	fn enter_backed_candidates_variable(v: u32) -> Weight;
 	/// The weight of a single backed candidate with a code upgrade.
 	fn enter_backed_candidate_code_upgrade() -> Weight;
+	/// The weight of clearing the votes scraped on chain.
+	fn force_clear_scraped_votes() -> Weight;
+	/// The weight of forcing `c` cores disputed.
+	fn force_set_disputed_cores(c: u32) -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -60,6 +66,12 @@ impl WeightInfo for TestWeightInfo {
 	fn enter_backed_candidate_code_upgrade() -> Weight {
 		Weight::zero()
 	}
+	fn force_clear_scraped_votes() -> Weight {
+		Weight::zero()
+	}
+	fn force_set_disputed_cores(_c: u32) -> Weight {
+		Weight::zero()
+	}
 }
 // To simplify benchmarks running as tests, we set all the weights to 0. `enter` will exit early
 // when if the data causes it to be over weight, but we don't want that to block a benchmark from
@@ -78,6 +90,12 @@ impl WeightInfo for TestWeightInfo {
 	fn enter_backed_candidate_code_upgrade() -> Weight {
 		Weight::zero()
 	}
+	fn force_clear_scraped_votes() -> Weight {
+		Weight::zero()
+	}
+	fn force_set_disputed_cores(_c: u32) -> Weight {
+		Weight::zero()
+	}
 }
 
 pub fn paras_inherent_total_weight<T: Config>(
@@ -155,11 +173,23 @@ pub fn backed_candidate_weight<T: frame_system::Config + Config>(
 			<<T as Config>::WeightInfo as WeightInfo>::enter_backed_candidates_variable(
 				candidate.validity_votes().len() as u32,
 			)
-		},
+		}
+		.saturating_add(disabled_since_weight::<T>()),
 		candidate,
 	)
 }
 
+/// The weight of noting the newly disabled validators in `DisabledSince` while sanitizing the
+/// backed candidates. Only done with a non-zero `DisabledValidatorsGracePeriod`, which the
+/// benchmarks can't assume.
+fn disabled_since_weight<T: Config>() -> Weight {
+	if T::DisabledValidatorsGracePeriod::get().is_zero() {
+		Weight::zero()
+	} else {
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
+
 pub fn backed_candidates_weight<T: frame_system::Config + Config>(
 	candidates: &[BackedCandidate<T::Hash>],
 ) -> Weight {
@@ -401,7 +401,7 @@ pub fn submit_unsigned_slashing_report<T: disputes::slashing::Config>(
 
 /// Return the min backing votes threshold from the configuration.
 pub fn minimum_backing_votes<T: initializer::Config>() -> u32 {
-	<configuration::Pallet<T>>::config().minimum_backing_votes
+	<configuration::Pallet<T>>::minimum_backing_votes()
 }
 
 /// Implementation for `ParaBackingState` function from the runtime API
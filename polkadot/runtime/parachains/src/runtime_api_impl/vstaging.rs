@@ -16,10 +16,12 @@
 
 //! Put implementations of functions from staging APIs here.
 
-use crate::{configuration, initializer, shared};
+use crate::{configuration, initializer, paras_inherent, shared};
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::HeaderFor;
 use primitives::{
-	vstaging::{ApprovalVotingParams, NodeFeatures},
-	ValidatorIndex,
+	vstaging::{ApprovalVotingParams, DisablingReason, NodeFeatures},
+	InherentData, ValidatorIndex,
 };
 use sp_std::prelude::Vec;
 
@@ -33,6 +35,16 @@ where
 	<shared::Pallet<T>>::disabled_validators()
 }
 
+/// Implementation for `DisabledValidatorsWithReasons`
+// CAVEAT: this should only be called on the node side
+// as it might produce incorrect results on session boundaries
+pub fn disabled_validators_with_reasons<T>() -> Vec<(ValidatorIndex, Option<DisablingReason>)>
+where
+	T: shared::Config,
+{
+	<shared::Pallet<T>>::disabled_validators_with_reasons()
+}
+
 /// Returns the current state of the node features.
 pub fn node_features<T: initializer::Config>() -> NodeFeatures {
 	<configuration::Pallet<T>>::config().node_features
@@ -43,3 +55,15 @@ pub fn approval_voting_params<T: initializer::Config>() -> ApprovalVotingParams
 	let config = <configuration::Pallet<T>>::config();
 	config.approval_voting_params
 }
+
+/// Implementation for `InherentWeightLimits`
+pub fn inherent_weight_limits<T: paras_inherent::Config>() -> (Weight, u32) {
+	<paras_inherent::Pallet<T>>::inherent_weight_limits()
+}
+
+/// Implementation for `InherentWeightBreakdown`
+pub fn inherent_weight_breakdown<T: paras_inherent::Config>(
+	inherent: &InherentData<HeaderFor<T>>,
+) -> (Weight, Weight, Weight) {
+	<paras_inherent::Pallet<T>>::inherent_weight_breakdown(inherent)
+}
@@ -45,7 +45,7 @@ use primitives::{
 };
 use sp_runtime::traits::One;
 use sp_std::{
-	collections::{btree_map::BTreeMap, vec_deque::VecDeque},
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet, vec_deque::VecDeque},
 	prelude::*,
 };
 
@@ -59,6 +59,10 @@ pub use pallet::*;
 mod tests;
 
 const LOG_TARGET: &str = "runtime::parachains::scheduler";
+
+/// The number of blocks over which the cores freed are averaged, see
+/// [`Pallet::estimated_queue_drain_blocks`].
+pub const CORES_FREED_HISTORY_LENGTH: u32 = 10;
 pub mod migration;
 
 #[frame_support::pallet]
@@ -75,6 +79,17 @@ pub mod pallet {
 	#[pallet::config]
 	pub trait Config: frame_system::Config + configuration::Config + paras::Config {
 		type AssignmentProvider: AssignmentProvider<BlockNumberFor<Self>>;
+
+		/// The overarching event type.
+		type RuntimeEvent: From<Event> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event {
+		/// The number of availability cores grew at a session boundary and the assignment
+		/// provider was asked to seed the new cores.
+		CoresAdded { from: u32, to: u32 },
 	}
 
 	/// All the validator groups. One for each core. Indices are into `ActiveValidators` - not the
@@ -147,11 +162,20 @@ pub mod pallet {
 	/// One entry for each availability core. The `VecDeque` represents the assignments to be
 	/// scheduled on that core. The value contained here will not be valid after the end of
 	/// a block. Runtime APIs should be used to determine scheduled cores/ for the upcoming block.
+	///
+	/// Being a `BTreeMap`, it is iterated in ascending `CoreIndex` order, which
+	/// [`Pallet::scheduled_paras`] relies upon.
 	#[pallet::storage]
 	#[pallet::getter(fn claimqueue)]
 	pub(crate) type ClaimQueue<T: Config> =
 		StorageValue<_, BTreeMap<CoreIndex, VecDeque<ParasEntryType<T>>>, ValueQuery>;
 
+	/// The number of cores freed because their candidate concluded, for the blocks of the last
+	/// [`CORES_FREED_HISTORY_LENGTH`] which freed any, oldest first.
+	#[pallet::storage]
+	pub(crate) type RecentCoresFreed<T: Config> =
+		StorageValue<_, Vec<(BlockNumberFor<T>, u32)>, ValueQuery>;
+
 	/// Assignments as tracked in the claim queue.
 	#[derive(Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Clone)]
 	pub struct ParasEntry<N> {
@@ -228,10 +252,21 @@ impl<T: Config> Pallet<T> {
 			},
 		);
 
-		AvailabilityCores::<T>::mutate(|cores| {
+		let prev_n_cores = AvailabilityCores::<T>::mutate(|cores| {
+			let prev_n_cores = cores.len() as u32;
 			cores.resize_with(n_cores as _, || CoreOccupied::Free);
+			prev_n_cores
 		});
 
+		// Assignments of the dropped cores were already pushed back to the provider in
+		// `pre_new_session`, only the provider's own state of those cores is left to prune.
+		if n_cores > prev_n_cores {
+			T::AssignmentProvider::seed_new_cores(prev_n_cores, n_cores);
+			Self::deposit_event(Event::CoresAdded { from: prev_n_cores, to: n_cores });
+		} else if n_cores < prev_n_cores {
+			T::AssignmentProvider::prune_removed_cores(prev_n_cores, n_cores);
+		}
+
 		// shuffle validators into groups.
 		if n_cores == 0 || validators.is_empty() {
 			ValidatorGroups::<T>::set(Vec::new());
@@ -350,7 +385,7 @@ impl<T: Config> Pallet<T> {
 	fn drop_expired_claims_from_claimqueue() {
 		let now = <frame_system::Pallet<T>>::block_number();
 		let availability_cores = AvailabilityCores::<T>::get();
-		let ttl = <configuration::Pallet<T>>::config().scheduler_params.ttl;
+		let ttl = Self::claim_ttl();
 
 		ClaimQueue::<T>::mutate(|cq| {
 			for (idx, _) in (0u32..).zip(availability_cores) {
@@ -567,12 +602,20 @@ impl<T: Config> Pallet<T> {
 		<configuration::Pallet<T>>::config().scheduler_params.lookahead
 	}
 
+	/// The number of blocks a claim stays in the claim queue, as of the active configuration.
+	///
+	/// Claims added to the claim queue in block `now` expire after block `now + claim_ttl()`.
+	pub fn claim_ttl() -> BlockNumberFor<T> {
+		<configuration::Pallet<T>>::config().scheduler_params.ttl
+	}
+
 	/// Frees cores and fills the free claimqueue spots by popping from the `AssignmentProvider`.
 	pub fn free_cores_and_fill_claimqueue(
 		just_freed_cores: impl IntoIterator<Item = (CoreIndex, FreedReason)>,
 		now: BlockNumberFor<T>,
 	) {
 		let (mut concluded_paras, mut timedout_paras) = Self::free_cores(just_freed_cores);
+		Self::note_cores_freed(now, concluded_paras.len() as u32);
 
 		// This can only happen on new sessions at which we move all assignments back to the
 		// provider. Hence, there's nothing we need to do here.
@@ -586,6 +629,23 @@ impl<T: Config> Pallet<T> {
 		let config = <configuration::Pallet<T>>::config();
 		let max_availability_timeouts = config.scheduler_params.max_availability_timeouts;
 		let ttl = config.scheduler_params.ttl;
+		let max_cores_per_para = config.max_cores_per_para;
+
+		// The cores each para already occupies or has claims on.
+		let mut para_cores: BTreeMap<ParaId, BTreeSet<CoreIndex>> = BTreeMap::new();
+		for (core_idx, entries) in cq.iter() {
+			for entry in entries {
+				para_cores.entry(entry.assignment.para_id()).or_default().insert(*core_idx);
+			}
+		}
+		for (core_idx, core) in AvailabilityCores::<T>::get().into_iter().enumerate() {
+			if let CoreOccupied::Paras(entry) = core {
+				para_cores
+					.entry(entry.assignment.para_id())
+					.or_default()
+					.insert(CoreIndex(core_idx as u32));
+			}
+		}
 
 		for core_idx in 0..n_session_cores {
 			let core_idx = CoreIndex::from(core_idx);
@@ -617,6 +677,17 @@ impl<T: Config> Pallet<T> {
 				if Self::is_core_occupied(core_idx) { 1 } else { 0 };
 			for _ in n_lookahead_used..n_lookahead {
 				if let Some(assignment) = T::AssignmentProvider::pop_assignment_for_core(core_idx) {
+					let cores = para_cores.entry(assignment.para_id()).or_default();
+					// Cores are filled in ascending order, so a para at its cap keeps the lowest
+					// ones.
+					let over_cap = max_cores_per_para.map_or(false, |max| {
+						!cores.contains(&core_idx) && cores.len() >= max as usize
+					});
+					if over_cap {
+						T::AssignmentProvider::push_back_assignment(assignment);
+						break
+					}
+					cores.insert(core_idx);
 					Self::add_to_claimqueue(core_idx, ParasEntry::new(assignment, now + ttl));
 				}
 			}
@@ -626,6 +697,23 @@ impl<T: Config> Pallet<T> {
 		debug_assert!(concluded_paras.is_empty());
 	}
 
+	/// Record `freed` cores as freed in block `now` in [`RecentCoresFreed`], pruning the blocks
+	/// which fell out of the history.
+	fn note_cores_freed(now: BlockNumberFor<T>, freed: u32) {
+		if freed == 0 {
+			return
+		}
+
+		let oldest_kept = now.saturating_sub(CORES_FREED_HISTORY_LENGTH.into());
+		RecentCoresFreed::<T>::mutate(|recent| {
+			match recent.last_mut() {
+				Some((block, count)) if *block == now => *count = count.saturating_add(freed),
+				_ => recent.push((now, freed)),
+			}
+			recent.retain(|(block, _)| *block > oldest_kept);
+		});
+	}
+
 	fn is_core_occupied(core_idx: CoreIndex) -> bool {
 		match AvailabilityCores::<T>::get().get(core_idx.0 as usize) {
 			None | Some(CoreOccupied::Free) => false,
@@ -659,13 +747,81 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Paras scheduled next in the claim queue.
+	///
+	/// Cores are yielded in ascending `CoreIndex` order, regardless of the order in which claims
+	/// were added to the claim queue. Candidates are bound to cores in this order when sanitizing
+	/// the inherent, so it must be the same on every node.
 	pub(crate) fn scheduled_paras() -> impl Iterator<Item = (CoreIndex, ParaId)> {
-		let claimqueue = ClaimQueue::<T>::get();
+		let claimqueue: BTreeMap<CoreIndex, _> = ClaimQueue::<T>::get();
 		claimqueue
 			.into_iter()
 			.filter_map(|(core_idx, v)| v.front().map(|e| (core_idx, e.assignment.para_id())))
 	}
 
+	/// The number of cores each para is scheduled on next, as given by [`Self::scheduled_paras`].
+	///
+	/// Paras without any scheduled core are omitted.
+	pub fn scheduled_cores_per_para() -> BTreeMap<ParaId, u32> {
+		let mut cores_per_para = BTreeMap::new();
+		for (_, para_id) in Self::scheduled_paras() {
+			*cores_per_para.entry(para_id).or_default() += 1;
+		}
+		cores_per_para
+	}
+
+	/// Up to `depth` paras queued on each core of the claim queue, in the order they are going to
+	/// be served. Expired claims are skipped and cores without any unexpired claim are omitted.
+	pub fn upcoming_schedule(depth: u32) -> BTreeMap<CoreIndex, Vec<ParaId>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		ClaimQueue::<T>::get()
+			.into_iter()
+			.filter_map(|(core_idx, queue)| {
+				let paras: Vec<_> = queue
+					.into_iter()
+					.filter(|entry| entry.ttl >= now)
+					.take(depth as usize)
+					.map(|entry| entry.assignment.para_id())
+					.collect();
+				(!paras.is_empty()).then_some((core_idx, paras))
+			})
+			.collect()
+	}
+
+	/// Whether the claim queue holds no unexpired claim for `core`.
+	///
+	/// A core whose queue only contains expired claims is considered empty, as those claims are
+	/// going to be dropped before they can be served.
+	pub fn claim_queue_is_empty_for(core: CoreIndex) -> bool {
+		let now = <frame_system::Pallet<T>>::block_number();
+		ClaimQueue::<T>::get()
+			.get(&core)
+			.map_or(true, |queue| queue.iter().all(|entry| entry.ttl < now))
+	}
+
+	/// An estimate of the number of blocks until all the claims currently in the claim queue are
+	/// served, at the average rate cores were freed over the last [`CORES_FREED_HISTORY_LENGTH`]
+	/// blocks.
+	///
+	/// This is a heuristic extrapolation of past activity, for capacity planning only: it neither
+	/// accounts for claims expiring or being added, nor for changes in the number of cores.
+	/// Returns `None` if no core was freed over that period.
+	pub fn estimated_queue_drain_blocks() -> Option<u32> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let oldest_kept = now.saturating_sub(CORES_FREED_HISTORY_LENGTH.into());
+		let freed = RecentCoresFreed::<T>::get()
+			.into_iter()
+			.filter(|(block, _)| *block > oldest_kept)
+			.fold(0u32, |freed, (_, count)| freed.saturating_add(count));
+		if freed == 0 {
+			return None
+		}
+
+		let queued: u32 = ClaimQueue::<T>::get().values().map(|queue| queue.len() as u32).sum();
+		// `queued / (freed / CORES_FREED_HISTORY_LENGTH)`, rounded up.
+		let scaled = queued.saturating_mul(CORES_FREED_HISTORY_LENGTH);
+		Some(scaled / freed + u32::from(scaled % freed != 0))
+	}
+
 	#[cfg(any(feature = "try-runtime", test))]
 	fn claimqueue_len() -> usize {
 		ClaimQueue::<T>::get().iter().map(|la_vec| la_vec.1.len()).sum()
@@ -25,7 +25,7 @@ use sp_runtime::{
 use primitives::{CoreIndex, Id as ParaId};
 
 /// Assignment (ParaId -> CoreIndex).
-#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq)]
+#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
 pub enum Assignment {
 	/// A pool assignment.
 	Pool {
@@ -84,4 +84,17 @@ pub trait AssignmentProvider<BlockNumber> {
 	/// - Core count has to be predetermined for the next session in the current session.
 	/// - Core count must not change during a session.
 	fn session_core_count() -> u32;
+
+	/// The number of cores grew from `from` to `to` at a session boundary.
+	///
+	/// Gives providers that keep per-core state a chance to populate the new cores
+	/// `from..to` before the scheduler starts popping assignments for them. Does nothing by
+	/// default.
+	fn seed_new_cores(_from: u32, _to: u32) {}
+
+	/// The number of cores shrank from `from` to `to` at a session boundary.
+	///
+	/// Gives providers that keep per-core state a chance to drop it for the removed cores
+	/// `to..from`, as the scheduler no longer pops assignments for them. Does nothing by default.
+	fn prune_removed_cores(_from: u32, _to: u32) {}
 }
@@ -252,6 +252,259 @@ fn claimqueue_ttl_drop_fn_works() {
 	});
 }
 
+#[test]
+fn upcoming_schedule_works() {
+	let mut config = default_config();
+	config.scheduler_params.lookahead = 3;
+	let genesis_config = genesis_config(&config);
+
+	let para_a = ParaId::from(100);
+	let para_b = ParaId::from(101);
+	let para_c = ParaId::from(102);
+	let now = 10;
+
+	new_test_ext(genesis_config).execute_with(|| {
+		run_to_block(now, |n| if n == now { Some(Default::default()) } else { None });
+
+		// Core 0: [a, expired b, c], core 1: [b], core 2: [expired c].
+		Scheduler::add_to_claimqueue(
+			CoreIndex(0),
+			ParasEntry::new(Assignment::Bulk(para_a), now + 5),
+		);
+		Scheduler::add_to_claimqueue(
+			CoreIndex(0),
+			ParasEntry::new(Assignment::Bulk(para_b), now - 1),
+		);
+		Scheduler::add_to_claimqueue(CoreIndex(0), ParasEntry::new(Assignment::Bulk(para_c), now));
+		Scheduler::add_to_claimqueue(
+			CoreIndex(1),
+			ParasEntry::new(Assignment::Bulk(para_b), now + 5),
+		);
+		Scheduler::add_to_claimqueue(
+			CoreIndex(2),
+			ParasEntry::new(Assignment::Bulk(para_c), now - 1),
+		);
+
+		assert_eq!(
+			Scheduler::upcoming_schedule(1),
+			BTreeMap::from([(CoreIndex(0), vec![para_a]), (CoreIndex(1), vec![para_b])])
+		);
+
+		// Cores with fewer claims than `depth` return what they have.
+		assert_eq!(
+			Scheduler::upcoming_schedule(3),
+			BTreeMap::from([(CoreIndex(0), vec![para_a, para_c]), (CoreIndex(1), vec![para_b])])
+		);
+
+		assert!(Scheduler::upcoming_schedule(0).is_empty());
+	});
+}
+
+#[test]
+fn scheduled_cores_per_para_works() {
+	let config = default_config();
+	let genesis_config = genesis_config(&config);
+
+	let para_a = ParaId::from(100);
+	let para_b = ParaId::from(101);
+	let para_c = ParaId::from(102);
+	let now = 10;
+
+	new_test_ext(genesis_config).execute_with(|| {
+		run_to_block(now, |n| if n == now { Some(Default::default()) } else { None });
+
+		assert!(Scheduler::scheduled_cores_per_para().is_empty());
+
+		// Core 0: [a], core 1: [a], core 2: [b, c]. `para_c` is only queued behind `para_b`.
+		Scheduler::add_to_claimqueue(CoreIndex(0), ParasEntry::new(Assignment::Bulk(para_a), now));
+		Scheduler::add_to_claimqueue(CoreIndex(1), ParasEntry::new(Assignment::Bulk(para_a), now));
+		Scheduler::add_to_claimqueue(CoreIndex(2), ParasEntry::new(Assignment::Bulk(para_b), now));
+		Scheduler::add_to_claimqueue(CoreIndex(2), ParasEntry::new(Assignment::Bulk(para_c), now));
+
+		assert_eq!(
+			Scheduler::scheduled_cores_per_para(),
+			BTreeMap::from([(para_a, 2), (para_b, 1)])
+		);
+	});
+}
+
+#[test]
+fn scheduled_paras_are_in_core_order() {
+	let config = default_config();
+	let genesis_config = genesis_config(&config);
+
+	let para_a = ParaId::from(100);
+	let para_b = ParaId::from(101);
+	let para_c = ParaId::from(102);
+	let now = 10;
+
+	new_test_ext(genesis_config).execute_with(|| {
+		run_to_block(now, |n| if n == now { Some(Default::default()) } else { None });
+
+		let claims = [(CoreIndex(0), para_b), (CoreIndex(1), para_c), (CoreIndex(2), para_a)];
+		let expected = claims.to_vec();
+
+		for order in [[0, 1, 2], [2, 1, 0], [1, 2, 0], [2, 0, 1]] {
+			ClaimQueue::<Test>::kill();
+			for i in order {
+				let (core_idx, para_id) = claims[i];
+				Scheduler::add_to_claimqueue(
+					core_idx,
+					ParasEntry::new(Assignment::Bulk(para_id), now),
+				);
+			}
+
+			assert_eq!(Scheduler::scheduled_paras().collect::<Vec<_>>(), expected);
+		}
+	});
+}
+
+#[test]
+fn claim_queue_is_empty_for_works() {
+	let config = default_config();
+	let genesis_config = genesis_config(&config);
+
+	let para_a = ParaId::from(100);
+	let para_b = ParaId::from(101);
+	let now = 10;
+
+	new_test_ext(genesis_config).execute_with(|| {
+		run_to_block(now, |n| if n == now { Some(Default::default()) } else { None });
+
+		// Core 0: [a], core 1: [expired a, expired b], core 2: nothing.
+		Scheduler::add_to_claimqueue(CoreIndex(0), ParasEntry::new(Assignment::Bulk(para_a), now));
+		Scheduler::add_to_claimqueue(
+			CoreIndex(1),
+			ParasEntry::new(Assignment::Bulk(para_a), now - 1),
+		);
+		Scheduler::add_to_claimqueue(
+			CoreIndex(1),
+			ParasEntry::new(Assignment::Bulk(para_b), now - 1),
+		);
+
+		assert!(!Scheduler::claim_queue_is_empty_for(CoreIndex(0)));
+		assert!(Scheduler::claim_queue_is_empty_for(CoreIndex(1)));
+		assert!(Scheduler::claim_queue_is_empty_for(CoreIndex(2)));
+	});
+}
+
+#[test]
+fn claim_ttl_follows_active_config() {
+	let config = default_config();
+	let genesis_config = genesis_config(&config);
+
+	let para_a = ParaId::from(3_u32);
+	let assignment_a = Assignment::Bulk(para_a);
+
+	new_test_ext(genesis_config).execute_with(|| {
+		MockAssigner::set_core_count(1);
+		let coretime_ttl = config.scheduler_params.ttl;
+
+		schedule_blank_para(para_a);
+
+		run_to_block(1, |number| match number {
+			1 => Some(SessionChangeNotification {
+				new_config: default_config(),
+				validators: vec![ValidatorId::from(Sr25519Keyring::Alice.public())],
+				..Default::default()
+			}),
+			_ => None,
+		});
+
+		assert_eq!(Scheduler::claim_ttl(), coretime_ttl);
+
+		// A pending change does not affect the ttl until it is applied at a session boundary.
+		let mut pending_params = config.scheduler_params;
+		pending_params.ttl = coretime_ttl + 3;
+		assert_ok!(configuration::Pallet::<Test>::set_scheduler_params(
+			RuntimeOrigin::root(),
+			pending_params
+		));
+		assert_eq!(Scheduler::claim_ttl(), coretime_ttl);
+
+		let mut new_config = config.clone();
+		new_config.scheduler_params = pending_params;
+		configuration::Pallet::<Test>::force_set_active_config(new_config);
+		assert_eq!(Scheduler::claim_ttl(), coretime_ttl + 3);
+
+		// New claims expire after the ttl reported by `claim_ttl`.
+		MockAssigner::add_test_assignment(assignment_a.clone());
+		run_to_block(2, |_| None);
+
+		assert_eq!(
+			Scheduler::claimqueue().get(&CoreIndex(0)).unwrap()[0],
+			ParasEntry {
+				assignment: assignment_a,
+				availability_timeouts: 0,
+				ttl: 2 + Scheduler::claim_ttl()
+			}
+		);
+	});
+}
+
+#[test]
+fn estimated_queue_drain_blocks_extrapolates_freed_cores() {
+	let config = default_config();
+	let genesis_config = genesis_config(&config);
+
+	let para_a = ParaId::from(3_u32);
+	let para_b = ParaId::from(4_u32);
+
+	let assignment_a = Assignment::Bulk(para_a);
+	let assignment_b = Assignment::Bulk(para_b);
+
+	new_test_ext(genesis_config).execute_with(|| {
+		MockAssigner::set_core_count(2);
+
+		schedule_blank_para(para_a);
+		schedule_blank_para(para_b);
+
+		run_to_block(1, |number| match number {
+			1 => Some(SessionChangeNotification {
+				new_config: default_config(),
+				validators: vec![
+					ValidatorId::from(Sr25519Keyring::Alice.public()),
+					ValidatorId::from(Sr25519Keyring::Bob.public()),
+				],
+				..Default::default()
+			}),
+			_ => None,
+		});
+
+		MockAssigner::add_test_assignment(assignment_a.clone());
+		MockAssigner::add_test_assignment(assignment_b.clone());
+		run_to_block(2, |_| None);
+
+		// Claims are queued, but no core was freed yet.
+		assert_eq!(Scheduler::claimqueue_len(), 2);
+		assert_eq!(Scheduler::estimated_queue_drain_blocks(), None);
+
+		let mut occupied_map: BTreeMap<CoreIndex, ParaId> = BTreeMap::new();
+		occupied_map.insert(CoreIndex(0), para_a);
+		occupied_map.insert(CoreIndex(1), para_b);
+		Scheduler::occupied(occupied_map);
+
+		MockAssigner::add_test_assignment(assignment_a);
+		MockAssigner::add_test_assignment(assignment_b);
+		let now = 3;
+		run_to_block(now, |_| None);
+		assert_eq!(Scheduler::claimqueue_len(), 2);
+
+		// 2 cores freed over the last `CORES_FREED_HISTORY_LENGTH` blocks, for 2 queued claims.
+		let just_freed: BTreeMap<CoreIndex, FreedReason> =
+			vec![(CoreIndex(0), FreedReason::Concluded), (CoreIndex(1), FreedReason::Concluded)]
+				.into_iter()
+				.collect();
+		Scheduler::free_cores_and_fill_claimqueue(just_freed, now);
+		assert_eq!(Scheduler::claimqueue_len(), 2);
+		assert_eq!(Scheduler::estimated_queue_drain_blocks(), Some(CORES_FREED_HISTORY_LENGTH));
+
+		// Without any core freed for long enough, there is nothing to extrapolate from.
+		run_to_block(now + CORES_FREED_HISTORY_LENGTH, |_| None);
+		assert_eq!(Scheduler::estimated_queue_drain_blocks(), None);
+	});
+}
+
 #[test]
 fn session_change_shuffles_validators() {
 	let genesis_config = genesis_config(&default_config());
@@ -291,6 +544,97 @@ fn session_change_shuffles_validators() {
 	});
 }
 
+#[test]
+fn session_change_seeds_added_cores() {
+	let genesis_config = genesis_config(&default_config());
+
+	let session_change = || SessionChangeNotification {
+		new_config: default_config(),
+		validators: vec![
+			ValidatorId::from(Sr25519Keyring::Alice.public()),
+			ValidatorId::from(Sr25519Keyring::Bob.public()),
+			ValidatorId::from(Sr25519Keyring::Charlie.public()),
+			ValidatorId::from(Sr25519Keyring::Dave.public()),
+			ValidatorId::from(Sr25519Keyring::Eve.public()),
+			ValidatorId::from(Sr25519Keyring::Ferdie.public()),
+			ValidatorId::from(Sr25519Keyring::One.public()),
+			ValidatorId::from(Sr25519Keyring::Two.public()),
+		],
+		random_seed: [99; 32],
+		..Default::default()
+	};
+
+	new_test_ext(genesis_config).execute_with(|| {
+		MockAssigner::set_core_count(5);
+		run_to_block(1, |number| if number == 1 { Some(session_change()) } else { None });
+		assert_eq!(Scheduler::availability_cores().len(), 5);
+
+		// Grow from 5 to 8 cores, with enough assignments to fill every core.
+		MockAssigner::set_core_count(8);
+		for para_id in 0..16u32 {
+			MockAssigner::add_test_assignment(Assignment::Bulk(para_id.into()));
+		}
+		run_to_block(2, |number| if number == 2 { Some(session_change()) } else { None });
+
+		assert_eq!(Scheduler::availability_cores().len(), 8);
+		assert_eq!(MockAssigner::seeded_cores(), Some((5, 8)));
+		System::assert_has_event(Event::CoresAdded { from: 5, to: 8 }.into());
+
+		let scheduled_cores: BTreeSet<_> =
+			Scheduler::scheduled_paras().map(|(core_idx, _)| core_idx).collect();
+		for core_idx in 5..8 {
+			assert!(scheduled_cores.contains(&CoreIndex(core_idx)));
+		}
+
+		// Shrinking does not seed anything.
+		System::reset_events();
+		MockAssigner::set_core_count(5);
+		run_to_block(3, |number| if number == 3 { Some(session_change()) } else { None });
+
+		assert_eq!(Scheduler::availability_cores().len(), 5);
+		assert_eq!(MockAssigner::seeded_cores(), Some((5, 8)));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			crate::mock::RuntimeEvent::Scheduler(Event::CoresAdded { .. })
+		)));
+	});
+}
+
+#[test]
+fn fill_claimqueue_respects_max_cores_per_para() {
+	let mut config = default_config();
+	config.max_cores_per_para = Some(2);
+	let genesis_config = genesis_config(&config);
+
+	let para_a = ParaId::from(3_u32);
+
+	new_test_ext(genesis_config).execute_with(|| {
+		MockAssigner::set_core_count(3);
+		// Enough assignments to fill all three cores with `para_a`.
+		for _ in 0..6 {
+			MockAssigner::add_test_assignment(Assignment::Bulk(para_a));
+		}
+
+		run_to_block(1, |number| match number {
+			1 => Some(SessionChangeNotification {
+				new_config: config.clone(),
+				validators: vec![
+					ValidatorId::from(Sr25519Keyring::Alice.public()),
+					ValidatorId::from(Sr25519Keyring::Bob.public()),
+					ValidatorId::from(Sr25519Keyring::Charlie.public()),
+				],
+				..Default::default()
+			}),
+			_ => None,
+		});
+
+		// Only the two lowest cores are claimed by `para_a`.
+		let scheduled: Vec<_> = Scheduler::scheduled_paras().collect();
+		assert_eq!(scheduled, vec![(CoreIndex(0), para_a), (CoreIndex(1), para_a)]);
+		assert!(Scheduler::claim_queue_is_empty_for(CoreIndex(2)));
+	});
+}
+
 #[test]
 fn session_change_takes_only_max_per_core() {
 	let config = {
@@ -21,7 +21,7 @@
 
 use frame_support::{pallet_prelude::*, traits::DisabledValidators};
 use frame_system::pallet_prelude::BlockNumberFor;
-use primitives::{SessionIndex, ValidatorId, ValidatorIndex};
+use primitives::{vstaging::DisablingReason, SessionIndex, ValidatorId, ValidatorIndex};
 use sp_runtime::traits::AtLeast32BitUnsigned;
 use sp_std::{
 	collections::{btree_map::BTreeMap, vec_deque::VecDeque},
@@ -160,6 +160,12 @@ pub mod pallet {
 	pub(crate) type AllowedRelayParents<T: Config> =
 		StorageValue<_, AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>, ValueQuery>;
 
+	/// Why validators were disabled in the current session, as noted when they were. Indices are
+	/// into the active validators, like those of [`Pallet::disabled_validators`].
+	#[pallet::storage]
+	pub(crate) type DisablingReasons<T: Config> =
+		StorageValue<_, BTreeMap<ValidatorIndex, DisablingReason>, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {}
 }
@@ -192,6 +198,7 @@ impl<T: Config> Pallet<T> {
 		// by the group assigned at `number(R) + 1`, which is guaranteed
 		// to be in the current session.
 		AllowedRelayParents::<T>::mutate(|tracker| tracker.buffer.clear());
+		DisablingReasons::<T>::kill();
 
 		CurrentSessionIndex::<T>::set(session_index);
 		let mut rng: ChaCha20Rng = SeedableRng::from_seed(random_seed);
@@ -240,6 +247,49 @@ impl<T: Config> Pallet<T> {
 			.collect()
 	}
 
+	/// The validators of [`Self::disabled_validators`], each with why it was disabled.
+	///
+	/// The reason is `None` for validators whose disabling was not noted, e.g. validators
+	/// disabled before the runtime started noting reasons.
+	///
+	/// CAVEAT: this might produce incorrect results on session boundaries
+	pub fn disabled_validators_with_reasons() -> Vec<(ValidatorIndex, Option<DisablingReason>)> {
+		let reasons = DisablingReasons::<T>::get();
+		Self::disabled_validators()
+			.into_iter()
+			.map(|v| (v, reasons.get(&v).copied()))
+			.collect()
+	}
+
+	/// Note that `validators` of the current session are being disabled for `reason`. The reason
+	/// first noted for a validator in a session is kept.
+	///
+	/// Governance disabling validators should note [`DisablingReason::Governance`] here before
+	/// disabling them, so that it is not noted as an offence by the session pallet.
+	pub fn note_disabling_reason(
+		validators: impl IntoIterator<Item = ValidatorIndex>,
+		reason: DisablingReason,
+	) {
+		DisablingReasons::<T>::mutate(|reasons| {
+			for validator in validators {
+				reasons.entry(validator).or_insert(reason);
+			}
+		});
+	}
+
+	/// Note that the session pallet disabled the validator at `index` in its validators, which it
+	/// does for offences reported to it, e.g. by staking. Noted as [`DisablingReason::Offence`],
+	/// unless another reason was noted for the validator before.
+	pub(crate) fn note_disabled_by_session(index: u32) {
+		let active_index = ActiveValidatorIndices::<T>::get().iter().position(|v| v.0 == index);
+		if let Some(active_index) = active_index {
+			Self::note_disabling_reason(
+				[ValidatorIndex(active_index as u32)],
+				DisablingReason::Offence,
+			);
+		}
+	}
+
 	/// Test function for setting the current session index.
 	#[cfg(any(feature = "std", feature = "runtime-benchmarks", test))]
 	pub fn set_session_index(index: SessionIndex) {
@@ -17,7 +17,7 @@
 use super::*;
 use crate::{
 	configuration::HostConfiguration,
-	mock::{new_test_ext, MockGenesisConfig, ParasShared},
+	mock::{new_test_ext, set_disabled_validators, MockGenesisConfig, ParasShared},
 };
 use assert_matches::assert_matches;
 use keyring::Sr25519Keyring;
@@ -163,3 +163,54 @@ fn sets_truncates_and_shuffles_validators() {
 		);
 	});
 }
+
+#[test]
+fn disabled_validators_are_reported_with_their_reasons() {
+	let validators = vec![
+		Sr25519Keyring::Alice,
+		Sr25519Keyring::Bob,
+		Sr25519Keyring::Charlie,
+		Sr25519Keyring::Dave,
+		Sr25519Keyring::Ferdie,
+	];
+
+	let mut config = HostConfiguration::default();
+	config.max_validators = None;
+
+	let pubkeys = validator_pubkeys(&validators);
+
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		// The active validators are shuffled to `[4, 1, 2, 3, 0]`.
+		ParasShared::initializer_on_new_session(1, [1; 32], &config, pubkeys.clone());
+
+		// Bob is disabled for backing a candidate concluded invalid. The dispute reports an
+		// offence, so the session pallet disables him as well, which must not override the
+		// reason noted first.
+		ParasShared::note_disabling_reason(
+			[ValidatorIndex(1)],
+			DisablingReason::DisputeConcludedAgainst,
+		);
+		ParasShared::note_disabled_by_session(1);
+		// Alice is disabled by the session pallet only, for an offence reported by staking.
+		ParasShared::note_disabled_by_session(0);
+		// Nothing is noted for an index without a validator.
+		ParasShared::note_disabled_by_session(5);
+		set_disabled_validators(vec![0, 1]);
+
+		assert_eq!(ParasShared::disabled_validators(), vec![ValidatorIndex(4), ValidatorIndex(1)]);
+		assert_eq!(
+			ParasShared::disabled_validators_with_reasons(),
+			vec![
+				(ValidatorIndex(4), Some(DisablingReason::Offence)),
+				(ValidatorIndex(1), Some(DisablingReason::DisputeConcludedAgainst)),
+			]
+		);
+
+		// The reasons are not carried over to the next session.
+		ParasShared::initializer_on_new_session(2, [1; 32], &config, pubkeys);
+		assert_eq!(
+			ParasShared::disabled_validators_with_reasons(),
+			vec![(ValidatorIndex(4), None), (ValidatorIndex(1), None)]
+		);
+	});
+}
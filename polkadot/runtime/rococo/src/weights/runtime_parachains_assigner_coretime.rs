@@ -0,0 +1,61 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `runtime_parachains::assigner_coretime`
+//!
+//! Placeholder weights, not generated by the benchmark CLI. Regenerate them with:
+
+// ./target/production/polkadot
+// benchmark
+// pallet
+// --chain=rococo-dev
+// --steps=50
+// --repeat=20
+// --no-storage-info
+// --no-median-slopes
+// --no-min-squares
+// --pallet=runtime_parachains::assigner_coretime
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=./polkadot/file_header.txt
+// --output=./polkadot/runtime/rococo/src/weights/runtime_parachains_assigner_coretime.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `runtime_parachains::assigner_coretime`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> runtime_parachains::assigner_coretime::WeightInfo for WeightInfo<T> {
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Proof: `Configuration::ActiveConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::ParaIdAffinity` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::ParaIdAffinity` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CoretimeAssignmentProvider::ForcedAssignments` (r:1 w:1)
+	/// Proof: `CoretimeAssignmentProvider::ForcedAssignments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn force_add_assignment() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(18_360_000, 0)
+			.saturating_add(Weight::from_parts(0, 3893))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
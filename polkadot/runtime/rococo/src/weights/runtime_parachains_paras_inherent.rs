@@ -102,6 +102,12 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 	/// Proof: `ParasShared::ActiveValidatorIndices` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Session::DisabledValidators` (r:1 w:0)
 	/// Proof: `Session::DisabledValidators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::CoreInclusionHistory` (r:1 w:1)
+	/// Proof: `ParaInherent::CoreInclusionHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::BitfieldsCredited` (r:1 w:1)
+	/// Proof: `ParaInherent::BitfieldsCredited` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CoretimeAssignmentProvider::ForcedAssignments` (r:1 w:0)
+	/// Proof: `CoretimeAssignmentProvider::ForcedAssignments` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Hrmp::HrmpWatermarks` (r:0 w:1)
 	/// Proof: `Hrmp::HrmpWatermarks` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Paras::Heads` (r:0 w:1)
@@ -120,8 +126,8 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 			.saturating_add(Weight::from_parts(0, 73759))
 			// Standard Error: 19_197
 			.saturating_add(Weight::from_parts(41_842_161, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(26))
-			.saturating_add(T::DbWeight::get().writes(16))
+			.saturating_add(T::DbWeight::get().reads(29))
+			.saturating_add(T::DbWeight::get().writes(18))
 			.saturating_add(Weight::from_parts(0, 23).saturating_mul(v.into()))
 	}
 	/// Storage: `ParaInherent::Included` (r:1 w:1)
@@ -170,6 +176,12 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 	/// Proof: `ParasShared::ActiveValidatorIndices` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Session::DisabledValidators` (r:1 w:0)
 	/// Proof: `Session::DisabledValidators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::CoreInclusionHistory` (r:1 w:1)
+	/// Proof: `ParaInherent::CoreInclusionHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::BitfieldsCredited` (r:1 w:1)
+	/// Proof: `ParaInherent::BitfieldsCredited` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CoretimeAssignmentProvider::ForcedAssignments` (r:1 w:0)
+	/// Proof: `CoretimeAssignmentProvider::ForcedAssignments` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `ParaInclusion::AvailabilityBitfields` (r:0 w:1)
 	/// Proof: `ParaInclusion::AvailabilityBitfields` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `ParasDisputes::Included` (r:0 w:1)
@@ -189,8 +201,8 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 		// Minimum execution time: 428_757_000 picoseconds.
 		Weight::from_parts(449_681_000, 0)
 			.saturating_add(Weight::from_parts(0, 48731))
-			.saturating_add(T::DbWeight::get().reads(24))
-			.saturating_add(T::DbWeight::get().writes(17))
+			.saturating_add(T::DbWeight::get().reads(27))
+			.saturating_add(T::DbWeight::get().writes(19))
 	}
 	/// Storage: `ParaInherent::Included` (r:1 w:1)
 	/// Proof: `ParaInherent::Included` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -244,6 +256,12 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 	/// Proof: `ParasShared::ActiveValidatorIndices` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Session::DisabledValidators` (r:1 w:0)
 	/// Proof: `Session::DisabledValidators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::CoreInclusionHistory` (r:1 w:1)
+	/// Proof: `ParaInherent::CoreInclusionHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::BitfieldsCredited` (r:1 w:1)
+	/// Proof: `ParaInherent::BitfieldsCredited` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CoretimeAssignmentProvider::ForcedAssignments` (r:1 w:0)
+	/// Proof: `CoretimeAssignmentProvider::ForcedAssignments` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `ParasDisputes::Included` (r:0 w:1)
 	/// Proof: `ParasDisputes::Included` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Hrmp::HrmpWatermarks` (r:0 w:1)
@@ -264,8 +282,8 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 			.saturating_add(Weight::from_parts(0, 48803))
 			// Standard Error: 18_279
 			.saturating_add(Weight::from_parts(43_528, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(27))
-			.saturating_add(T::DbWeight::get().writes(16))
+			.saturating_add(T::DbWeight::get().reads(30))
+			.saturating_add(T::DbWeight::get().writes(18))
 	}
 	/// Storage: `ParaInherent::Included` (r:1 w:1)
 	/// Proof: `ParaInherent::Included` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -323,6 +341,12 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 	/// Proof: `ParasShared::ActiveValidatorIndices` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Session::DisabledValidators` (r:1 w:0)
 	/// Proof: `Session::DisabledValidators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::CoreInclusionHistory` (r:1 w:1)
+	/// Proof: `ParaInherent::CoreInclusionHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::BitfieldsCredited` (r:1 w:1)
+	/// Proof: `ParaInherent::BitfieldsCredited` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CoretimeAssignmentProvider::ForcedAssignments` (r:1 w:0)
+	/// Proof: `CoretimeAssignmentProvider::ForcedAssignments` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `ParasDisputes::Included` (r:0 w:1)
 	/// Proof: `ParasDisputes::Included` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Hrmp::HrmpWatermarks` (r:0 w:1)
@@ -340,7 +364,32 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 		// Minimum execution time: 34_352_245_000 picoseconds.
 		Weight::from_parts(34_587_559_000, 0)
 			.saturating_add(Weight::from_parts(0, 48816))
-			.saturating_add(T::DbWeight::get().reads(29))
-			.saturating_add(T::DbWeight::get().writes(16))
+			.saturating_add(T::DbWeight::get().reads(32))
+			.saturating_add(T::DbWeight::get().writes(18))
+	}
+	/// Storage: `ParaInherent::OnChainVotes` (r:0 w:1)
+	/// Proof: `ParaInherent::OnChainVotes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::OnChainDisputesInclusion` (r:0 w:1)
+	/// Proof: `ParaInherent::OnChainDisputesInclusion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::RecentOnChainVotes` (r:0 w:1)
+	/// Proof: `ParaInherent::RecentOnChainVotes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn force_clear_scraped_votes() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(4_538_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `ParaScheduler::AvailabilityCores` (r:1 w:0)
+	/// Proof: `ParaScheduler::AvailabilityCores` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::ForcedDisputedCores` (r:0 w:1)
+	/// Proof: `ParaInherent::ForcedDisputedCores` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 100]`.
+	fn force_set_disputed_cores(c: u32, ) -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(6_418_362, 0)
+			.saturating_add(Weight::from_parts(0, 1488))
+			.saturating_add(Weight::from_parts(5_846, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
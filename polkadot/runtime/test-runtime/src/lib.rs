@@ -515,6 +515,9 @@ impl parachains_disputes::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RewardValidators = ();
 	type SlashingHandler = parachains_slashing::SlashValidatorsForDisputes<ParasSlashing>;
+	type TieBreak = ();
+	type VerifiedBackingVotes = ParaInherent;
+	type PrioritizeDisputeOrigin = frame_system::EnsureRoot<AccountId>;
 	type WeightInfo = parachains_disputes::TestWeightInfo;
 }
 
@@ -536,7 +539,13 @@ impl parachains_slashing::Config for Runtime {
 }
 
 impl parachains_paras_inherent::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = parachains_paras_inherent::TestWeightInfo;
+	type OnChainVotesRetention = ();
+	type DisabledValidatorsGracePeriod = ();
+	type ClearScrapedVotesOrigin = frame_system::EnsureRoot<AccountId>;
+	type ForceDisputedCoresOrigin = frame_system::EnsureRoot<AccountId>;
+	type OnBitfieldCredited = ();
 }
 
 impl parachains_initializer::Config for Runtime {
@@ -590,6 +599,7 @@ impl parachains_assigner_parachains::Config for Runtime {}
 
 impl parachains_scheduler::Config for Runtime {
 	type AssignmentProvider = ParaAssignmentProvider;
+	type RuntimeEvent = RuntimeEvent;
 }
 
 impl paras_sudo_wrapper::Config for Runtime {}
@@ -46,7 +46,7 @@ use pallet_transaction_payment::{CurrencyAdapter, FeeDetails, RuntimeDispatchInf
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use primitives::{
 	slashing,
-	vstaging::{ApprovalVotingParams, NodeFeatures},
+	vstaging::{ApprovalVotingParams, DisablingReason, NodeFeatures},
 	AccountId, AccountIndex, Balance, BlockNumber, CandidateEvent, CandidateHash,
 	CommittedCandidateReceipt, CoreState, DisputeState, ExecutorParams, GroupRotationInfo, Hash,
 	Id as ParaId, InboundDownwardMessage, InboundHrmpMessage, Moment, Nonce,
@@ -1212,13 +1212,20 @@ impl parachains_hrmp::Config for Runtime {
 }
 
 impl parachains_paras_inherent::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::runtime_parachains_paras_inherent::WeightInfo<Runtime>;
+	type OnChainVotesRetention = ();
+	type DisabledValidatorsGracePeriod = ();
+	type ClearScrapedVotesOrigin = EnsureRoot<AccountId>;
+	type ForceDisputedCoresOrigin = EnsureRoot<AccountId>;
+	type OnBitfieldCredited = ();
 }
 
 impl parachains_scheduler::Config for Runtime {
 	// If you change this, make sure the `Assignment` type of the new provider is binary compatible,
 	// otherwise provide a migration.
 	type AssignmentProvider = CoretimeAssignmentProvider;
+	type RuntimeEvent = RuntimeEvent;
 }
 
 parameter_types! {
@@ -1245,7 +1252,12 @@ impl parachains_assigner_on_demand::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_assigner_on_demand::WeightInfo<Runtime>;
 }
 
-impl parachains_assigner_coretime::Config for Runtime {}
+impl parachains_assigner_coretime::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type MaxForcedAssignments = ConstU32<16>;
+	type WeightInfo = weights::runtime_parachains_assigner_coretime::WeightInfo<Runtime>;
+}
 
 impl parachains_initializer::Config for Runtime {
 	type Randomness = pallet_babe::RandomnessFromOneEpochAgo<Runtime>;
@@ -1276,6 +1288,9 @@ impl parachains_disputes::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RewardValidators = parachains_reward_points::RewardValidatorsWithEraPoints<Runtime>;
 	type SlashingHandler = parachains_slashing::SlashValidatorsForDisputes<ParasSlashing>;
+	type TieBreak = ();
+	type VerifiedBackingVotes = ParaInherent;
+	type PrioritizeDisputeOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::runtime_parachains_disputes::WeightInfo<Runtime>;
 }
 
@@ -1703,6 +1718,7 @@ pub mod migrations {
 		pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
 		parachains_configuration::migration::v11::MigrateToV11<Runtime>,
 		parachains_configuration::migration::v12::MigrateToV12<Runtime>,
+		parachains_configuration::migration::v13::MigrateToV13<Runtime>,
 		// permanent
 		pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 		// Migrate from legacy lease to coretime. Needs to run after configuration v11
@@ -1750,6 +1766,7 @@ mod benches {
 		[runtime_parachains::paras, Paras]
 		[runtime_parachains::paras_inherent, ParaInherent]
 		[runtime_parachains::assigner_on_demand, OnDemandAssignmentProvider]
+		[runtime_parachains::assigner_coretime, CoretimeAssignmentProvider]
 		[runtime_parachains::coretime, Coretime]
 		// Substrate
 		[pallet_bags_list, VoterList]
@@ -1861,7 +1878,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(10)]
+	#[api_version(13)]
 	impl primitives::runtime_api::ParachainHost<Block> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -2016,6 +2033,18 @@ sp_api::impl_runtime_apis! {
 		fn node_features() -> NodeFeatures {
 			parachains_staging_runtime_api_impl::node_features::<Runtime>()
 		}
+
+		fn inherent_weight_limits() -> (Weight, u32) {
+			parachains_staging_runtime_api_impl::inherent_weight_limits::<Runtime>()
+		}
+
+		fn disabled_validators_with_reasons() -> Vec<(ValidatorIndex, Option<DisablingReason>)> {
+			parachains_staging_runtime_api_impl::disabled_validators_with_reasons::<Runtime>()
+		}
+
+		fn inherent_weight_breakdown(inherent: primitives::InherentData) -> (Weight, Weight, Weight) {
+			parachains_staging_runtime_api_impl::inherent_weight_breakdown::<Runtime>(&inherent)
+		}
 	}
 
 	impl beefy_primitives::BeefyApi<Block, BeefyId> for Runtime {
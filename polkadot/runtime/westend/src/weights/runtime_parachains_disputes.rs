@@ -61,4 +61,12 @@ impl<T: frame_system::Config> runtime_parachains::disputes::WeightInfo for Weigh
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: ParasDisputes PrioritizedDispute (r:0 w:1)
+	/// Proof Skipped: ParasDisputes PrioritizedDispute (max_values: Some(1), max_size: None, mode: Measured)
+	fn force_prioritize_dispute() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(2_264_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
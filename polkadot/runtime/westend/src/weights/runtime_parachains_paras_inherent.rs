@@ -103,6 +103,12 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 	/// Proof Skipped: ParaScheduler Scheduled (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParaScheduler ValidatorGroups (r:1 w:0)
 	/// Proof Skipped: ParaScheduler ValidatorGroups (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParaInherent CoreInclusionHistory (r:1 w:1)
+	/// Proof Skipped: ParaInherent CoreInclusionHistory (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParaInherent BitfieldsCredited (r:1 w:1)
+	/// Proof Skipped: ParaInherent BitfieldsCredited (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CoretimeAssignmentProvider ForcedAssignments (r:1 w:0)
+	/// Proof Skipped: CoretimeAssignmentProvider ForcedAssignments (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Hrmp HrmpWatermarks (r:0 w:1)
 	/// Proof Skipped: Hrmp HrmpWatermarks (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Paras Heads (r:0 w:1)
@@ -119,8 +125,8 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 			.saturating_add(Weight::from_parts(0, 56458))
 			// Standard Error: 20_559
 			.saturating_add(Weight::from_parts(56_965_025, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(27))
-			.saturating_add(T::DbWeight::get().writes(15))
+			.saturating_add(T::DbWeight::get().reads(30))
+			.saturating_add(T::DbWeight::get().writes(17))
 			.saturating_add(Weight::from_parts(0, 23).saturating_mul(v.into()))
 	}
 	/// Storage: ParaInherent Included (r:1 w:1)
@@ -171,6 +177,12 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 	/// Proof Skipped: ParaScheduler Scheduled (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParaScheduler ValidatorGroups (r:1 w:0)
 	/// Proof Skipped: ParaScheduler ValidatorGroups (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParaInherent CoreInclusionHistory (r:1 w:1)
+	/// Proof Skipped: ParaInherent CoreInclusionHistory (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParaInherent BitfieldsCredited (r:1 w:1)
+	/// Proof Skipped: ParaInherent BitfieldsCredited (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CoretimeAssignmentProvider ForcedAssignments (r:1 w:0)
+	/// Proof Skipped: CoretimeAssignmentProvider ForcedAssignments (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParaInclusion AvailabilityBitfields (r:0 w:1)
 	/// Proof Skipped: ParaInclusion AvailabilityBitfields (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParasDisputes Included (r:0 w:1)
@@ -188,8 +200,8 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 		// Minimum execution time: 457_404_000 picoseconds.
 		Weight::from_parts(485_416_000, 0)
 			.saturating_add(Weight::from_parts(0, 48292))
-			.saturating_add(T::DbWeight::get().reads(25))
-			.saturating_add(T::DbWeight::get().writes(16))
+			.saturating_add(T::DbWeight::get().reads(28))
+			.saturating_add(T::DbWeight::get().writes(18))
 	}
 	/// Storage: ParaInherent Included (r:1 w:1)
 	/// Proof Skipped: ParaInherent Included (max_values: Some(1), max_size: None, mode: Measured)
@@ -245,6 +257,12 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 	/// Proof Skipped: Paras ParaLifecycles (max_values: None, max_size: None, mode: Measured)
 	/// Storage: MessageQueue BookStateFor (r:1 w:0)
 	/// Proof: MessageQueue BookStateFor (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: ParaInherent CoreInclusionHistory (r:1 w:1)
+	/// Proof Skipped: ParaInherent CoreInclusionHistory (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParaInherent BitfieldsCredited (r:1 w:1)
+	/// Proof Skipped: ParaInherent BitfieldsCredited (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CoretimeAssignmentProvider ForcedAssignments (r:1 w:0)
+	/// Proof Skipped: CoretimeAssignmentProvider ForcedAssignments (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParasDisputes Included (r:0 w:1)
 	/// Proof Skipped: ParasDisputes Included (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Hrmp HrmpWatermarks (r:0 w:1)
@@ -263,8 +281,8 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 			.saturating_add(Weight::from_parts(0, 48327))
 			// Standard Error: 33_413
 			.saturating_add(Weight::from_parts(56_199_819, 0).saturating_mul(v.into()))
-			.saturating_add(T::DbWeight::get().reads(28))
-			.saturating_add(T::DbWeight::get().writes(15))
+			.saturating_add(T::DbWeight::get().reads(31))
+			.saturating_add(T::DbWeight::get().writes(17))
 	}
 	/// Storage: ParaInherent Included (r:1 w:1)
 	/// Proof Skipped: ParaInherent Included (max_values: Some(1), max_size: None, mode: Measured)
@@ -324,6 +342,12 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 	/// Proof Skipped: Paras ParaLifecycles (max_values: None, max_size: None, mode: Measured)
 	/// Storage: MessageQueue BookStateFor (r:1 w:0)
 	/// Proof: MessageQueue BookStateFor (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: ParaInherent CoreInclusionHistory (r:1 w:1)
+	/// Proof Skipped: ParaInherent CoreInclusionHistory (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParaInherent BitfieldsCredited (r:1 w:1)
+	/// Proof Skipped: ParaInherent BitfieldsCredited (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CoretimeAssignmentProvider ForcedAssignments (r:1 w:0)
+	/// Proof Skipped: CoretimeAssignmentProvider ForcedAssignments (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParasDisputes Included (r:0 w:1)
 	/// Proof Skipped: ParasDisputes Included (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Hrmp HrmpWatermarks (r:0 w:1)
@@ -339,7 +363,32 @@ impl<T: frame_system::Config> runtime_parachains::paras_inherent::WeightInfo for
 		// Minimum execution time: 43_320_529_000 picoseconds.
 		Weight::from_parts(45_622_613_000, 0)
 			.saturating_add(Weight::from_parts(0, 48354))
-			.saturating_add(T::DbWeight::get().reads(30))
-			.saturating_add(T::DbWeight::get().writes(15))
+			.saturating_add(T::DbWeight::get().reads(33))
+			.saturating_add(T::DbWeight::get().writes(17))
+	}
+	/// Storage: `ParaInherent::OnChainVotes` (r:0 w:1)
+	/// Proof: `ParaInherent::OnChainVotes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::OnChainDisputesInclusion` (r:0 w:1)
+	/// Proof: `ParaInherent::OnChainDisputesInclusion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::RecentOnChainVotes` (r:0 w:1)
+	/// Proof: `ParaInherent::RecentOnChainVotes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn force_clear_scraped_votes() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(4_538_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `ParaScheduler::AvailabilityCores` (r:1 w:0)
+	/// Proof: `ParaScheduler::AvailabilityCores` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParaInherent::ForcedDisputedCores` (r:0 w:1)
+	/// Proof: `ParaInherent::ForcedDisputedCores` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 100]`.
+	fn force_set_disputed_cores(c: u32, ) -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(6_418_362, 0)
+			.saturating_add(Weight::from_parts(0, 1488))
+			.saturating_add(Weight::from_parts(5_846, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
@@ -161,7 +161,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 268,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -575,6 +575,14 @@ impl pallet_asset_tx_payment::Config for Runtime {
 	type BenchmarkHelper = AssetTxHelper;
 }
 
+parameter_types! {
+	pub const FeeSlippageWarnThreshold: Permill = Permill::from_percent(5);
+	pub const MinAssetFee: Balance = 0;
+	pub const OnBelowMinAssetFee: pallet_asset_conversion_tx_payment::MinAssetFeePolicy =
+		pallet_asset_conversion_tx_payment::MinAssetFeePolicy::ChargeMinimum;
+	pub const MaxFeeSwapsPerBlock: u32 = 500;
+}
+
 impl pallet_asset_conversion_tx_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Fungibles = Assets;
@@ -583,6 +591,11 @@ impl pallet_asset_conversion_tx_payment::Config for Runtime {
 		AssetConversion,
 		Native,
 	>;
+	type SlippageWarnThreshold = FeeSlippageWarnThreshold;
+	type MinAssetFee = MinAssetFee;
+	type OnBelowMinAssetFee = OnBelowMinAssetFee;
+	type AssetFeeAllowedFor = frame_support::traits::Everything;
+	type MaxFeeSwapsPerBlock = MaxFeeSwapsPerBlock;
 	type WeightInfo = pallet_asset_conversion_tx_payment::weights::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = AssetConversionTxHelper;
@@ -590,6 +603,9 @@ impl pallet_asset_conversion_tx_payment::Config for Runtime {
 
 impl pallet_skip_feeless_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_skip_feeless_payment::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -1694,6 +1710,7 @@ parameter_types! {
 	pub const PoolSetupFee: Balance = 1 * DOLLARS; // should be more or equal to the existential deposit
 	pub const MintMinLiquidity: Balance = 100;  // 100 is good enough when the main currency has 10-12 decimals.
 	pub const LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	pub const MaxPoolFee: Permill = Permill::from_percent(10);
 	pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
 }
 
@@ -1714,11 +1731,17 @@ impl pallet_asset_conversion::Config for Runtime {
 	type PoolSetupFeeAsset = Native;
 	type PoolSetupFeeTarget = ResolveAssetTo<AssetConversionOrigin, Self::Assets>;
 	type PalletId = AssetConversionPalletId;
+	type MaxLpSupplyOrigin = EnsureRoot<AccountId>;
+	type PoolFeeOrigin = EnsureRoot<AccountId>;
 	type LPFee = ConstU32<3>; // means 0.3%
+	type MaxPoolFee = MaxPoolFee;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
 	type MaxSwapPathLength = ConstU32<4>;
 	type MintMinLiquidity = MintMinLiquidity;
+	type TrackPoolVolume = ConstBool<true>;
+	type TrackPriceMoves = ConstBool<false>;
+	type OnPriceMove = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -2191,6 +2214,7 @@ impl pallet_parameters::Config for Runtime {
 	type RuntimeParameters = RuntimeParameters;
 	type RuntimeEvent = RuntimeEvent;
 	type AdminOrigin = DynamicParametersManagerOrigin;
+	type HistoryDepth = ConstU32<0>;
 	type WeightInfo = ();
 }
 
@@ -2496,6 +2520,7 @@ mod benches {
 		[pallet_asset_conversion_tx_payment, AssetConversionTxPayment]
 		[pallet_asset_tx_payment, AssetTxPayment]
 		[pallet_transaction_payment, TransactionPayment]
+		[pallet_skip_feeless_payment, SkipFeelessPayment]
 		[pallet_election_provider_multi_phase, ElectionProviderMultiPhase]
 		[pallet_election_provider_support_benchmarking, EPSBench::<Runtime>]
 		[pallet_elections_phragmen, Elections]
@@ -2843,6 +2868,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -2860,6 +2886,38 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(asset1, asset2).ok()
 		}
+
+		fn pool_summaries(start_after: Option<(NativeOrWithId<u32>, NativeOrWithId<u32>)>, limit: u32) -> Vec<pallet_asset_conversion::PoolSummary<NativeOrWithId<u32>, Balance>> {
+			AssetConversion::pool_summaries(start_after, limit)
+		}
+
+		fn acceptable_fee_assets(start_after: Option<NativeOrWithId<u32>>, limit: u32) -> Vec<NativeOrWithId<u32>> {
+			AssetConversion::acceptable_fee_assets(Native::get(), ExistentialDeposit::get(), start_after, limit)
+		}
+
+		fn pool_exists(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>) -> bool {
+			AssetConversion::pool_exists(asset1, asset2)
+		}
+	}
+
+	impl pallet_asset_conversion_tx_payment::AssetConversionTxPaymentApi<
+		Block,
+		Balance,
+		Balance,
+		u32,
+	> for Runtime
+	{
+		fn query_fee_in(
+			call_len: u32,
+			info: frame_support::dispatch::DispatchInfo,
+			asset: Option<u32>,
+		) -> pallet_asset_conversion_tx_payment::FeeQuote<Balance, Balance> {
+			AssetConversionTxPayment::query_fee_in(call_len, &info, asset)
+		}
+
+		fn asset_fee_capabilities() -> pallet_asset_conversion_tx_payment::AssetFeeCapabilities {
+			AssetConversionTxPayment::asset_fee_capabilities()
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
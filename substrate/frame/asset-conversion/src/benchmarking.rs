@@ -164,6 +164,17 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	assert_eq!(event, &system_event);
 }
 
+/// Checks that the volume of every hop of `path` was recorded, if the volume is tracked.
+fn assert_pool_volume_noted<T: Config>(path: &[Box<T::AssetKind>]) {
+	if !T::TrackPoolVolume::get() {
+		return
+	}
+	for hop in path.windows(2) {
+		let (volume_in, _) = AssetConversion::<T>::pool_volume(&hop[0], &hop[1]).unwrap();
+		assert!(!volume_in.is_zero());
+	}
+}
+
 #[benchmarks(where T::Assets: Create<T::AccountId> + Mutate<T::AccountId>, T::PoolAssetId: Into<u32>,)]
 mod benchmarks {
 	use super::*;
@@ -211,8 +222,12 @@ mod benchmarks {
 		);
 
 		let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).unwrap();
-		let lp_minted =
-			AssetConversion::<T>::calc_lp_amount_for_zero_supply(&liquidity1, &liquidity2).unwrap();
+		let lp_minted = AssetConversion::<T>::calc_lp_amount_for_zero_supply(
+			&liquidity1,
+			&liquidity2,
+			&T::MintMinLiquidity::get(),
+		)
+		.unwrap();
 		assert_eq!(T::PoolAssets::balance(lp_token, &caller), lp_minted);
 		assert_eq!(T::Assets::balance(asset1, &pool_account), liquidity1);
 		assert_eq!(T::Assets::balance(asset2, &pool_account), liquidity2);
@@ -294,6 +309,7 @@ mod benchmarks {
 			swap_amount + T::Balance::one()
 		));
 		let init_caller_balance = T::Assets::balance(asset_in.clone(), &caller);
+		let hops = path.clone();
 
 		#[extrinsic_call]
 		_(
@@ -307,6 +323,7 @@ mod benchmarks {
 
 		let actual_balance = T::Assets::balance(asset_in, &caller);
 		assert_eq!(actual_balance, init_caller_balance - swap_amount);
+		assert_pool_volume_noted::<T>(&hops);
 	}
 
 	#[benchmark]
@@ -343,6 +360,7 @@ mod benchmarks {
 		let asset_out = *path.last().unwrap().clone();
 		assert_ok!(T::Assets::mint_into(asset_in, &caller, max_swap_amount));
 		let init_caller_balance = T::Assets::balance(asset_out.clone(), &caller);
+		let hops = path.clone();
 
 		#[extrinsic_call]
 		_(
@@ -356,6 +374,182 @@ mod benchmarks {
 
 		let actual_balance = T::Assets::balance(asset_out, &caller);
 		assert_eq!(actual_balance, init_caller_balance + T::Balance::one());
+		assert_pool_volume_noted::<T>(&hops);
+	}
+
+	#[benchmark]
+	fn swap_batch(
+		n: Linear<1, MAX_SWAP_BATCH_SIZE>,
+		p: Linear<2, { T::MaxSwapPathLength::get() }>,
+	) {
+		let mut swap_amount = T::Balance::one();
+		let mut path = vec![];
+
+		let caller: T::AccountId = whitelisted_caller();
+		create_fee_asset::<T>(&caller);
+		for i in 1..p {
+			let (asset1, asset2) = T::BenchmarkHelper::create_pair(i - 1, i);
+			swap_amount = swap_amount + T::Balance::one();
+			if path.len() == 0 {
+				path = vec![Box::new(asset1.clone()), Box::new(asset2.clone())];
+			} else {
+				path.push(Box::new(asset2.clone()));
+			}
+
+			let (_, liquidity1, liquidity2) = create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+
+			assert_ok!(AssetConversion::<T>::add_liquidity(
+				SystemOrigin::Signed(caller.clone()).into(),
+				Box::new(asset1.clone()),
+				Box::new(asset2.clone()),
+				liquidity1,
+				liquidity2,
+				T::Balance::one(),
+				T::Balance::zero(),
+				caller.clone(),
+			));
+		}
+
+		let asset_in = *path.first().unwrap().clone();
+		let batch_amount = swap_amount * n.into();
+		assert_ok!(T::Assets::mint_into(
+			asset_in.clone(),
+			&caller,
+			batch_amount + T::Balance::one()
+		));
+		let init_caller_balance = T::Assets::balance(asset_in.clone(), &caller);
+		let swaps = (0..n)
+			.map(|_| SwapRequest {
+				path: path.clone(),
+				amount_in: swap_amount,
+				amount_out_min: T::Balance::one(),
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller.clone()), swaps, caller.clone(), true);
+
+		let actual_balance = T::Assets::balance(asset_in, &caller);
+		assert_eq!(actual_balance, init_caller_balance - batch_amount);
+		assert_pool_volume_noted::<T>(&path);
+	}
+
+	#[benchmark]
+	fn set_max_lp_supply() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+
+		create_fee_asset::<T>(&caller);
+		create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+		let origin = T::MaxLpSupplyOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let max_lp_supply = Some(T::Balance::one());
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			max_lp_supply,
+		);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		assert_last_event::<T>(Event::MaxLpSupplySet { pool_id, max_lp_supply }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn zap_add_liquidity() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+
+		create_fee_asset::<T>(&caller);
+		let (lp_token, liquidity1, liquidity2) =
+			create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+
+		assert_ok!(AssetConversion::<T>::add_liquidity(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			liquidity1,
+			liquidity2,
+			T::Balance::one(),
+			T::Balance::zero(),
+			caller.clone(),
+		));
+		create_asset::<T>(&caller, &asset1, liquidity1);
+		let lp_balance = T::PoolAssets::balance(lp_token.clone(), &caller);
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			Box::new(asset1),
+			Box::new(asset2),
+			liquidity1,
+			T::Balance::zero(),
+			T::Balance::zero(),
+			caller.clone(),
+		);
+
+		assert!(T::PoolAssets::balance(lp_token, &caller) > lp_balance);
+	}
+
+	#[benchmark]
+	fn set_pool_fee() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+
+		create_fee_asset::<T>(&caller);
+		create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+		let origin =
+			T::PoolFeeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let fee = T::MaxPoolFee::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Box::new(asset1.clone()), Box::new(asset2.clone()), fee);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		assert_last_event::<T>(Event::PoolFeeSet { pool_id, fee }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_liquidity_percent() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+
+		create_fee_asset::<T>(&caller);
+		let (lp_token, liquidity1, liquidity2) =
+			create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+
+		assert_ok!(AssetConversion::<T>::add_liquidity(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			liquidity1,
+			liquidity2,
+			T::Balance::one(),
+			T::Balance::zero(),
+			caller.clone(),
+		));
+		let lp_balance =
+			<T::PoolAssets as Inspect<T::AccountId>>::balance(lp_token.clone(), &caller);
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			Box::new(asset1),
+			Box::new(asset2),
+			Permill::from_percent(50),
+			T::Balance::zero(),
+			T::Balance::zero(),
+			caller.clone(),
+		);
+
+		let new_lp_balance = <T::PoolAssets as Inspect<T::AccountId>>::balance(lp_token, &caller);
+		assert!(new_lp_balance < lp_balance);
 	}
 
 	impl_benchmark_test_suite!(AssetConversion, crate::mock::new_test_ext(), crate::mock::Test);
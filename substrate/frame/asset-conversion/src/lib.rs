@@ -24,12 +24,15 @@
 //! This pallet allows you to:
 //!
 //!  - [create a liquidity pool](`Pallet::create_pool()`) for 2 assets
+//!  - [create a liquidity pool with its own minimum
+//!    liquidity](`Pallet::create_pool_with_min_liquidity()`)
 //!  - [provide the liquidity](`Pallet::add_liquidity()`) and receive back an LP token
 //!  - [exchange the LP token back to assets](`Pallet::remove_liquidity()`)
 //!  - [swap a specific amount of assets for another](`Pallet::swap_exact_tokens_for_tokens()`) if
 //!    there is a pool created, or
 //!  - [swap some assets for a specific amount of
-//!    another](`Pallet::swap_tokens_for_exact_tokens()`).
+//!    another](`Pallet::swap_tokens_for_exact_tokens()`), or
+//!  - [execute several swaps at once](`Pallet::swap_batch()`), all or nothing.
 //!  - [query for an exchange price](`AssetConversionApi::quote_price_exact_tokens_for_tokens`) via
 //!    a runtime call endpoint
 //!  - [query the size of a liquidity pool](`AssetConversionApi::get_reserves`) via a runtime api
@@ -39,6 +42,7 @@
 //! both take a path parameter of the route to take. If you want to swap from native asset to
 //! non-native asset 1, you would pass in a path of `[DOT, 1]` or `[1, DOT]`. If you want to swap
 //! from non-native asset 1 to non-native asset 2, you would pass in a path of `[1, DOT, 2]`.
+//! [`Pallet::resolve_path`] finds such a path deterministically from the existing pools.
 //!
 //! (For an example of configuring this pallet to use `Location` as an asset id, see the
 //! cumulus repo).
@@ -91,16 +95,26 @@ use sp_runtime::{
 		CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Ensure, IntegerSquareRoot, MaybeDisplay,
 		One, TrailingZeroInput, Zero,
 	},
-	DispatchError, Saturating, TokenError, TransactionOutcome,
+	DispatchError, FixedPointNumber, FixedU128, SaturatedConversion, Saturating, TokenError,
+	TransactionOutcome,
 };
-use sp_std::{boxed::Box, collections::btree_set::BTreeSet, vec::Vec};
+use sp_std::{boxed::Box, collections::btree_set::BTreeSet, vec, vec::Vec};
+
+/// The maximum number of pools returned by a single [`Pallet::pool_summaries`] query.
+pub const MAX_POOL_SUMMARIES: u32 = 256;
+
+/// The maximum number of assets returned by a single [`Pallet::acceptable_fee_assets`] query.
+pub const MAX_ACCEPTABLE_FEE_ASSETS: u32 = 256;
+
+/// The maximum number of swaps in a single [`Pallet::swap_batch`].
+pub const MAX_SWAP_BATCH_SIZE: u32 = 32;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use sp_arithmetic::{traits::Unsigned, Permill};
+	use sp_arithmetic::{traits::Unsigned, PerThing, Permill};
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -133,7 +147,10 @@ pub mod pallet {
 			+ Balanced<Self::AccountId>;
 
 		/// Liquidity pool identifier.
-		type PoolId: Parameter + MaxEncodedLen + Ord;
+		///
+		/// Built from a pair of [`Config::AssetKind`]s to tell the direction of a swap through the
+		/// pool.
+		type PoolId: Parameter + MaxEncodedLen + Ord + From<(Self::AssetKind, Self::AssetKind)>;
 
 		/// Provides means to resolve the [`Config::PoolId`] and it's `AccountId` from a pair
 		/// of [`Config::AssetKind`]s.
@@ -152,9 +169,15 @@ pub mod pallet {
 			+ AccountTouch<Self::PoolAssetId, Self::AccountId, Balance = Self::Balance>;
 
 		/// A % the liquidity providers will take of every swap. Represents 10ths of a percent.
+		///
+		/// Applies to all pools without a fee of their own set with [`Pallet::set_pool_fee`].
 		#[pallet::constant]
 		type LPFee: Get<u32>;
 
+		/// The maximum fee [`Pallet::set_pool_fee`] may set for a pool.
+		#[pallet::constant]
+		type MaxPoolFee: Get<Permill>;
+
 		/// A one-time fee to setup the pool.
 		#[pallet::constant]
 		type PoolSetupFee: Get<Self::Balance>;
@@ -178,10 +201,29 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSwapPathLength: Get<u32>;
 
+		/// Whether the cumulative input volume of every swap is recorded in [`PoolVolume`].
+		#[pallet::constant]
+		type TrackPoolVolume: Get<bool>;
+
+		/// Whether [`Config::OnPriceMove`] is informed of swaps moving the price of a pool out of
+		/// its band.
+		#[pallet::constant]
+		type TrackPriceMoves: Get<bool>;
+
+		/// Handler of swaps moving the price of a pool out of its band.
+		type OnPriceMove: OnPoolPriceMove<Self::PoolId>;
+
 		/// The pallet's id, used for deriving its sovereign account ID.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// Origin allowed to cap the lp token supply of pools with
+		/// [`Pallet::set_max_lp_supply`].
+		type MaxLpSupplyOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin allowed to set the fee of pools with [`Pallet::set_pool_fee`].
+		type PoolFeeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -196,6 +238,30 @@ pub mod pallet {
 	pub type Pools<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::PoolId, PoolInfo<T::PoolAssetId>, OptionQuery>;
 
+	/// The minimum liquidity of pools created with
+	/// [`Pallet::create_pool_with_min_liquidity`], overriding [`Config::MintMinLiquidity`].
+	#[pallet::storage]
+	pub type PoolMinLiquidity<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, T::Balance, OptionQuery>;
+
+	/// The maximum lp token supply of pools capped with [`Pallet::set_max_lp_supply`].
+	#[pallet::storage]
+	pub type PoolMaxLpSupply<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, T::Balance, OptionQuery>;
+
+	/// The fee of pools set with [`Pallet::set_pool_fee`], overriding [`Config::LPFee`].
+	#[pallet::storage]
+	pub type PoolFee<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, Permill, OptionQuery>;
+
+	/// The cumulative amount swapped into each pool, per direction. The first element accounts
+	/// for swaps whose input asset is the first one of the `PoolId`, the second element for the
+	/// opposite direction.
+	///
+	/// Only updated when [`Config::TrackPoolVolume`] is enabled.
+	#[pallet::storage]
+	pub type PoolVolume<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, (T::Balance, T::Balance), ValueQuery>;
+
 	/// Stores the `PoolAssetId` that is going to be used for the next lp token.
 	/// This gets incremented whenever a new lp pool is created.
 	#[pallet::storage]
@@ -281,6 +347,20 @@ pub mod pallet {
 			/// E.g. (A, amount_in) -> (Dot, amount_out) -> (B, amount_out)
 			path: BalancePath<T>,
 		},
+		/// The lp token supply of a pool was capped, or uncapped if `max_lp_supply` is `None`.
+		MaxLpSupplySet {
+			/// The pool id of the pool whose cap was set.
+			pool_id: T::PoolId,
+			/// The maximum lp token supply of the pool.
+			max_lp_supply: Option<T::Balance>,
+		},
+		/// The fee liquidity providers take of every swap through a pool was set.
+		PoolFeeSet {
+			/// The pool id of the pool whose fee was set.
+			pool_id: T::PoolId,
+			/// The new fee of the pool.
+			fee: Permill,
+		},
 	}
 
 	#[pallet::error]
@@ -334,6 +414,10 @@ pub mod pallet {
 		IncorrectPoolAssetId,
 		/// The destination account cannot exist with the swapped funds.
 		BelowMinimum,
+		/// Minting the lp tokens would take the pool's supply above its [`PoolMaxLpSupply`].
+		LpSupplyCapExceeded,
+		/// The pool fee is above [`Config::MaxPoolFee`].
+		PoolFeeTooHigh,
 	}
 
 	#[pallet::hooks]
@@ -361,49 +445,24 @@ pub mod pallet {
 			asset2: Box<T::AssetKind>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
-
-			// prepare pool_id
-			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
-				.map_err(|_| Error::<T>::InvalidAssetPair)?;
-			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
-
-			let pool_account =
-				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
-
-			// pay the setup fee
-			let fee =
-				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
-			T::PoolSetupFeeTarget::on_unbalanced(fee);
-
-			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
-				T::Assets::touch(*asset1, &pool_account, &sender)?
-			};
-
-			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
-				T::Assets::touch(*asset2, &pool_account, &sender)?
-			};
-
-			let lp_token = NextPoolAssetId::<T>::get()
-				.or(T::PoolAssetId::initial_value())
-				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
-
-			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
-			T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?;
-
-			let pool_info = PoolInfo { lp_token: lp_token.clone() };
-			Pools::<T>::insert(pool_id.clone(), pool_info);
-
-			Self::deposit_event(Event::PoolCreated {
-				creator: sender,
-				pool_id,
-				pool_account,
-				lp_token,
-			});
+			Self::do_create_pool(sender, asset1, asset2, None)
+		}
 
-			Ok(())
+		/// Creates an empty liquidity pool like [`Pallet::create_pool`], locking `min_liquidity`
+		/// instead of [`Config::MintMinLiquidity`] with the first liquidity provided.
+		///
+		/// Useful for pairs of assets whose decimals call for a different minimum.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::create_pool().saturating_add(T::DbWeight::get().writes(1)))]
+		pub fn create_pool_with_min_liquidity(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			min_liquidity: T::Balance,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!min_liquidity.is_zero(), Error::<T>::ZeroLiquidity);
+			Self::do_create_pool(sender, asset1, asset2, Some(min_liquidity))
 		}
 
 		/// Provide liquidity into the pool of `asset1` and `asset2`.
@@ -474,12 +533,16 @@ pub mod pallet {
 				}
 			}
 
+			// The reserves after the deposit must still be representable.
+			let new_reserve1 = reserve1.checked_add(&amount1).ok_or(Error::<T>::Overflow)?;
+			let new_reserve2 = reserve2.checked_add(&amount2).ok_or(Error::<T>::Overflow)?;
+
 			ensure!(
-				amount1.saturating_add(reserve1) >= T::Assets::minimum_balance(*asset1.clone()),
+				new_reserve1 >= T::Assets::minimum_balance(*asset1.clone()),
 				Error::<T>::AmountOneLessThanMinimal
 			);
 			ensure!(
-				amount2.saturating_add(reserve2) >= T::Assets::minimum_balance(*asset2.clone()),
+				new_reserve2 >= T::Assets::minimum_balance(*asset2.clone()),
 				Error::<T>::AmountTwoLessThanMinimal
 			);
 
@@ -487,25 +550,27 @@ pub mod pallet {
 			T::Assets::transfer(*asset2, &sender, &pool_account, amount2, Preserve)?;
 
 			let total_supply = T::PoolAssets::total_issuance(pool.lp_token.clone());
+			let min_liquidity = Self::min_liquidity(&pool_id);
 
 			let lp_token_amount: T::Balance;
 			if total_supply.is_zero() {
-				lp_token_amount = Self::calc_lp_amount_for_zero_supply(&amount1, &amount2)?;
-				T::PoolAssets::mint_into(
-					pool.lp_token.clone(),
-					&pool_account,
-					T::MintMinLiquidity::get(),
-				)?;
+				lp_token_amount =
+					Self::calc_lp_amount_for_zero_supply(&amount1, &amount2, &min_liquidity)?;
+				T::PoolAssets::mint_into(pool.lp_token.clone(), &pool_account, min_liquidity)?;
 			} else {
 				let side1 = Self::mul_div(&amount1, &total_supply, &reserve1)?;
 				let side2 = Self::mul_div(&amount2, &total_supply, &reserve2)?;
 				lp_token_amount = side1.min(side2);
 			}
 
-			ensure!(
-				lp_token_amount > T::MintMinLiquidity::get(),
-				Error::<T>::InsufficientLiquidityMinted
-			);
+			ensure!(lp_token_amount > min_liquidity, Error::<T>::InsufficientLiquidityMinted);
+
+			if let Some(max_lp_supply) = PoolMaxLpSupply::<T>::get(&pool_id) {
+				let new_supply = T::PoolAssets::total_issuance(pool.lp_token.clone())
+					.checked_add(&lp_token_amount)
+					.ok_or(Error::<T>::Overflow)?;
+				ensure!(new_supply <= max_lp_supply, Error::<T>::LpSupplyCapExceeded);
+			}
 
 			T::PoolAssets::mint_into(pool.lp_token.clone(), &mint_to, lp_token_amount)?;
 
@@ -551,11 +616,12 @@ pub mod pallet {
 			let reserve2 = Self::get_balance(&pool_account, *asset2.clone());
 
 			let total_supply = T::PoolAssets::total_issuance(pool.lp_token.clone());
-			let withdrawal_fee_amount = T::LiquidityWithdrawalFee::get() * lp_token_burn;
-			let lp_redeem_amount = lp_token_burn.saturating_sub(withdrawal_fee_amount);
-
-			let amount1 = Self::mul_div(&lp_redeem_amount, &reserve1, &total_supply)?;
-			let amount2 = Self::mul_div(&lp_redeem_amount, &reserve2, &total_supply)?;
+			let (amount1, amount2) = Self::calc_liquidity_withdrawal(
+				&lp_token_burn,
+				&reserve1,
+				&reserve2,
+				&total_supply,
+			)?;
 
 			ensure!(
 				!amount1.is_zero() && amount1 >= amount1_min_receive,
@@ -651,6 +717,176 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Execute up to [`MAX_SWAP_BATCH_SIZE`] swaps in order, like
+		/// [`Pallet::swap_exact_tokens_for_tokens`] would, sending all of their output to
+		/// `send_to`.
+		///
+		/// Either all swaps succeed or none of them is applied. The weight is that of as many swaps
+		/// along the longest of the paths.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::swap_batch(
+			swaps.len() as u32,
+			swaps.iter().map(|swap| swap.path.len()).max().unwrap_or_default() as u32,
+		))]
+		pub fn swap_batch(
+			origin: OriginFor<T>,
+			swaps: BoundedVec<SwapRequest<T::AssetKind, T::Balance>, ConstU32<MAX_SWAP_BATCH_SIZE>>,
+			send_to: T::AccountId,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			for SwapRequest { path, amount_in, amount_out_min } in swaps {
+				Self::do_swap_exact_tokens_for_tokens(
+					sender.clone(),
+					path.into_iter().map(|a| *a).collect(),
+					amount_in,
+					Some(amount_out_min),
+					send_to.clone(),
+					keep_alive,
+				)?;
+			}
+			Ok(())
+		}
+
+		/// Cap the lp token supply of the pool of `asset1` and `asset2` at `max_lp_supply`, or
+		/// lift the cap if it is `None`.
+		///
+		/// [`Pallet::add_liquidity`] fails with [`Error::LpSupplyCapExceeded`] once it would take
+		/// the supply above the cap. Removing liquidity is unaffected, and a cap below the current
+		/// supply only blocks further additions.
+		///
+		/// Must be called by [`Config::MaxLpSupplyOrigin`].
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_max_lp_supply())]
+		pub fn set_max_lp_supply(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			max_lp_supply: Option<T::Balance>,
+		) -> DispatchResult {
+			T::MaxLpSupplyOrigin::ensure_origin(origin)?;
+
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(Pools::<T>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+
+			PoolMaxLpSupply::<T>::set(&pool_id, max_lp_supply);
+			Self::deposit_event(Event::MaxLpSupplySet { pool_id, max_lp_supply });
+			Ok(())
+		}
+
+		/// Provide liquidity into the pool of `asset1` and `asset2` holding only `asset1`.
+		///
+		/// The share of `amount` that balances the deposit is first swapped for `asset2` within
+		/// the same pool, then both are added like [`Pallet::add_liquidity`] would, with
+		/// `amount1_min`/`amount2_min` bounding the deposited amounts. If any step fails, the swap
+		/// is not applied either.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::zap_add_liquidity())]
+		pub fn zap_add_liquidity(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			amount: T::Balance,
+			amount1_min: T::Balance,
+			amount2_min: T::Balance,
+			mint_to: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin.clone())?;
+			ensure!(amount > Zero::zero(), Error::<T>::WrongDesiredAmount);
+
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(Pools::<T>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+			let pool_account =
+				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			let reserve1 = Self::get_balance(&pool_account, *asset1.clone());
+			let swap_amount =
+				Self::calc_zap_swap_amount(&amount, &reserve1, Self::pool_fee(&pool_id))?;
+			let amount2 = Self::do_swap_exact_tokens_for_tokens(
+				sender.clone(),
+				vec![*asset1.clone(), *asset2.clone()],
+				swap_amount,
+				None,
+				sender,
+				true,
+			)?;
+
+			Self::add_liquidity(
+				origin,
+				asset1,
+				asset2,
+				amount.checked_sub(&swap_amount).ok_or(Error::<T>::Overflow)?,
+				amount2,
+				amount1_min,
+				amount2_min,
+				mint_to,
+			)
+		}
+
+		/// Set the fee liquidity providers take of every swap through the pool of `asset1` and
+		/// `asset2`, in place of [`Config::LPFee`].
+		///
+		/// The fee may not exceed [`Config::MaxPoolFee`]. Must be called by
+		/// [`Config::PoolFeeOrigin`].
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_pool_fee())]
+		pub fn set_pool_fee(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			fee: Permill,
+		) -> DispatchResult {
+			T::PoolFeeOrigin::ensure_origin(origin)?;
+			ensure!(fee <= T::MaxPoolFee::get(), Error::<T>::PoolFeeTooHigh);
+
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(Pools::<T>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+
+			PoolFee::<T>::insert(&pool_id, fee);
+			Self::deposit_event(Event::PoolFeeSet { pool_id, fee });
+			Ok(())
+		}
+
+		/// Remove `percent` of the caller's liquidity from the pool of `asset1` and `asset2`.
+		///
+		/// The amount of LP tokens to burn is taken from the caller's balance, then removed like
+		/// [`Pallet::remove_liquidity`] would, with `amount1_min_receive`/`amount2_min_receive`
+		/// bounding the returned amounts. Removing 100% fully exits the position.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::remove_liquidity_percent())]
+		pub fn remove_liquidity_percent(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			percent: Permill,
+			amount1_min_receive: T::Balance,
+			amount2_min_receive: T::Balance,
+			withdraw_to: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin.clone())?;
+
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			let pool = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			let lp_balance =
+				T::PoolAssets::reducible_balance(pool.lp_token, &sender, Expendable, Polite);
+			let lp_token_burn = percent * lp_balance;
+
+			Self::remove_liquidity(
+				origin,
+				asset1,
+				asset2,
+				lp_token_burn,
+				amount1_min_receive,
+				amount2_min_receive,
+				withdraw_to,
+			)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -884,6 +1120,12 @@ pub mod pallet {
 			credit_in: CreditOf<T>,
 			path: &BalancePath<T>,
 		) -> Result<CreditOf<T>, (CreditOf<T>, DispatchError)> {
+			let pools_within_band = if T::TrackPriceMoves::get() {
+				Self::pools_within_price_band(path)
+			} else {
+				Vec::new()
+			};
+
 			let resolve_path = || -> Result<CreditOf<T>, DispatchError> {
 				for pos in 0..=path.len() {
 					if let Some([(asset1, _), (asset2, amount_out)]) = path.get(pos..=pos + 1) {
@@ -928,9 +1170,84 @@ pub mod pallet {
 			T::Assets::resolve(&pool_to, credit_in)
 				.map_err(|c| (c, Error::<T>::BelowMinimum.into()))?;
 
+			if T::TrackPoolVolume::get() {
+				Self::note_pool_volume(path);
+			}
+
+			Self::note_price_moves(path, pools_within_band);
+
 			Ok(credit_out)
 		}
 
+		/// Add the input amount of every hop of `path` to the [`PoolVolume`] of its pool.
+		fn note_pool_volume(path: &BalancePath<T>) {
+			for hop in path.windows(2) {
+				let ((asset_in, amount_in), (asset_out, _)) = (&hop[0], &hop[1]);
+				let Ok(pool_id) = T::PoolLocator::pool_id(asset_in, asset_out) else { continue };
+				let forward = Self::is_pool_order(&pool_id, asset_in, asset_out);
+				PoolVolume::<T>::mutate(pool_id, |(volume_forward, volume_backward)| {
+					let volume = if forward { volume_forward } else { volume_backward };
+					*volume = volume.saturating_add(*amount_in);
+				});
+			}
+		}
+
+		/// The hops of `path` whose pool has a price band under [`Config::OnPriceMove`] and a
+		/// price within it.
+		fn pools_within_price_band(path: &BalancePath<T>) -> Vec<(usize, T::PoolId)> {
+			path.windows(2)
+				.enumerate()
+				.filter_map(|(pos, hop)| {
+					let ((asset1, _), (asset2, _)) = (&hop[0], &hop[1]);
+					let pool_id = T::PoolLocator::pool_id(asset1, asset2).ok()?;
+					let (low, high) = T::OnPriceMove::price_band(&pool_id)?;
+					let price = Self::pool_price(&pool_id, asset1, asset2)?;
+					(low <= price && price <= high).then_some((pos, pool_id))
+				})
+				.collect()
+		}
+
+		/// Inform [`Config::OnPriceMove`] of the pools of `pools_within_band`, given by their hop
+		/// in `path`, whose price left their band.
+		fn note_price_moves(path: &BalancePath<T>, pools_within_band: Vec<(usize, T::PoolId)>) {
+			for (pos, pool_id) in pools_within_band {
+				let Some([(asset1, _), (asset2, _)]) = path.get(pos..=pos + 1) else { continue };
+				let Some((low, high)) = T::OnPriceMove::price_band(&pool_id) else { continue };
+				let Some(price) = Self::pool_price(&pool_id, asset1, asset2) else { continue };
+				if price < low || price > high {
+					T::OnPriceMove::on_price_move(&pool_id, price);
+				}
+			}
+		}
+
+		/// The price of the pool `pool_id` of `asset1` and `asset2`: the reserve of the second
+		/// asset of `pool_id` per unit of the reserve of its first asset.
+		fn pool_price(
+			pool_id: &T::PoolId,
+			asset1: &T::AssetKind,
+			asset2: &T::AssetKind,
+		) -> Option<FixedU128> {
+			let (reserve1, reserve2) = Self::get_reserves(asset1.clone(), asset2.clone()).ok()?;
+			let (reserve_first, reserve_second) = if Self::is_pool_order(pool_id, asset1, asset2) {
+				(reserve1, reserve2)
+			} else {
+				(reserve2, reserve1)
+			};
+			FixedU128::checked_from_rational(
+				reserve_second.saturated_into::<u128>(),
+				reserve_first.saturated_into::<u128>(),
+			)
+		}
+
+		/// Whether `asset1` and `asset2` are the first and the second asset of `pool_id`.
+		fn is_pool_order(
+			pool_id: &T::PoolId,
+			asset1: &T::AssetKind,
+			asset2: &T::AssetKind,
+		) -> bool {
+			*pool_id == T::PoolId::from((asset1.clone(), asset2.clone()))
+		}
+
 		/// Removes `value` balance of `asset` from `who` account if possible.
 		fn withdraw(
 			asset: T::AssetKind,
@@ -951,12 +1268,108 @@ pub mod pallet {
 			T::Assets::withdraw(asset, who, value, Exact, preservation, Polite)
 		}
 
+		/// Create a pool of `asset1` and `asset2` on behalf of `sender`, locking `min_liquidity`
+		/// with the first liquidity provided if given, or [`Config::MintMinLiquidity`] otherwise.
+		pub(crate) fn do_create_pool(
+			sender: T::AccountId,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			min_liquidity: Option<T::Balance>,
+		) -> DispatchResult {
+			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
+
+			// prepare pool_id
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
+
+			let pool_account =
+				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			// pay the setup fee
+			let fee =
+				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
+			T::PoolSetupFeeTarget::on_unbalanced(fee);
+
+			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
+				T::Assets::touch(*asset1, &pool_account, &sender)?
+			};
+
+			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
+				T::Assets::touch(*asset2, &pool_account, &sender)?
+			};
+
+			let lp_token = NextPoolAssetId::<T>::get()
+				.or(T::PoolAssetId::initial_value())
+				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
+
+			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
+			T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?;
+
+			let pool_info = PoolInfo { lp_token: lp_token.clone() };
+			Pools::<T>::insert(pool_id.clone(), pool_info);
+			if let Some(min_liquidity) = min_liquidity {
+				PoolMinLiquidity::<T>::insert(pool_id.clone(), min_liquidity);
+			}
+
+			Self::deposit_event(Event::PoolCreated {
+				creator: sender,
+				pool_id,
+				pool_account,
+				lp_token,
+			});
+
+			Ok(())
+		}
+
 		/// Get the `owner`'s balance of `asset`, which could be the chain's native asset or another
 		/// fungible. Returns a value in the form of an `Balance`.
 		fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
 			T::Assets::reducible_balance(asset, owner, Expendable, Polite)
 		}
 
+		/// The cumulative input volume of the pool of `asset1` and `asset2`, per direction.
+		///
+		/// The first element is the volume swapped from `asset1` to `asset2`, the second one the
+		/// volume swapped from `asset2` to `asset1`. Returns `None` if no such pool exists.
+		pub fn pool_volume(
+			asset1: &T::AssetKind,
+			asset2: &T::AssetKind,
+		) -> Option<(T::Balance, T::Balance)> {
+			let pool_id = T::PoolLocator::pool_id(asset1, asset2).ok()?;
+			if !Pools::<T>::contains_key(&pool_id) {
+				return None
+			}
+			let (volume_forward, volume_backward) = PoolVolume::<T>::get(&pool_id);
+			if Self::is_pool_order(&pool_id, asset1, asset2) {
+				Some((volume_forward, volume_backward))
+			} else {
+				Some((volume_backward, volume_forward))
+			}
+		}
+
+		/// The amount of lp tokens locked with the first liquidity provided to the pool.
+		pub fn min_liquidity(pool_id: &T::PoolId) -> T::Balance {
+			PoolMinLiquidity::<T>::get(pool_id).unwrap_or_else(T::MintMinLiquidity::get)
+		}
+
+		/// The canonical id of the pool of `asset1` and `asset2`, in either order, as determined by
+		/// [`Config::PoolLocator`].
+		///
+		/// Returns `None` if the pair isn't supported, e.g. for identical assets. The pool doesn't
+		/// have to exist.
+		pub fn pool_id_for(asset1: T::AssetKind, asset2: T::AssetKind) -> Option<T::PoolId> {
+			T::PoolLocator::pool_id(&asset1, &asset2).ok()
+		}
+
+		/// Whether a pool of `asset1` and `asset2` was created, in either order.
+		pub fn pool_exists(asset1: T::AssetKind, asset2: T::AssetKind) -> bool {
+			Self::pool_id_for(asset1, asset2)
+				.map_or(false, |pool_id| Pools::<T>::contains_key(pool_id))
+		}
+
 		/// Returns the balance of each asset in the pool.
 		/// The tuple result is in the order requested (not necessarily the same as pool order).
 		pub fn get_reserves(
@@ -994,8 +1407,11 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let fee = Self::pool_fee_for(asset1, &asset2)?;
 				balance_path.push((asset2, amount_in));
-				amount_in = Self::get_amount_in(&amount_in, &reserve_in, &reserve_out)?;
+				amount_in =
+					Self::get_amount_in_with_fee(&amount_in, &reserve_in, &reserve_out, fee)?;
+				ensure!(reserve_in.checked_add(&amount_in).is_some(), Error::<T>::Overflow);
 			}
 			balance_path.reverse();
 
@@ -1020,8 +1436,11 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let fee = Self::pool_fee_for(&asset1, asset2)?;
+				ensure!(reserve_in.checked_add(&amount_out).is_some(), Error::<T>::Overflow);
 				balance_path.push((asset1, amount_out));
-				amount_out = Self::get_amount_out(&amount_out, &reserve_in, &reserve_out)?;
+				amount_out =
+					Self::get_amount_out_with_fee(&amount_out, &reserve_in, &reserve_out, fee)?;
 			}
 			Ok(balance_path)
 		}
@@ -1034,12 +1453,13 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let fee = Self::pool_fee_for(&asset1, &asset2).ok()?;
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
 				if include_fee {
-					Self::get_amount_out(&amount, &balance1, &balance2).ok()
+					Self::get_amount_out_with_fee(&amount, &balance1, &balance2, fee).ok()
 				} else {
 					Self::quote(&amount, &balance1, &balance2).ok()
 				}
@@ -1056,12 +1476,13 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let fee = Self::pool_fee_for(&asset1, &asset2).ok()?;
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
 				if include_fee {
-					Self::get_amount_in(&amount, &balance1, &balance2).ok()
+					Self::get_amount_in_with_fee(&amount, &balance1, &balance2, fee).ok()
 				} else {
 					Self::quote(&amount, &balance2, &balance1).ok()
 				}
@@ -1070,6 +1491,163 @@ pub mod pallet {
 			}
 		}
 
+		/// Provides a quote of the amounts of `asset1` and `asset2` that
+		/// [`Pallet::remove_liquidity`] would withdraw when burning `lp_amount` of the pool's lp
+		/// token, net of the [`Config::LiquidityWithdrawalFee`].
+		///
+		/// Returns `None` if there is no pool for the pair or `lp_amount` exceeds the lp token
+		/// supply.
+		pub fn quote_liquidity_withdrawal(
+			asset1: T::AssetKind,
+			asset2: T::AssetKind,
+			lp_amount: T::Balance,
+		) -> Option<(T::Balance, T::Balance)> {
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).ok()?;
+			let pool = Pools::<T>::get(&pool_id)?;
+			let pool_account = T::PoolLocator::address(&pool_id).ok()?;
+
+			let total_supply = T::PoolAssets::total_issuance(pool.lp_token);
+			if lp_amount > total_supply {
+				return None
+			}
+
+			let reserve1 = Self::get_balance(&pool_account, asset1);
+			let reserve2 = Self::get_balance(&pool_account, asset2);
+			Self::calc_liquidity_withdrawal(&lp_amount, &reserve1, &reserve2, &total_supply).ok()
+		}
+
+		/// Returns a summary of up to `limit` pools, starting after the pool of the
+		/// `start_after` asset pair if given, or from the first pool otherwise. Nothing is returned
+		/// if there is no pool for the `start_after` pair.
+		///
+		/// `limit` is capped at [`MAX_POOL_SUMMARIES`]. Pools are returned in storage order, so
+		/// the assets of the last returned summary can be passed as `start_after` to fetch the
+		/// next page.
+		pub fn pool_summaries(
+			start_after: Option<(T::AssetKind, T::AssetKind)>,
+			limit: u32,
+		) -> Vec<PoolSummary<T::AssetKind, T::Balance>>
+		where
+			T::PoolId: Into<(T::AssetKind, T::AssetKind)>,
+		{
+			let limit = limit.min(MAX_POOL_SUMMARIES) as usize;
+			let pools = match start_after {
+				Some((asset1, asset2)) => match T::PoolLocator::pool_id(&asset1, &asset2) {
+					Ok(pool_id) if Pools::<T>::contains_key(&pool_id) =>
+						Pools::<T>::iter_from(Pools::<T>::hashed_key_for(pool_id)),
+					_ => return Vec::new(),
+				},
+				None => Pools::<T>::iter(),
+			};
+
+			pools
+				.filter_map(|(pool_id, pool)| {
+					let pool_account = T::PoolLocator::address(&pool_id).ok()?;
+					let lp_fee = Self::pool_fee(&pool_id);
+					let (asset1, asset2) = pool_id.into();
+					Some(PoolSummary {
+						reserve1: Self::get_balance(&pool_account, asset1.clone()),
+						reserve2: Self::get_balance(&pool_account, asset2.clone()),
+						lp_token_supply: T::PoolAssets::total_issuance(pool.lp_token),
+						lp_fee,
+						asset1,
+						asset2,
+					})
+				})
+				.take(limit)
+				.collect()
+		}
+
+		/// Returns up to `limit` assets which can be swapped to `native` directly, i.e. which have
+		/// a pool with `native` holding at least `min_native_reserve` of it and a non-zero reserve
+		/// of the asset. Pools with less native liquidity are skipped, as swapping a fee out of
+		/// them is bound to fail.
+		///
+		/// Assets are returned in the storage order of their pools, starting after the pool of the
+		/// `start_after` asset if given, or from the first pool otherwise. Nothing is returned if
+		/// there is no pool of `native` and the `start_after` asset. `limit` is capped at
+		/// [`MAX_ACCEPTABLE_FEE_ASSETS`].
+		pub fn acceptable_fee_assets(
+			native: T::AssetKind,
+			min_native_reserve: T::Balance,
+			start_after: Option<T::AssetKind>,
+			limit: u32,
+		) -> Vec<T::AssetKind>
+		where
+			T::PoolId: Into<(T::AssetKind, T::AssetKind)>,
+		{
+			let limit = limit.min(MAX_ACCEPTABLE_FEE_ASSETS) as usize;
+			let pools = match start_after {
+				Some(asset) => match T::PoolLocator::pool_id(&native, &asset) {
+					Ok(pool_id) if Pools::<T>::contains_key(&pool_id) =>
+						Pools::<T>::iter_from(Pools::<T>::hashed_key_for(pool_id)),
+					_ => return Vec::new(),
+				},
+				None => Pools::<T>::iter(),
+			};
+
+			pools
+				.filter_map(|(pool_id, _)| {
+					let pool_account = T::PoolLocator::address(&pool_id).ok()?;
+					let (asset1, asset2): (T::AssetKind, T::AssetKind) = pool_id.into();
+					let asset = if asset1 == native {
+						asset2
+					} else if asset2 == native {
+						asset1
+					} else {
+						return None
+					};
+					let native_reserve = Self::get_balance(&pool_account, native.clone());
+					let asset_reserve = Self::get_balance(&pool_account, asset.clone());
+					(native_reserve >= min_native_reserve && !asset_reserve.is_zero())
+						.then_some(asset)
+				})
+				.take(limit)
+				.collect()
+		}
+
+		/// Resolves a swap path from `asset1` to `asset2` through at most one intermediate asset.
+		///
+		/// The direct pool is used if it exists. Otherwise all two-hop paths are equally good, and
+		/// the intermediate asset with the lexicographically smallest SCALE encoding among those
+		/// having a pool with both `asset1` and `asset2` is chosen. The path therefore only
+		/// depends on the set of pools, not on the order they are stored in, so every node
+		/// resolves the same one. Returns `None` if the assets are not connected this way.
+		pub fn resolve_path(
+			asset1: &T::AssetKind,
+			asset2: &T::AssetKind,
+		) -> Option<Vec<T::AssetKind>>
+		where
+			T::PoolId: Into<(T::AssetKind, T::AssetKind)>,
+		{
+			let pool_exists = |asset1: &T::AssetKind, asset2: &T::AssetKind| {
+				T::PoolLocator::pool_id(asset1, asset2)
+					.map_or(false, |pool_id| Pools::<T>::contains_key(pool_id))
+			};
+
+			if asset1 == asset2 {
+				return None
+			}
+			if pool_exists(asset1, asset2) {
+				return Some(vec![asset1.clone(), asset2.clone()])
+			}
+
+			Pools::<T>::iter_keys()
+				.filter_map(|pool_id| {
+					let (pool_asset1, pool_asset2): (T::AssetKind, T::AssetKind) = pool_id.into();
+					if &pool_asset1 == asset1 {
+						Some(pool_asset2)
+					} else if &pool_asset2 == asset1 {
+						Some(pool_asset1)
+					} else {
+						None
+					}
+				})
+				.filter(|intermediate| intermediate != asset2 && pool_exists(intermediate, asset2))
+				.min_by_key(|intermediate| intermediate.encode())
+				.map(|intermediate| vec![asset1.clone(), intermediate, asset2.clone()])
+		}
+
 		/// Calculates the optimal amount from the reserves.
 		pub fn quote(
 			amount: &T::Balance,
@@ -1083,6 +1661,7 @@ pub mod pallet {
 		pub(super) fn calc_lp_amount_for_zero_supply(
 			amount1: &T::Balance,
 			amount2: &T::Balance,
+			min_liquidity: &T::Balance,
 		) -> Result<T::Balance, Error<T>> {
 			let amount1 = T::HigherPrecisionBalance::from(*amount1);
 			let amount2 = T::HigherPrecisionBalance::from(*amount2);
@@ -1091,12 +1670,76 @@ pub mod pallet {
 				.checked_mul(&amount2)
 				.ok_or(Error::<T>::Overflow)?
 				.integer_sqrt()
-				.checked_sub(&T::MintMinLiquidity::get().into())
+				.checked_sub(&(*min_liquidity).into())
 				.ok_or(Error::<T>::InsufficientLiquidityMinted)?;
 
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
 
+		/// Calculates how much of `amount` to swap into the pool with `reserve` of the same asset,
+		/// charging `fee`, so that the rest and the swap output are deposited in the pool's ratio.
+		///
+		/// Solves `(amount - s) / (r + s) = out(s) / (reserve_out - out(s))` for `s`, where
+		/// `r = reserve`, which with `g = 1_000_000 - fee` and `k = 2_000_000 - fee` in parts per
+		/// million gives `s = (sqrt((r * k)^2 + 4_000_000 * g * amount * r) - r * k) / (2 * g)`.
+		pub(super) fn calc_zap_swap_amount(
+			amount: &T::Balance,
+			reserve: &T::Balance,
+			fee: Permill,
+		) -> Result<T::Balance, Error<T>> {
+			let amount = T::HigherPrecisionBalance::from(*amount);
+			let reserve = T::HigherPrecisionBalance::from(*reserve);
+
+			if reserve.is_zero() {
+				return Err(Error::<T>::ZeroLiquidity)
+			}
+
+			let accuracy = T::HigherPrecisionBalance::from(Permill::ACCURACY);
+			let g = T::HigherPrecisionBalance::from(fee.left_from_one().deconstruct());
+			let k = accuracy + g;
+
+			let reserve_k = reserve.checked_mul(&k).ok_or(Error::<T>::Overflow)?;
+			let deposit_term = g
+				.checked_mul(&accuracy)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_mul(&4u32.into())
+				.ok_or(Error::<T>::Overflow)?
+				.checked_mul(&amount)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_mul(&reserve)
+				.ok_or(Error::<T>::Overflow)?;
+			let discriminant = reserve_k
+				.checked_mul(&reserve_k)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&deposit_term)
+				.ok_or(Error::<T>::Overflow)?;
+
+			let result = discriminant
+				.integer_sqrt()
+				.checked_sub(&reserve_k)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&g.checked_mul(&2u32.into()).ok_or(Error::<T>::Overflow)?)
+				.ok_or(Error::<T>::Overflow)?;
+
+			result.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		/// Calculates the amounts of both assets redeemed for burning `lp_token_burn`, net of the
+		/// [`Config::LiquidityWithdrawalFee`].
+		fn calc_liquidity_withdrawal(
+			lp_token_burn: &T::Balance,
+			reserve1: &T::Balance,
+			reserve2: &T::Balance,
+			total_supply: &T::Balance,
+		) -> Result<(T::Balance, T::Balance), Error<T>> {
+			let withdrawal_fee_amount = T::LiquidityWithdrawalFee::get() * *lp_token_burn;
+			let lp_redeem_amount = lp_token_burn.saturating_sub(withdrawal_fee_amount);
+
+			let amount1 = Self::mul_div(&lp_redeem_amount, reserve1, total_supply)?;
+			let amount2 = Self::mul_div(&lp_redeem_amount, reserve2, total_supply)?;
+			Ok((amount1, amount2))
+		}
+
 		fn mul_div(a: &T::Balance, b: &T::Balance, c: &T::Balance) -> Result<T::Balance, Error<T>> {
 			let a = T::HigherPrecisionBalance::from(*a);
 			let b = T::HigherPrecisionBalance::from(*b);
@@ -1114,11 +1757,21 @@ pub mod pallet {
 		/// Calculates amount out.
 		///
 		/// Given an input amount of an asset and pair reserves, returns the maximum output amount
-		/// of the other asset.
+		/// of the other asset, net of [`Config::LPFee`].
 		pub fn get_amount_out(
 			amount_in: &T::Balance,
 			reserve_in: &T::Balance,
 			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			Self::get_amount_out_with_fee(amount_in, reserve_in, reserve_out, Self::default_fee())
+		}
+
+		/// Calculates amount out, net of `fee`. See [`Pallet::get_amount_out`].
+		pub fn get_amount_out_with_fee(
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+			fee: Permill,
 		) -> Result<T::Balance, Error<T>> {
 			let amount_in = T::HigherPrecisionBalance::from(*amount_in);
 			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
@@ -1129,14 +1782,14 @@ pub mod pallet {
 			}
 
 			let amount_in_with_fee = amount_in
-				.checked_mul(&(T::HigherPrecisionBalance::from(1000u32) - (T::LPFee::get().into())))
+				.checked_mul(&fee.left_from_one().deconstruct().into())
 				.ok_or(Error::<T>::Overflow)?;
 
 			let numerator =
 				amount_in_with_fee.checked_mul(&reserve_out).ok_or(Error::<T>::Overflow)?;
 
 			let denominator = reserve_in
-				.checked_mul(&1000u32.into())
+				.checked_mul(&Permill::ACCURACY.into())
 				.ok_or(Error::<T>::Overflow)?
 				.checked_add(&amount_in_with_fee)
 				.ok_or(Error::<T>::Overflow)?;
@@ -1149,11 +1802,21 @@ pub mod pallet {
 		/// Calculates amount in.
 		///
 		/// Given an output amount of an asset and pair reserves, returns a required input amount
-		/// of the other asset.
+		/// of the other asset, including [`Config::LPFee`].
 		pub fn get_amount_in(
 			amount_out: &T::Balance,
 			reserve_in: &T::Balance,
 			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			Self::get_amount_in_with_fee(amount_out, reserve_in, reserve_out, Self::default_fee())
+		}
+
+		/// Calculates amount in, including `fee`. See [`Pallet::get_amount_in`].
+		pub fn get_amount_in_with_fee(
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+			fee: Permill,
 		) -> Result<T::Balance, Error<T>> {
 			let amount_out = T::HigherPrecisionBalance::from(*amount_out);
 			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
@@ -1170,13 +1833,13 @@ pub mod pallet {
 			let numerator = reserve_in
 				.checked_mul(&amount_out)
 				.ok_or(Error::<T>::Overflow)?
-				.checked_mul(&1000u32.into())
+				.checked_mul(&Permill::ACCURACY.into())
 				.ok_or(Error::<T>::Overflow)?;
 
 			let denominator = reserve_out
 				.checked_sub(&amount_out)
 				.ok_or(Error::<T>::Overflow)?
-				.checked_mul(&(T::HigherPrecisionBalance::from(1000u32) - T::LPFee::get().into()))
+				.checked_mul(&fee.left_from_one().deconstruct().into())
 				.ok_or(Error::<T>::Overflow)?;
 
 			let result = numerator
@@ -1188,6 +1851,24 @@ pub mod pallet {
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
 
+		/// The fee liquidity providers take of every swap through the pool `pool_id`: the one set
+		/// with [`Pallet::set_pool_fee`], or [`Config::LPFee`] otherwise.
+		pub fn pool_fee(pool_id: &T::PoolId) -> Permill {
+			PoolFee::<T>::get(pool_id).unwrap_or_else(Self::default_fee)
+		}
+
+		/// The fee of the pool of `asset1` and `asset2`, see [`Pallet::pool_fee`].
+		fn pool_fee_for(asset1: &T::AssetKind, asset2: &T::AssetKind) -> Result<Permill, Error<T>> {
+			let pool_id = T::PoolLocator::pool_id(asset1, asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			Ok(Self::pool_fee(&pool_id))
+		}
+
+		/// [`Config::LPFee`] as a [`Permill`].
+		fn default_fee() -> Permill {
+			Permill::from_perthousand(T::LPFee::get())
+		}
+
 		/// Ensure that a path is valid.
 		fn validate_swap_path(path: &Vec<T::AssetKind>) -> Result<(), DispatchError> {
 			ensure!(path.len() >= 2, Error::<T>::InvalidPath);
@@ -1222,6 +1903,7 @@ pub mod pallet {
 sp_api::decl_runtime_apis! {
 	/// This runtime api allows people to query the size of the liquidity pools
 	/// and quote prices for swaps.
+	#[api_version(2)]
 	pub trait AssetConversionApi<Balance, AssetId>
 	where
 		Balance: frame_support::traits::tokens::Balance + MaybeDisplay,
@@ -1251,6 +1933,28 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Returns a summary of up to `limit` pools, starting after the pool of the
+		/// `start_after` asset pair. See [`Pallet::pool_summaries`].
+		///
+		/// All summaries are read from the state of the block the call is made at.
+		#[api_version(2)]
+		fn pool_summaries(
+			start_after: Option<(AssetId, AssetId)>,
+			limit: u32,
+		) -> Vec<PoolSummary<AssetId, Balance>>;
+
+		/// Returns up to `limit` assets which can currently be used to pay fees, starting after
+		/// the `start_after` asset. See [`Pallet::acceptable_fee_assets`].
+		///
+		/// Only assets with a pool to the native asset holding a usable amount of native
+		/// liquidity are returned.
+		#[api_version(2)]
+		fn acceptable_fee_assets(start_after: Option<AssetId>, limit: u32) -> Vec<AssetId>;
+
+		/// Returns whether a pool exists for the given asset pair, in either order.
+		#[api_version(2)]
+		fn pool_exists(asset1: AssetId, asset2: AssetId) -> bool;
 	}
 }
 
@@ -29,7 +29,7 @@ use frame_support::{
 			fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
 			imbalance::ResolveAssetTo,
 		},
-		AsEnsureOriginWithArg, ConstU128, ConstU32,
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32,
 	},
 	PalletId,
 };
@@ -130,6 +130,22 @@ parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
 	pub storage LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	pub const MaxPoolFee: Permill = Permill::from_percent(10);
+	pub storage PriceBand: Option<(FixedU128, FixedU128)> = None;
+	pub storage PriceMoves: Vec<(<Test as Config>::PoolId, FixedU128)> = Vec::new();
+}
+
+/// Watches the price of every pool within [`PriceBand`], recording its moves in [`PriceMoves`].
+pub struct RecordPriceMoves;
+impl OnPoolPriceMove<<Test as Config>::PoolId> for RecordPriceMoves {
+	fn price_band(_: &<Test as Config>::PoolId) -> Option<(FixedU128, FixedU128)> {
+		PriceBand::get()
+	}
+	fn on_price_move(pool_id: &<Test as Config>::PoolId, price: FixedU128) {
+		let mut moves = PriceMoves::get();
+		moves.push((pool_id.clone(), price));
+		PriceMoves::set(&moves);
+	}
 }
 
 ord_parameter_types! {
@@ -154,11 +170,17 @@ impl Config for Test {
 	type PoolSetupFeeAsset = Native;
 	type PoolSetupFeeTarget = ResolveAssetTo<AssetConversionOrigin, Self::Assets>;
 	type PalletId = AssetConversionPalletId;
+	type MaxLpSupplyOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type PoolFeeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
 	type LPFee = ConstU32<3>; // means 0.3%
+	type MaxPoolFee = MaxPoolFee;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = ConstU32<4>;
 	type MintMinLiquidity = ConstU128<100>; // 100 is good enough when the main currency has 12 decimals.
+	type TrackPoolVolume = ConstBool<true>;
+	type TrackPriceMoves = ConstBool<true>;
+	type OnPriceMove = RecordPriceMoves;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
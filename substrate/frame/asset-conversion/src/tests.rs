@@ -18,6 +18,7 @@
 use crate::{mock::*, *};
 use frame_support::{
 	assert_noop, assert_ok, assert_storage_noop,
+	dispatch::GetDispatchInfo,
 	instances::Instance1,
 	traits::{
 		fungible,
@@ -415,6 +416,365 @@ fn can_add_liquidity() {
 	});
 }
 
+#[test]
+fn pool_min_liquidity_overrides_mint_min_liquidity() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_noop!(
+			AssetConversion::create_pool_with_min_liquidity(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				0
+			),
+			Error::<Test>::ZeroLiquidity
+		);
+		let lp_token1 = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool_with_min_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			200
+		));
+		let lp_token2 = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_3.clone())
+		));
+
+		let pool_id1 = (token_1.clone(), token_2.clone());
+		let pool_id2 = (token_1.clone(), token_3.clone());
+		assert_eq!(AssetConversion::min_liquidity(&pool_id1), 200);
+		assert_eq!(AssetConversion::min_liquidity(&pool_id2), 100);
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000 * 2 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 1000));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			10,
+			10000,
+			10,
+			user,
+		));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_3.clone()),
+			10000,
+			10,
+			10000,
+			10,
+			user,
+		));
+
+		let pool_account1 = <Test as Config>::PoolLocator::address(&pool_id1).unwrap();
+		let pool_account2 = <Test as Config>::PoolLocator::address(&pool_id2).unwrap();
+		assert_eq!(pool_balance(pool_account1, lp_token1), 200);
+		assert_eq!(pool_balance(user, lp_token1), 116);
+		assert_eq!(pool_balance(pool_account2, lp_token2), 100);
+		assert_eq!(pool_balance(user, lp_token2), 216);
+	});
+}
+
+#[test]
+fn max_lp_supply_caps_add_liquidity() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		assert_noop!(
+			AssetConversion::set_max_lp_supply(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				Some(632)
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetConversion::set_max_lp_supply(
+				RuntimeOrigin::root(),
+				Box::new(token_1.clone()),
+				Box::new(token_3.clone()),
+				Some(632)
+			),
+			Error::<Test>::PoolNotFound
+		);
+		// twice the supply of the first liquidity added below.
+		assert_ok!(AssetConversion::set_max_lp_supply(
+			RuntimeOrigin::root(),
+			Box::new(token_2.clone()),
+			Box::new(token_1.clone()),
+			Some(632)
+		));
+		assert_eq!(
+			events().last(),
+			Some(&Event::<Test>::MaxLpSupplySet { pool_id, max_lp_supply: Some(632) })
+		);
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000 * 3 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+
+		let add_liquidity = || {
+			AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				10000,
+				10,
+				10000,
+				10,
+				user,
+			)
+		};
+
+		// up to the cap.
+		assert_ok!(add_liquidity());
+		assert_eq!(pool_balance(user, lp_token), 216);
+		assert_ok!(add_liquidity());
+		assert_eq!(pool_balance(user, lp_token), 216 + 316);
+		assert_eq!(<Test as Config>::PoolAssets::total_issuance(lp_token), 632);
+
+		// beyond it.
+		assert_noop!(add_liquidity(), Error::<Test>::LpSupplyCapExceeded);
+
+		// liquidity can still be removed.
+		assert_ok!(AssetConversion::remove_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			100,
+			1,
+			1,
+			user,
+		));
+		assert_eq!(pool_balance(user, lp_token), 216 + 316 - 100);
+		assert_eq!(<Test as Config>::PoolAssets::total_issuance(lp_token), 532);
+	});
+}
+
+#[test]
+fn fee_math_has_headroom_for_near_max_reserves() {
+	use sp_core::U256;
+
+	new_test_ext().execute_with(|| {
+		// Fees are applied in parts per million. With `U256` as the higher precision balance this
+		// covers the full `u128` range of reserves for amounts up to 2^100, with the same results
+		// as the per mille scale used before for per mille fees.
+		let reserve = u128::MAX;
+		let amount = 1u128 << 100;
+
+		let expected_out = U256::from(amount) * 997u32 * U256::from(reserve) /
+			(U256::from(reserve) * 1000u32 + U256::from(amount) * 997u32);
+		assert_eq!(
+			AssetConversion::get_amount_out(&amount, &reserve, &reserve).ok(),
+			Some(expected_out.as_u128())
+		);
+
+		let expected_in = U256::from(reserve) * U256::from(amount) * 1000u32 /
+			((U256::from(reserve) - U256::from(amount)) * 997u32) +
+			1u32;
+		assert_eq!(
+			AssetConversion::get_amount_in(&amount, &reserve, &reserve).ok(),
+			Some(expected_in.as_u128())
+		);
+
+		// A zap squares the scaled reserve, which leaves room for reserves up to 2^100.
+		assert_ok!(AssetConversion::calc_zap_swap_amount(
+			&amount,
+			&(1u128 << 100),
+			Permill::from_perthousand(3)
+		));
+	});
+}
+
+#[test]
+fn set_pool_fee_changes_swap_prices() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let pool_id = (token_1.clone(), token_2.clone());
+		let fee = Permill::from_percent(1);
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 10000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			10000,
+			1,
+			1,
+			user,
+		));
+
+		assert_noop!(
+			AssetConversion::set_pool_fee(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				fee
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetConversion::set_pool_fee(
+				RuntimeOrigin::root(),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				Permill::from_percent(11)
+			),
+			Error::<Test>::PoolFeeTooHigh
+		);
+		assert_noop!(
+			AssetConversion::set_pool_fee(
+				RuntimeOrigin::root(),
+				Box::new(token_1.clone()),
+				Box::new(token_3.clone()),
+				fee
+			),
+			Error::<Test>::PoolNotFound
+		);
+
+		// 0.3% by default.
+		assert_eq!(AssetConversion::pool_fee(&pool_id), Permill::from_perthousand(3));
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_1.clone(),
+				token_2.clone(),
+				1000,
+				true
+			),
+			Some(906)
+		);
+
+		assert_ok!(AssetConversion::set_pool_fee(
+			RuntimeOrigin::root(),
+			Box::new(token_2.clone()),
+			Box::new(token_1.clone()),
+			fee
+		));
+		assert_eq!(
+			events().last(),
+			Some(&Event::<Test>::PoolFeeSet { pool_id: pool_id.clone(), fee })
+		);
+		assert_eq!(AssetConversion::pool_fee(&pool_id), fee);
+
+		// 1000 * 0.99 * 10000 / (10000 + 1000 * 0.99)
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_1.clone(),
+				token_2.clone(),
+				1000,
+				true
+			),
+			Some(900)
+		);
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			1000,
+			1,
+			user,
+			true,
+		));
+		assert_eq!(balance(user, token_2.clone()), 900);
+		assert_eq!(balance(user, token_1.clone()), 9000 + ed);
+	});
+}
+
+#[test]
+fn add_liquidity_near_max_balance_fails_with_overflow() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 40000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, u128::MAX));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			u128::MAX / 2,
+			1,
+			1,
+			user,
+		));
+
+		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
+		let reserves =
+			(balance(pool_account, token_1.clone()), balance(pool_account, token_2.clone()));
+		assert_eq!(reserves, (10000, u128::MAX / 2));
+
+		// Matching three times the native reserve would require more than `u128::MAX` of the
+		// second asset.
+		assert_noop!(
+			AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				30000,
+				u128::MAX / 2,
+				1,
+				1,
+				user,
+			),
+			Error::<Test>::Overflow
+		);
+		assert_eq!(
+			(balance(pool_account, token_1.clone()), balance(pool_account, token_2.clone())),
+			reserves
+		);
+	});
+}
+
 #[test]
 fn add_tiny_liquidity_leads_to_insufficient_liquidity_minted_error() {
 	new_test_ext().execute_with(|| {
@@ -504,45 +864,384 @@ fn add_tiny_liquidity_directly_to_pool_address() {
 			user,
 		));
 
-		// check the same but for token_3.clone() (non-native token)
+		// check the same but for token_3.clone() (non-native token)
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_3.clone()),
+			10000,
+			10,
+			10000,
+			10,
+			user,
+		));
+	});
+}
+
+#[test]
+fn can_remove_liquidity() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		create_tokens(user, vec![token_2.clone()]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed_token_1 = <Balances as fungible::Inspect<_>>::minimum_balance();
+		let ed_token_2 = <Assets as fungibles::Inspect<_>>::minimum_balance(2);
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			user,
+			10000000000 + ed_token_1
+		));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 100000 + ed_token_2));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			1000000000,
+			100000,
+			1000000000,
+			100000,
+			user,
+		));
+
+		let total_lp_received = pool_balance(user, lp_token);
+		LiquidityWithdrawalFee::set(&Permill::from_percent(10));
+
+		assert_ok!(AssetConversion::remove_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			total_lp_received,
+			0,
+			0,
+			user,
+		));
+
+		assert!(events().contains(&Event::<Test>::LiquidityRemoved {
+			who: user,
+			withdraw_to: user,
+			pool_id: pool_id.clone(),
+			amount1: 899991000,
+			amount2: 89999,
+			lp_token,
+			lp_token_burned: total_lp_received,
+			withdrawal_fee: <Test as Config>::LiquidityWithdrawalFee::get()
+		}));
+
+		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
+		assert_eq!(balance(pool_account, token_1.clone()), 100009000);
+		assert_eq!(balance(pool_account, token_2.clone()), 10001);
+		assert_eq!(pool_balance(pool_account, lp_token), 100);
+
+		assert_eq!(
+			balance(user, token_1.clone()),
+			10000000000 - 1000000000 + 899991000 + ed_token_1
+		);
+		assert_eq!(balance(user, token_2.clone()), 89999 + ed_token_2);
+		assert_eq!(pool_balance(user, lp_token), 0);
+	});
+}
+
+#[test]
+fn can_remove_liquidity_by_percent() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		create_tokens(user, vec![token_2.clone()]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 2000000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000000));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			1000000,
+			1000000,
+			1,
+			1,
+			user,
+		));
+		assert_eq!(pool_balance(user, lp_token), 999900);
+
+		assert_noop!(
+			AssetConversion::remove_liquidity_percent(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				Permill::from_percent(50),
+				499951,
+				0,
+				user
+			),
+			Error::<Test>::AssetOneWithdrawalDidNotMeetMinimum
+		);
+
+		assert_ok!(AssetConversion::remove_liquidity_percent(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			Permill::from_percent(50),
+			499950,
+			499950,
+			user,
+		));
+
+		assert_eq!(pool_balance(user, lp_token), 499950);
+		assert_eq!(balance(user, token_1.clone()), 1000000 + 499950 + ed);
+		assert_eq!(balance(user, token_2.clone()), 499950);
+
+		// Removing everything that is left exits the position.
+		assert_ok!(AssetConversion::remove_liquidity_percent(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			Permill::from_percent(100),
+			0,
+			0,
+			user,
+		));
+
+		assert_eq!(pool_balance(user, lp_token), 0);
+		assert_eq!(balance(user, token_1.clone()), 2000000 - 100 + ed);
+		assert_eq!(balance(user, token_2.clone()), 1000000 - 100);
+
+		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
+		assert_eq!(balance(pool_account, token_1.clone()), 100);
+		assert_eq!(balance(pool_account, token_2.clone()), 100);
+
+		assert_noop!(
+			AssetConversion::remove_liquidity_percent(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				Permill::from_percent(100),
+				0,
+				0,
+				user
+			),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn quote_liquidity_withdrawal_matches_execution() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed_token_1 = <Balances as fungible::Inspect<_>>::minimum_balance();
+		let ed_token_2 = <Assets as fungibles::Inspect<_>>::minimum_balance(2);
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			user,
+			10000000000 + ed_token_1
+		));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 100000 + ed_token_2));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			1000000000,
+			100000,
+			1000000000,
+			100000,
+			user,
+		));
+
+		let lp_to_burn = pool_balance(user, lp_token) / 2;
+		LiquidityWithdrawalFee::set(&Permill::from_percent(10));
+
+		// no pool, or more lp tokens than minted
+		assert_eq!(
+			AssetConversion::quote_liquidity_withdrawal(token_1.clone(), token_3.clone(), 1),
+			None
+		);
+		let total_supply = <Test as Config>::PoolAssets::total_issuance(lp_token);
+		assert_eq!(
+			AssetConversion::quote_liquidity_withdrawal(
+				token_1.clone(),
+				token_2.clone(),
+				total_supply + 1
+			),
+			None
+		);
+
+		let (quoted1, quoted2) = AssetConversion::quote_liquidity_withdrawal(
+			token_1.clone(),
+			token_2.clone(),
+			lp_to_burn,
+		)
+		.unwrap();
+
+		let user_balance1 = balance(user, token_1.clone());
+		let user_balance2 = balance(user, token_2.clone());
+		assert_ok!(AssetConversion::remove_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			lp_to_burn,
+			0,
+			0,
+			user,
+		));
+
+		assert!(events().contains(&Event::<Test>::LiquidityRemoved {
+			who: user,
+			withdraw_to: user,
+			pool_id,
+			amount1: quoted1,
+			amount2: quoted2,
+			lp_token,
+			lp_token_burned: lp_to_burn,
+			withdrawal_fee: <Test as Config>::LiquidityWithdrawalFee::get()
+		}));
+		assert_eq!(balance(user, token_1.clone()), user_balance1 + quoted1);
+		assert_eq!(balance(user, token_2.clone()), user_balance2 + quoted2);
+	});
+}
+
+#[test]
+fn pool_summaries_works() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+
+		assert_eq!(AssetConversion::pool_summaries(None, 10), vec![]);
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_3.clone())
+		));
+
+		let ed_token_1 = <Balances as fungible::Inspect<_>>::minimum_balance();
+		let ed_token_2 = <Assets as fungibles::Inspect<_>>::minimum_balance(2);
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			user,
+			10000000000 + ed_token_1
+		));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 100000 + ed_token_2));
+
 		assert_ok!(AssetConversion::add_liquidity(
 			RuntimeOrigin::signed(user),
 			Box::new(token_1.clone()),
-			Box::new(token_3.clone()),
-			10000,
-			10,
-			10000,
-			10,
+			Box::new(token_2.clone()),
+			1000000000,
+			100000,
+			1000000000,
+			100000,
 			user,
 		));
+
+		let summaries = AssetConversion::pool_summaries(None, 10);
+		assert_eq!(summaries.len(), 2);
+		assert!(summaries.contains(&PoolSummary {
+			asset1: token_1.clone(),
+			asset2: token_2.clone(),
+			reserve1: 1000000000,
+			reserve2: 100000,
+			lp_token_supply: <Test as Config>::PoolAssets::total_issuance(lp_token),
+			lp_fee: Permill::from_perthousand(3),
+		}));
+		assert!(summaries.contains(&PoolSummary {
+			asset1: token_1.clone(),
+			asset2: token_3.clone(),
+			reserve1: 0,
+			reserve2: 0,
+			lp_token_supply: 0,
+			lp_fee: Permill::from_perthousand(3),
+		}));
+
+		// paging through the pools one by one yields the same summaries.
+		let first_page = AssetConversion::pool_summaries(None, 1);
+		assert_eq!(first_page, vec![summaries[0].clone()]);
+		let start_after = Some((first_page[0].asset1.clone(), first_page[0].asset2.clone()));
+		assert_eq!(AssetConversion::pool_summaries(start_after, 10), vec![summaries[1].clone()]);
+
+		// unknown asset pairs yield no summaries.
+		assert_eq!(AssetConversion::pool_summaries(Some((token_2, token_3)), 10), vec![]);
 	});
 }
 
 #[test]
-fn can_remove_liquidity() {
+fn acceptable_fee_assets_works() {
 	new_test_ext().execute_with(|| {
 		let user = 1;
 		let token_1 = NativeOrWithId::Native;
 		let token_2 = NativeOrWithId::WithId(2);
-		let pool_id = (token_1.clone(), token_2.clone());
-
-		create_tokens(user, vec![token_2.clone()]);
-		let lp_token = AssetConversion::get_next_pool_asset_id();
-		assert_ok!(AssetConversion::create_pool(
-			RuntimeOrigin::signed(user),
-			Box::new(token_1.clone()),
-			Box::new(token_2.clone())
-		));
+		let token_3 = NativeOrWithId::WithId(3);
+		let token_4 = NativeOrWithId::WithId(4);
+
+		assert_eq!(AssetConversion::acceptable_fee_assets(token_1.clone(), 100, None, 10), vec![]);
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone(), token_4.clone()]);
+		for (asset1, asset2) in [
+			(token_1.clone(), token_2.clone()),
+			(token_1.clone(), token_3.clone()),
+			(token_1.clone(), token_4.clone()),
+			(token_2.clone(), token_3.clone()),
+		] {
+			assert_ok!(AssetConversion::create_pool(
+				RuntimeOrigin::signed(user),
+				Box::new(asset1),
+				Box::new(asset2)
+			));
+		}
 
 		let ed_token_1 = <Balances as fungible::Inspect<_>>::minimum_balance();
-		let ed_token_2 = <Assets as fungibles::Inspect<_>>::minimum_balance(2);
 		assert_ok!(Balances::force_set_balance(
 			RuntimeOrigin::root(),
 			user,
 			10000000000 + ed_token_1
 		));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 100000 + ed_token_2));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 1000000));
 
+		// plenty of native liquidity.
 		assert_ok!(AssetConversion::add_liquidity(
 			RuntimeOrigin::signed(user),
 			Box::new(token_1.clone()),
@@ -553,42 +1252,192 @@ fn can_remove_liquidity() {
 			100000,
 			user,
 		));
-
-		let total_lp_received = pool_balance(user, lp_token);
-		LiquidityWithdrawalFee::set(&Permill::from_percent(10));
-
-		assert_ok!(AssetConversion::remove_liquidity(
+		// hardly any native liquidity.
+		assert_ok!(AssetConversion::add_liquidity(
 			RuntimeOrigin::signed(user),
 			Box::new(token_1.clone()),
+			Box::new(token_3.clone()),
+			200,
+			100000,
+			200,
+			100000,
+			user,
+		));
+		// no path to native, despite the liquidity.
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
 			Box::new(token_2.clone()),
-			total_lp_received,
-			0,
-			0,
+			Box::new(token_3.clone()),
+			1000,
+			1000,
+			1000,
+			1000,
 			user,
 		));
 
-		assert!(events().contains(&Event::<Test>::LiquidityRemoved {
-			who: user,
-			withdraw_to: user,
-			pool_id: pool_id.clone(),
-			amount1: 899991000,
-			amount2: 89999,
-			lp_token,
-			lp_token_burned: total_lp_received,
-			withdrawal_fee: <Test as Config>::LiquidityWithdrawalFee::get()
-		}));
+		assert_eq!(
+			AssetConversion::acceptable_fee_assets(token_1.clone(), 1000, None, 10),
+			vec![token_2.clone()]
+		);
 
-		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
-		assert_eq!(balance(pool_account, token_1.clone()), 100009000);
-		assert_eq!(balance(pool_account, token_2.clone()), 10001);
-		assert_eq!(pool_balance(pool_account, lp_token), 100);
+		let assets = AssetConversion::acceptable_fee_assets(token_1.clone(), 100, None, 10);
+		assert_eq!(assets.len(), 2);
+		assert!(assets.contains(&token_2));
+		assert!(assets.contains(&token_3));
 
+		// paging through the assets one by one yields the same assets.
+		let first_page = AssetConversion::acceptable_fee_assets(token_1.clone(), 100, None, 1);
+		assert_eq!(first_page, vec![assets[0].clone()]);
 		assert_eq!(
-			balance(user, token_1.clone()),
-			10000000000 - 1000000000 + 899991000 + ed_token_1
+			AssetConversion::acceptable_fee_assets(
+				token_1.clone(),
+				100,
+				Some(first_page[0].clone()),
+				10
+			),
+			vec![assets[1].clone()]
 		);
-		assert_eq!(balance(user, token_2.clone()), 89999 + ed_token_2);
-		assert_eq!(pool_balance(user, lp_token), 0);
+
+		// assets without a pool to native yield no assets.
+		assert_eq!(
+			AssetConversion::acceptable_fee_assets(
+				token_1,
+				100,
+				Some(NativeOrWithId::WithId(5)),
+				10,
+			),
+			vec![]
+		);
+	});
+}
+
+#[test]
+fn resolve_path_prefers_smallest_intermediate() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let token_4 = NativeOrWithId::WithId(4);
+		let token_5 = NativeOrWithId::WithId(5);
+		let token_6 = NativeOrWithId::WithId(6);
+
+		create_tokens(
+			user,
+			vec![
+				token_2.clone(),
+				token_3.clone(),
+				token_4.clone(),
+				token_5.clone(),
+				token_6.clone(),
+			],
+		);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000));
+
+		// two equally good paths from token 2 to token 3, through token 5 and token 4.
+		for (asset1, asset2) in [
+			(token_2.clone(), token_5.clone()),
+			(token_5.clone(), token_3.clone()),
+			(token_2.clone(), token_4.clone()),
+			(token_4.clone(), token_3.clone()),
+		] {
+			assert_ok!(AssetConversion::create_pool(
+				RuntimeOrigin::signed(user),
+				Box::new(asset1),
+				Box::new(asset2)
+			));
+		}
+
+		assert_eq!(
+			AssetConversion::resolve_path(&token_2, &token_3),
+			Some(vec![token_2.clone(), token_4.clone(), token_3.clone()])
+		);
+		assert_eq!(
+			AssetConversion::resolve_path(&token_3, &token_2),
+			Some(vec![token_3.clone(), token_4.clone(), token_2.clone()])
+		);
+		assert_eq!(AssetConversion::resolve_path(&token_2, &token_6), None);
+
+		// the direct pool is preferred once it exists.
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone())
+		));
+		assert_eq!(
+			AssetConversion::resolve_path(&token_2, &token_3),
+			Some(vec![token_2.clone(), token_3.clone()])
+		);
+	});
+}
+
+#[test]
+fn pool_id_for_is_order_independent() {
+	new_test_ext().execute_with(|| {
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+
+		// the native asset always comes first.
+		assert_eq!(
+			AssetConversion::pool_id_for(token_2.clone(), token_1.clone()),
+			Some((token_1.clone(), token_2.clone()))
+		);
+		assert_eq!(
+			AssetConversion::pool_id_for(token_1.clone(), token_2.clone()),
+			Some((token_1.clone(), token_2.clone()))
+		);
+
+		// other pairs are in ascending order.
+		assert_eq!(
+			AssetConversion::pool_id_for(token_3.clone(), token_2.clone()),
+			Some((token_2.clone(), token_3.clone()))
+		);
+		assert_eq!(
+			AssetConversion::pool_id_for(token_2.clone(), token_3.clone()),
+			Some((token_2.clone(), token_3.clone()))
+		);
+
+		// no pool has to exist for the id to be known.
+		assert!(!AssetConversion::pool_exists(token_2.clone(), token_3.clone()));
+
+		assert_eq!(AssetConversion::pool_id_for(token_1.clone(), token_1.clone()), None);
+		assert_eq!(AssetConversion::pool_id_for(token_2.clone(), token_2.clone()), None);
+	});
+}
+
+#[test]
+fn pool_exists_in_either_order() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let token_4 = NativeOrWithId::WithId(4);
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone(), token_4.clone()]);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000));
+
+		// a pool anchored to the native asset and one of two non-native assets.
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_1.clone())
+		));
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_3.clone()),
+			Box::new(token_2.clone())
+		));
+
+		assert!(AssetConversion::pool_exists(token_1.clone(), token_2.clone()));
+		assert!(AssetConversion::pool_exists(token_2.clone(), token_1.clone()));
+		assert!(AssetConversion::pool_exists(token_2.clone(), token_3.clone()));
+		assert!(AssetConversion::pool_exists(token_3.clone(), token_2.clone()));
+
+		assert!(!AssetConversion::pool_exists(token_1.clone(), token_3.clone()));
+		assert!(!AssetConversion::pool_exists(token_4.clone(), token_3.clone()));
+		assert!(!AssetConversion::pool_exists(token_3.clone(), token_4.clone()));
+		assert!(!AssetConversion::pool_exists(token_2.clone(), token_2.clone()));
 	});
 }
 
@@ -868,12 +1717,129 @@ fn can_quote_price() {
 }
 
 #[test]
-fn quote_price_exact_tokens_for_tokens_matches_execution() {
+fn quote_price_exact_tokens_for_tokens_matches_execution() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let user2 = 2;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			200,
+			1,
+			1,
+			user,
+		));
+
+		let amount = 1;
+		let quoted_price = 49;
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_2.clone(),
+				token_1.clone(),
+				amount,
+				true,
+			),
+			Some(quoted_price)
+		);
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user2, amount));
+		let prior_dot_balance = 20000;
+		assert_eq!(prior_dot_balance, balance(user2, token_1.clone()));
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user2),
+			bvec![token_2.clone(), token_1.clone()],
+			amount,
+			1,
+			user2,
+			false,
+		));
+
+		assert_eq!(prior_dot_balance + quoted_price, balance(user2, token_1.clone()));
+	});
+}
+
+#[test]
+fn quote_price_tokens_for_exact_tokens_matches_execution() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let user2 = 2;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			200,
+			1,
+			1,
+			user,
+		));
+
+		let amount = 49;
+		let quoted_price = 1;
+		assert_eq!(
+			AssetConversion::quote_price_tokens_for_exact_tokens(
+				token_2.clone(),
+				token_1.clone(),
+				amount,
+				true,
+			),
+			Some(quoted_price)
+		);
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user2, amount));
+		let prior_dot_balance = 20000;
+		assert_eq!(prior_dot_balance, balance(user2, token_1.clone()));
+		let prior_asset_balance = 49;
+		assert_eq!(prior_asset_balance, balance(user2, token_2.clone()));
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(user2),
+			bvec![token_2.clone(), token_1.clone()],
+			amount,
+			1,
+			user2,
+			false,
+		));
+
+		assert_eq!(prior_dot_balance + amount, balance(user2, token_1.clone()));
+		assert_eq!(prior_asset_balance - quoted_price, balance(user2, token_2.clone()));
+	});
+}
+
+#[test]
+fn pool_volume_accumulates_for_all_swaps() {
 	new_test_ext().execute_with(|| {
 		let user = 1;
-		let user2 = 2;
 		let token_1 = NativeOrWithId::Native;
 		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
 
 		create_tokens(user, vec![token_2.clone()]);
 		assert_ok!(AssetConversion::create_pool(
@@ -881,8 +1847,10 @@ fn quote_price_exact_tokens_for_tokens_matches_execution() {
 			Box::new(token_1.clone()),
 			Box::new(token_2.clone())
 		));
+		assert_eq!(AssetConversion::pool_volume(&token_1, &token_2), Some((0, 0)));
 
-		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
 		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
 
 		assert_ok!(AssetConversion::add_liquidity(
@@ -896,41 +1864,40 @@ fn quote_price_exact_tokens_for_tokens_matches_execution() {
 			user,
 		));
 
-		let amount = 1;
-		let quoted_price = 49;
-		assert_eq!(
-			AssetConversion::quote_price_exact_tokens_for_tokens(
-				token_2.clone(),
-				token_1.clone(),
-				amount,
-				true,
-			),
-			Some(quoted_price)
-		);
-
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user2, amount));
-		let prior_dot_balance = 20000;
-		assert_eq!(prior_dot_balance, balance(user2, token_1.clone()));
+		// a user swap from `token_2` to `token_1`.
 		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
-			RuntimeOrigin::signed(user2),
+			RuntimeOrigin::signed(user),
 			bvec![token_2.clone(), token_1.clone()],
-			amount,
+			100,
 			1,
-			user2,
+			user,
 			false,
 		));
+		assert_eq!(PoolVolume::<Test>::get(&pool_id), (0, 100));
 
-		assert_eq!(prior_dot_balance + quoted_price, balance(user2, token_1.clone()));
+		// a credit swap from `token_1` to `token_2`, as done by the fee adapters.
+		let credit_in = NativeAndAssets::issue(token_1.clone(), 500);
+		assert!(<AssetConversion as SwapCredit<_>>::swap_exact_tokens_for_tokens(
+			vec![token_1.clone(), token_2.clone()],
+			credit_in,
+			None,
+		)
+		.is_ok());
+		assert_eq!(PoolVolume::<Test>::get(&pool_id), (500, 100));
+
+		assert_eq!(AssetConversion::pool_volume(&token_1, &token_2), Some((500, 100)));
+		assert_eq!(AssetConversion::pool_volume(&token_2, &token_1), Some((100, 500)));
+		assert_eq!(AssetConversion::pool_volume(&token_1, &NativeOrWithId::WithId(3)), None);
 	});
 }
 
 #[test]
-fn quote_price_tokens_for_exact_tokens_matches_execution() {
+fn price_moves_out_of_band_are_reported() {
 	new_test_ext().execute_with(|| {
 		let user = 1;
-		let user2 = 2;
 		let token_1 = NativeOrWithId::Native;
 		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
 
 		create_tokens(user, vec![token_2.clone()]);
 		assert_ok!(AssetConversion::create_pool(
@@ -939,48 +1906,60 @@ fn quote_price_tokens_for_exact_tokens_matches_execution() {
 			Box::new(token_2.clone())
 		));
 
-		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 20000));
 
+		// a stable pool, expected to stay within 5% of its peg.
 		assert_ok!(AssetConversion::add_liquidity(
 			RuntimeOrigin::signed(user),
 			Box::new(token_1.clone()),
 			Box::new(token_2.clone()),
 			10000,
-			200,
+			10000,
 			1,
 			1,
 			user,
 		));
+		let low = FixedU128::from_rational(95, 100);
+		let high = FixedU128::from_rational(105, 100);
+		PriceBand::set(&Some((low, high)));
 
-		let amount = 49;
-		let quoted_price = 1;
-		assert_eq!(
-			AssetConversion::quote_price_tokens_for_exact_tokens(
-				token_2.clone(),
-				token_1.clone(),
-				amount,
-				true,
-			),
-			Some(quoted_price)
-		);
+		// a small swap keeps the price within the band.
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			10,
+			1,
+			user,
+			false,
+		));
+		assert!(PriceMoves::get().is_empty());
 
-		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user2, amount));
-		let prior_dot_balance = 20000;
-		assert_eq!(prior_dot_balance, balance(user2, token_1.clone()));
-		let prior_asset_balance = 49;
-		assert_eq!(prior_asset_balance, balance(user2, token_2.clone()));
-		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
-			RuntimeOrigin::signed(user2),
-			bvec![token_2.clone(), token_1.clone()],
-			amount,
+		// a large swap moves the price past the band.
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			1000,
 			1,
-			user2,
+			user,
 			false,
 		));
+		let moves = PriceMoves::get();
+		assert_eq!(moves.len(), 1);
+		assert_eq!(moves[0].0, pool_id);
+		assert!(moves[0].1 < low);
 
-		assert_eq!(prior_dot_balance + amount, balance(user2, token_1.clone()));
-		assert_eq!(prior_asset_balance - quoted_price, balance(user2, token_2.clone()));
+		// further swaps out of the band aren't reported again.
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			100,
+			1,
+			user,
+			false,
+		));
+		assert_eq!(PriceMoves::get().len(), 1);
 	});
 }
 
@@ -1873,6 +2852,240 @@ fn swap_exact_tokens_for_tokens_in_multi_hops() {
 	});
 }
 
+#[test]
+fn swap_batch_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 10000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 10000));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			200,
+			1,
+			1,
+			user,
+		));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone()),
+			200,
+			2000,
+			1,
+			1,
+			user,
+		));
+
+		let swap =
+			|path, amount_in, amount_out_min| SwapRequest { path, amount_in, amount_out_min };
+
+		// The last swap can't get the minimum out, so the first one is not applied either.
+		assert_noop!(
+			AssetConversion::swap_batch(
+				RuntimeOrigin::signed(user),
+				vec![
+					swap(bvec![token_1.clone(), token_2.clone()], 500, 1),
+					swap(bvec![token_2.clone(), token_3.clone()], 5, 1000),
+				]
+				.try_into()
+				.unwrap(),
+				user,
+				true,
+			),
+			Error::<Test>::ProvidedMinimumNotSufficientForSwap
+		);
+
+		let expect_out2 = AssetConversion::get_amount_out(&500, &10000, &200).ok().unwrap();
+		let expect_out3 = AssetConversion::get_amount_out(&5, &200, &2000).ok().unwrap();
+		let balance2 = balance(user, token_2.clone());
+		let balance3 = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_batch(
+			RuntimeOrigin::signed(user),
+			vec![
+				swap(bvec![token_1.clone(), token_2.clone()], 500, 1),
+				swap(bvec![token_2.clone(), token_3.clone()], 5, 1),
+			]
+			.try_into()
+			.unwrap(),
+			user,
+			true,
+		));
+		assert_eq!(balance(user, token_2.clone()), balance2 + expect_out2 - 5);
+		assert_eq!(balance(user, token_3.clone()), balance3 + expect_out3);
+
+		// The batch is weighed by its longest path.
+		let call = Call::<Test>::swap_batch {
+			swaps: vec![
+				swap(bvec![token_1.clone(), token_2.clone()], 1, 1),
+				swap(bvec![token_1.clone(), token_2.clone(), token_3.clone()], 1, 1),
+			]
+			.try_into()
+			.unwrap(),
+			send_to: user,
+			keep_alive: true,
+		};
+		assert_eq!(call.get_dispatch_info().weight, <() as WeightInfo>::swap_batch(2, 3));
+	});
+}
+#[test]
+fn zap_add_liquidity_deposits_a_balanced_position() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		create_tokens(user, vec![token_2.clone()]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 11000));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			10000,
+			1,
+			1,
+			user,
+		));
+		let lp_balance = pool_balance(user, lp_token);
+		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
+
+		// 488 of the token 2 are swapped for 463 native, and the remaining 512 match those 463 up
+		// to rounding.
+		assert_eq!(
+			AssetConversion::calc_zap_swap_amount(&1000, &10000, Permill::from_perthousand(3))
+				.ok()
+				.unwrap(),
+			488
+		);
+		assert_ok!(AssetConversion::zap_add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_1.clone()),
+			1000,
+			500,
+			450,
+			user,
+		));
+
+		assert!(events().contains(&Event::<Test>::LiquidityAdded {
+			who: user,
+			mint_to: user,
+			pool_id,
+			amount1_provided: 509,
+			amount2_provided: 463,
+			lp_token,
+			lp_token_minted: 485,
+		}));
+		assert_eq!(pool_balance(user, lp_token), lp_balance + 485);
+		// All of the native out of the swap is deposited, and only 3 of the token 2 are left.
+		assert_eq!(balance(user, token_1.clone()), ed);
+		assert_eq!(balance(user, token_2.clone()), 3);
+		assert_eq!(balance(pool_account, token_1.clone()), 10000);
+		assert_eq!(balance(pool_account, token_2.clone()), 10997);
+	});
+}
+
+#[test]
+fn zap_add_liquidity_rolls_back_the_swap_if_minimums_are_not_met() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		create_tokens(user, vec![token_2.clone()]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 11000));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			10000,
+			1,
+			1,
+			user,
+		));
+		let lp_balance = pool_balance(user, lp_token);
+		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
+
+		// Only 509 of the token 2 can be deposited after the swap.
+		assert_noop!(
+			AssetConversion::zap_add_liquidity(
+				RuntimeOrigin::signed(user),
+				Box::new(token_2.clone()),
+				Box::new(token_1.clone()),
+				1000,
+				510,
+				1,
+				user,
+			),
+			Error::<Test>::AssetOneDepositDidNotMeetMinimum
+		);
+
+		assert_eq!(pool_balance(user, lp_token), lp_balance);
+		assert_eq!(balance(user, token_1.clone()), ed);
+		assert_eq!(balance(user, token_2.clone()), 1000);
+		assert_eq!(balance(pool_account, token_1.clone()), 10000);
+		assert_eq!(balance(pool_account, token_2.clone()), 10000);
+
+		assert_noop!(
+			AssetConversion::zap_add_liquidity(
+				RuntimeOrigin::signed(user),
+				Box::new(token_2.clone()),
+				Box::new(token_1.clone()),
+				0,
+				1,
+				1,
+				user,
+			),
+			Error::<Test>::WrongDesiredAmount
+		);
+	});
+}
+
 #[test]
 fn swap_tokens_for_exact_tokens_in_multi_hops() {
 	new_test_ext().execute_with(|| {
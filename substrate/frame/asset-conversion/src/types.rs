@@ -19,6 +19,7 @@ use super::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use scale_info::TypeInfo;
+use sp_runtime::{FixedU128, Permill, RuntimeDebug};
 
 /// Represents a swap path with associated asset amounts indicating how much of the asset needs to
 /// be deposited to get the following asset's amount withdrawn (this is inclusive of fees).
@@ -40,6 +41,35 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// Summary of a liquidity pool's state, as returned by [`Pallet::pool_summaries`].
+#[derive(Decode, Encode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PoolSummary<AssetKind, Balance> {
+	/// First asset of the pool, in the order of its `PoolId`.
+	pub asset1: AssetKind,
+	/// Second asset of the pool, in the order of its `PoolId`.
+	pub asset2: AssetKind,
+	/// Reserve of `asset1` held by the pool.
+	pub reserve1: Balance,
+	/// Reserve of `asset2` held by the pool.
+	pub reserve2: Balance,
+	/// Total issuance of the pool's lp token.
+	pub lp_token_supply: Balance,
+	/// The fee liquidity providers take of every swap, see [`Pallet::pool_fee`].
+	pub lp_fee: Permill,
+}
+
+/// A single swap of a [`Pallet::swap_batch`], swapping exactly `amount_in` of the first asset of
+/// `path` for at least `amount_out_min` of its last asset.
+#[derive(Decode, Encode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SwapRequest<AssetKind, Balance> {
+	/// The assets to swap along, see [`Pallet::swap_exact_tokens_for_tokens`].
+	pub path: Vec<Box<AssetKind>>,
+	/// The amount of the first asset of `path` to swap.
+	pub amount_in: Balance,
+	/// The minimum amount of the last asset of `path` to receive.
+	pub amount_out_min: Balance,
+}
+
 /// Provides means to resolve the `PoolId` and `AccountId` from a pair of assets.
 ///
 /// Resulting `PoolId` remains consistent whether the asset pair is presented as (asset1, asset2)
@@ -131,3 +161,22 @@ where
 		First::address(id).or(Second::address(id))
 	}
 }
+
+/// Handler of swaps moving the price of a pool out of the band it's expected to stay within, e.g.
+/// a pool of two stablecoins losing its peg.
+///
+/// The price of a pool is the reserve of the second asset of its `PoolId` per unit of the reserve
+/// of its first asset.
+pub trait OnPoolPriceMove<PoolId> {
+	/// The lowest and highest price of `pool_id` within its band, if its price is watched.
+	fn price_band(pool_id: &PoolId) -> Option<(FixedU128, FixedU128)>;
+	/// Called after a swap moved the price of `pool_id` from within its band to `price`, out of it.
+	fn on_price_move(pool_id: &PoolId, price: FixedU128);
+}
+
+impl<PoolId> OnPoolPriceMove<PoolId> for () {
+	fn price_band(_: &PoolId) -> Option<(FixedU128, FixedU128)> {
+		None
+	}
+	fn on_price_move(_: &PoolId, _: FixedU128) {}
+}
@@ -56,6 +56,11 @@ pub trait WeightInfo {
 	fn remove_liquidity() -> Weight;
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight;
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight;
+	fn swap_batch(n: u32, p: u32, ) -> Weight;
+	fn set_max_lp_supply() -> Weight;
+	fn zap_add_liquidity() -> Weight;
+	fn set_pool_fee() -> Weight;
+	fn remove_liquidity_percent() -> Weight;
 }
 
 /// Weights for `pallet_asset_conversion` using the Substrate node and recommended hardware.
@@ -94,13 +99,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:2 w:2)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMaxLpSupply` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolMaxLpSupply` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMinLiquidity` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolMinLiquidity` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn add_liquidity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1507`
 		//  Estimated: `11426`
 		// Minimum execution time: 138_424_000 picoseconds.
 		Weight::from_parts(142_083_000, 11426)
-			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
 			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
@@ -126,6 +135,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolVolume` (r:3 w:3)
+	/// Proof: `AssetConversion::PoolVolume` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolFee` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolFee` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -138,11 +151,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+			// Placeholder for the volume of every hop, recorded when `TrackPoolVolume` is enabled.
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(n.saturating_sub(1).into()))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(n.saturating_sub(1).into()))
+			// Placeholder for the reserves of every hop, read before and after the swap when
+			// `TrackPriceMoves` is enabled.
+			.saturating_add(T::DbWeight::get().reads(8_u64).saturating_mul(n.saturating_sub(1).into()))
+			// Placeholder for the fee of every hop.
+			.saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(n.saturating_sub(1).into()))
+			.saturating_add(Weight::from_parts(0, 2505).saturating_mul(n.saturating_sub(1).into()))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolVolume` (r:3 w:3)
+	/// Proof: `AssetConversion::PoolVolume` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolFee` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolFee` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -155,6 +181,98 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+			// Placeholder for the volume of every hop, recorded when `TrackPoolVolume` is enabled.
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(n.saturating_sub(1).into()))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(n.saturating_sub(1).into()))
+			// Placeholder for the reserves of every hop, read before and after the swap when
+			// `TrackPriceMoves` is enabled.
+			.saturating_add(T::DbWeight::get().reads(8_u64).saturating_mul(n.saturating_sub(1).into()))
+			// Placeholder for the fee of every hop.
+			.saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(n.saturating_sub(1).into()))
+			.saturating_add(Weight::from_parts(0, 2505).saturating_mul(n.saturating_sub(1).into()))
+	}
+	/// Storage: `Assets::Asset` (r:4 w:4)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:8 w:8)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolVolume` (r:96 w:96)
+	/// Proof: `AssetConversion::PoolVolume` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolFee` (r:96 w:0)
+	/// Proof: `AssetConversion::PoolFee` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	/// The range of component `p` is `[2, 4]`.
+	fn swap_batch(n: u32, p: u32, ) -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(3_912_000, 990)
+			.saturating_add(Weight::from_parts(121_437_528, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(10_581_054, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads((12_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((12_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 20872).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+			// Placeholder for the fee of every hop.
+			.saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+			.saturating_add(Weight::from_parts(0, 2505).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMaxLpSupply` (r:0 w:1)
+	/// Proof: `AssetConversion::PoolMaxLpSupply` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn set_max_lp_supply() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(15_906_000, 3495)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMaxLpSupply` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolMaxLpSupply` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:1 w:1)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:1 w:1)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMinLiquidity` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolMinLiquidity` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn zap_add_liquidity() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(220_318_000, 11426)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolFee` (r:0 w:1)
+	/// Proof: `AssetConversion::PoolFee` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	fn set_pool_fee() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(15_541_000, 3495)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:2 w:2)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:4 w:4)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:1 w:1)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:1 w:1)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn remove_liquidity_percent() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(127_645_000, 11426)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 }
 
@@ -193,13 +311,17 @@ impl WeightInfo for () {
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:2 w:2)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMaxLpSupply` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolMaxLpSupply` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMinLiquidity` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolMinLiquidity` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn add_liquidity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1507`
 		//  Estimated: `11426`
 		// Minimum execution time: 138_424_000 picoseconds.
 		Weight::from_parts(142_083_000, 11426)
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
@@ -225,6 +347,10 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolVolume` (r:3 w:3)
+	/// Proof: `AssetConversion::PoolVolume` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolFee` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolFee` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -237,11 +363,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+			// Placeholder for the volume of every hop, recorded when `TrackPoolVolume` is enabled.
+			.saturating_add(RocksDbWeight::get().reads_writes(1, 1).saturating_mul(n.saturating_sub(1).into()))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(n.saturating_sub(1).into()))
+			// Placeholder for the reserves of every hop, read before and after the swap when
+			// `TrackPriceMoves` is enabled.
+			.saturating_add(RocksDbWeight::get().reads(8_u64).saturating_mul(n.saturating_sub(1).into()))
+			// Placeholder for the fee of every hop.
+			.saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(n.saturating_sub(1).into()))
+			.saturating_add(Weight::from_parts(0, 2505).saturating_mul(n.saturating_sub(1).into()))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolVolume` (r:3 w:3)
+	/// Proof: `AssetConversion::PoolVolume` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolFee` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolFee` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -254,5 +393,97 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+			// Placeholder for the volume of every hop, recorded when `TrackPoolVolume` is enabled.
+			.saturating_add(RocksDbWeight::get().reads_writes(1, 1).saturating_mul(n.saturating_sub(1).into()))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(n.saturating_sub(1).into()))
+			// Placeholder for the reserves of every hop, read before and after the swap when
+			// `TrackPriceMoves` is enabled.
+			.saturating_add(RocksDbWeight::get().reads(8_u64).saturating_mul(n.saturating_sub(1).into()))
+			// Placeholder for the fee of every hop.
+			.saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(n.saturating_sub(1).into()))
+			.saturating_add(Weight::from_parts(0, 2505).saturating_mul(n.saturating_sub(1).into()))
+	}
+	/// Storage: `Assets::Asset` (r:4 w:4)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:8 w:8)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolVolume` (r:96 w:96)
+	/// Proof: `AssetConversion::PoolVolume` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolFee` (r:96 w:0)
+	/// Proof: `AssetConversion::PoolFee` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	/// The range of component `p` is `[2, 4]`.
+	fn swap_batch(n: u32, p: u32, ) -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(3_912_000, 990)
+			.saturating_add(Weight::from_parts(121_437_528, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(10_581_054, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((12_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 20872).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads_writes(1, 1).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+			// Placeholder for the fee of every hop.
+			.saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+			.saturating_add(Weight::from_parts(0, 2505).saturating_mul(n.saturating_mul(p.saturating_sub(1)).into()))
+	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMaxLpSupply` (r:0 w:1)
+	/// Proof: `AssetConversion::PoolMaxLpSupply` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn set_max_lp_supply() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(15_906_000, 3495)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMaxLpSupply` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolMaxLpSupply` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:1 w:1)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:1 w:1)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolMinLiquidity` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolMinLiquidity` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn zap_add_liquidity() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(220_318_000, 11426)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolFee` (r:0 w:1)
+	/// Proof: `AssetConversion::PoolFee` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	fn set_pool_fee() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(15_541_000, 3495)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:2 w:2)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:4 w:4)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:1 w:1)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:1 w:1)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn remove_liquidity_percent() -> Weight {
+		// Placeholder, not generated by the benchmark CLI. Regenerate before relying on it.
+		Weight::from_parts(127_645_000, 11426)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 }
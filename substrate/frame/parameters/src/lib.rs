@@ -140,6 +140,10 @@ type KeyOf<T> = <<T as Config>::RuntimeParameters as AggregratedKeyValue>::Key;
 /// The value type of a parameter.
 type ValueOf<T> = <<T as Config>::RuntimeParameters as AggregratedKeyValue>::Value;
 
+/// The retained changes of a single parameter.
+type HistoryOf<T> =
+	BoundedVec<(BlockNumberFor<T>, Option<ValueOf<T>>), <T as Config>::HistoryDepth>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -163,6 +167,13 @@ pub mod pallet {
 		#[pallet::no_default_bounds]
 		type AdminOrigin: EnsureOriginWithArg<Self::RuntimeOrigin, KeyOf<Self>>;
 
+		/// The number of past values that are retained per parameter key.
+		///
+		/// Setting this to zero disables the history and [`Pallet::get_at`] will always return
+		/// `None`.
+		#[pallet::constant]
+		type HistoryDepth: Get<u32>;
+
 		/// Weight information for extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -188,6 +199,14 @@ pub mod pallet {
 	pub type Parameters<T: Config> =
 		StorageMap<_, Blake2_128Concat, KeyOf<T>, ValueOf<T>, OptionQuery>;
 
+	/// The most recent changes of each parameter, oldest first.
+	///
+	/// Each entry records the block in which the value was set and the value itself. At most
+	/// [`Config::HistoryDepth`] entries are kept per key.
+	#[pallet::storage]
+	pub type ParameterHistory<T: Config> =
+		StorageMap<_, Blake2_128Concat, KeyOf<T>, HistoryOf<T>, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -198,7 +217,9 @@ pub mod pallet {
 		/// The dispatch origin of this call must be `AdminOrigin` for the given `key`. Values be
 		/// deleted by setting them to `None`.
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::set_parameter())]
+		#[pallet::weight(
+			T::WeightInfo::set_parameter().saturating_add(Pallet::<T>::note_history_weight())
+		)]
 		pub fn set_parameter(
 			origin: OriginFor<T>,
 			key_value: T::RuntimeParameters,
@@ -211,12 +232,62 @@ pub mod pallet {
 				old = v.clone();
 				*v = new.clone();
 			});
+			Self::note_history(&key, new.clone());
 
 			Self::deposit_event(Event::Updated { key, old_value: old, new_value: new });
 
 			Ok(())
 		}
 	}
+
+	impl<T: Config> Pallet<T> {
+		/// The value that `key` had at the end of `block`.
+		///
+		/// Returns `None` if the parameter was unset at that block or if `block` predates the
+		/// retained history.
+		pub fn get_at(key: KeyOf<T>, block: BlockNumberFor<T>) -> Option<ValueOf<T>> {
+			ParameterHistory::<T>::get(key)
+				.into_iter()
+				.rev()
+				.find(|(at, _)| *at <= block)
+				.and_then(|(_, value)| value)
+		}
+
+		/// The weight of [`Self::note_history`], which is not covered by [`Config::WeightInfo`].
+		///
+		/// One read and write of [`ParameterHistory`], with a proof size that grows with
+		/// [`Config::HistoryDepth`]. Zero if the history is disabled.
+		pub fn note_history_weight() -> Weight {
+			if T::HistoryDepth::get() == 0 {
+				return Weight::zero()
+			}
+
+			// `Blake2_128Concat` hash, the key itself and the full history of that key.
+			let proof_size = 16 + KeyOf::<T>::max_encoded_len() + HistoryOf::<T>::max_encoded_len();
+			T::DbWeight::get()
+				.reads_writes(1, 1)
+				.saturating_add(Weight::from_parts(0, proof_size as u64))
+		}
+
+		/// Record `value` as the value of `key` in the current block.
+		///
+		/// Does nothing if the history is disabled. Evicts the oldest entry once the history is
+		/// full.
+		fn note_history(key: &KeyOf<T>, value: Option<ValueOf<T>>) {
+			if T::HistoryDepth::get() == 0 {
+				return
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ParameterHistory::<T>::mutate(key, |history| {
+				if history.last().map_or(false, |(at, _)| *at == now) {
+					history.pop();
+				}
+				history.force_push((now, value));
+			});
+		}
+	}
+
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
@@ -239,6 +310,8 @@ pub mod pallet {
 				frame_system::EnsureRoot<Self::AccountId>,
 			>;
 
+			type HistoryDepth = ConstU32<0>;
+
 			type WeightInfo = ();
 		}
 	}
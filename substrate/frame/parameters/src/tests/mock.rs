@@ -22,7 +22,7 @@
 use frame_support::{
 	construct_runtime, derive_impl,
 	dynamic_params::{dynamic_pallet_params, dynamic_params},
-	traits::EnsureOriginWithArg,
+	traits::{ConstU32, EnsureOriginWithArg},
 };
 
 use crate as pallet_parameters;
@@ -115,6 +115,7 @@ mod custom_origin {
 #[derive_impl(pallet_parameters::config_preludes::TestDefaultConfig as pallet_parameters::DefaultConfig)]
 impl Config for Runtime {
 	type AdminOrigin = custom_origin::ParamsManager;
+	type HistoryDepth = ConstU32<3>;
 	// RuntimeParameters is injected by the `derive_impl` macro.
 	// RuntimeEvent is injected by the `derive_impl` macro.
 	// WeightInfo is injected by the `derive_impl` macro.
@@ -22,7 +22,7 @@
 use crate::tests::mock::{
 	assert_last_event, dynamic_params::*, new_test_ext, PalletParameters, Runtime,
 	RuntimeOrigin as Origin, RuntimeParameters, RuntimeParameters::*, RuntimeParametersKey,
-	RuntimeParametersValue,
+	RuntimeParametersValue, System,
 };
 use codec::Encode;
 use frame_support::{assert_noop, assert_ok, traits::dynamic_params::AggregratedKeyValue};
@@ -227,6 +227,57 @@ fn test_define_parameters_key_convert() {
 	assert_eq!(parameter_key, pallet1::ParametersKey::Key2(key2));
 }
 
+#[test]
+fn get_at_returns_historical_values() {
+	new_test_ext().execute_with(|| {
+		let key = RuntimeParametersKey::Pallet1(pallet1::ParametersKey::Key3(pallet1::Key3));
+		let value = |v| Some(RuntimeParametersValue::Pallet1(pallet1::ParametersValue::Key3(v)));
+		let set = |v| {
+			assert_ok!(PalletParameters::set_parameter(
+				Origin::root(),
+				Pallet1(pallet1::Parameters::Key3(pallet1::Key3, v)),
+			));
+		};
+
+		set(Some(123));
+		System::set_block_number(3);
+		set(Some(432));
+		// Only the last change within a block is retained.
+		set(Some(543));
+		System::set_block_number(5);
+		set(None);
+
+		assert_eq!(PalletParameters::get_at(key.clone(), 0), None);
+		assert_eq!(PalletParameters::get_at(key.clone(), 2), value(123));
+		assert_eq!(PalletParameters::get_at(key.clone(), 3), value(543));
+		assert_eq!(PalletParameters::get_at(key.clone(), 4), value(543));
+		assert_eq!(PalletParameters::get_at(key.clone(), 5), None);
+
+		// The oldest entry is evicted once the history is full.
+		System::set_block_number(7);
+		set(Some(654));
+		assert_eq!(crate::ParameterHistory::<Runtime>::get(&key).len(), 3);
+		assert_eq!(PalletParameters::get_at(key.clone(), 2), None);
+		assert_eq!(PalletParameters::get_at(key.clone(), 4), value(543));
+		assert_eq!(PalletParameters::get_at(key, 7), value(654));
+	});
+}
+
+#[test]
+fn set_parameter_weight_covers_history() {
+	use crate::WeightInfo;
+	use frame_support::dispatch::GetDispatchInfo;
+
+	let kv = Pallet1(pallet1::Parameters::Key1(pallet1::Key1, Some(123)));
+	let weight = crate::Call::<Runtime>::set_parameter { key_value: kv }
+		.get_dispatch_info()
+		.weight;
+	let history = PalletParameters::note_history_weight();
+
+	assert!(history.proof_size() > 0);
+	assert_eq!(weight, <() as WeightInfo>::set_parameter().saturating_add(history));
+}
+
 #[test]
 fn test_define_parameters_value_convert() {
 	let value1 = pallet1::Key1Value(1);
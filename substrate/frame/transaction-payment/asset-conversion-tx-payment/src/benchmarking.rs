@@ -97,7 +97,7 @@ mod benchmarks {
 		<T as Config>::BenchmarkHelper::setup_balances_and_pool(fun_asset_id, caller.clone());
 
 		let tip = 10u64.into();
-		let ext: ChargeAssetTxPayment<T> = ChargeAssetTxPayment::from(tip, Some(asset_id));
+		let ext: ChargeAssetTxPayment<T> = ChargeAssetTxPayment::from(tip, Some(asset_id.clone()));
 		let inner = frame_system::Call::remark { remark: vec![] };
 		let call = T::RuntimeCall::from(inner);
 		let info = DispatchInfo {
@@ -119,6 +119,9 @@ mod benchmarks {
 				.unwrap()
 				.is_ok());
 		}
+
+		assert!(!CollectedAssetFees::<T>::get(asset_id).is_zero());
+		assert_eq!(FeeSwapsInBlock::<T>::get(), 1);
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
@@ -29,11 +29,13 @@
 //!
 //! ## Pallet API
 //!
-//! This pallet does not have any dispatchable calls or storage. It wraps FRAME's Transaction
-//! Payment pallet and functions as a replacement. This means you should include both pallets in
-//! your `construct_runtime` macro, but only include this pallet's [`TransactionExtension`]
+//! This pallet does not have any dispatchable calls. It wraps FRAME's Transaction Payment pallet
+//! and functions as a replacement. This means you should include both pallets in your
+//! `construct_runtime` macro, but only include this pallet's [`TransactionExtension`]
 //! ([`ChargeAssetTxPayment`]).
 //!
+//! The total fees collected in each asset are kept in [`CollectedAssetFees`].
+//!
 //! ## Terminology
 //!
 //! - Native Asset or Native Currency: The asset that a chain considers native, as in its default
@@ -44,23 +46,25 @@
 
 use sp_std::prelude::*;
 
-use codec::{Decode, Encode};
+use codec::{Codec, Decode, Encode};
 use frame_support::{
 	dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo},
 	traits::{
 		fungibles::{Balanced, Inspect},
-		IsType,
+		Contains, IsType,
 	},
 	DefaultNoBound,
 };
 use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
 use scale_info::TypeInfo;
 use sp_runtime::{
+	helpers_128bit,
 	traits::{
-		AsSystemOriginSigner, DispatchInfoOf, Dispatchable, PostDispatchInfoOf,
+		AsSystemOriginSigner, DispatchInfoOf, Dispatchable, Get, PostDispatchInfoOf,
 		TransactionExtension, TransactionExtensionBase, ValidateResult, Zero,
 	},
 	transaction_validity::{InvalidTransaction, TransactionValidityError, ValidTransaction},
+	Permill, Rounding, RuntimeDebug, SaturatedConversion, Saturating,
 };
 
 #[cfg(test)]
@@ -115,11 +119,39 @@ pub enum InitialPayment<T: Config> {
 	Asset((LiquidityInfoOf<T>, BalanceOf<T>, AssetBalanceOf<T>)),
 }
 
+/// The fee of a call in the native currency and in an asset, as quoted by
+/// [`Pallet::query_fee_in`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct FeeQuote<Balance, AssetBalance> {
+	/// The fee in the native currency.
+	pub native_fee: Balance,
+	/// The equivalent of `native_fee` in the requested asset, or `None` if no asset was requested
+	/// or the fee cannot be paid in it.
+	pub asset_fee: Option<AssetBalance>,
+}
+
+/// The optional asset fee features of a runtime, as reported by
+/// [`Pallet::asset_fee_capabilities`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AssetFeeCapabilities {
+	/// Whether a list of assets can be given, the fee being paid in the first one it can be paid
+	/// in.
+	pub multi_asset_fallback: bool,
+	/// Whether the fee is charged by swapping for exactly the fee in the native currency.
+	pub exact_output_swaps: bool,
+	/// Whether the fee can be paid by an account other than the signer.
+	pub sponsorship: bool,
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
+	use frame_support::pallet_prelude::{
+		Blake2_128Concat, Hooks, StorageMap, StorageValue, ValueQuery,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
 
 	#[pallet::config]
 	pub trait Config:
@@ -131,6 +163,25 @@ pub mod pallet {
 		type Fungibles: Balanced<Self::AccountId>;
 		/// The actual transaction charging logic that charges the fees.
 		type OnChargeAssetTransaction: OnChargeAssetTransaction<Self>;
+		/// The slippage of a fee swap above which [`Event::HighSlippageFee`] is emitted. The
+		/// transaction is charged regardless.
+		#[pallet::constant]
+		type SlippageWarnThreshold: Get<Permill>;
+		/// The minimum fee charged in an asset. What happens to transactions whose fee in the asset
+		/// is below it is decided by [`Config::OnBelowMinAssetFee`].
+		#[pallet::constant]
+		type MinAssetFee: Get<AssetBalanceOf<Self>>;
+		/// The policy applied to transactions whose fee in the asset is below
+		/// [`Config::MinAssetFee`].
+		type OnBelowMinAssetFee: Get<MinAssetFeePolicy>;
+		/// The calls whose fee may be paid in an asset. The fee of any other call is charged in the
+		/// native currency, regardless of the asset selected.
+		type AssetFeeAllowedFor: Contains<Self::RuntimeCall>;
+		/// The maximum number of fees swapped from an asset in a block. Once reached, the fee of
+		/// any further transaction in the block is charged in the native currency, bounding the
+		/// pool interactions of fee payment.
+		#[pallet::constant]
+		type MaxFeeSwapsPerBlock: Get<u32>;
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 		#[cfg(feature = "runtime-benchmarks")]
@@ -145,6 +196,18 @@ pub mod pallet {
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// The total amount of fees collected in each asset, net of refunds.
+	#[pallet::storage]
+	#[pallet::getter(fn collected_asset_fees)]
+	pub type CollectedAssetFees<T: Config> =
+		StorageMap<_, Blake2_128Concat, ChargeAssetIdOf<T>, AssetBalanceOf<T>, ValueQuery>;
+
+	/// The number of fees swapped from an asset in the current block, cleared in
+	/// `on_initialize`.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_swaps_in_block)]
+	pub type FeeSwapsInBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	#[cfg(feature = "runtime-benchmarks")]
 	/// Helper trait to benchmark the `ChargeAssetTxPayment` transaction extension.
 	pub trait BenchmarkHelperTrait<AccountId, FunAssetIdParameter, AssetIdParameter> {
@@ -155,19 +218,71 @@ pub mod pallet {
 		fn setup_balances_and_pool(asset_id: FunAssetIdParameter, account: AccountId);
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			FeeSwapsInBlock::<T>::kill();
+			T::DbWeight::get().writes(1)
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A transaction fee `actual_fee`, of which `tip` was added to the minimum inclusion fee,
 		/// has been paid by `who` in an asset `asset_id`.
+		///
+		/// `actual_fee` is split into `fee_asset_amount` and `tip_asset_amount`, the parts of it
+		/// that paid for the net fee and the tip respectively.
 		AssetTxFeePaid {
 			who: T::AccountId,
 			actual_fee: AssetBalanceOf<T>,
 			tip: BalanceOf<T>,
 			asset_id: ChargeAssetIdOf<T>,
+			fee_asset_amount: AssetBalanceOf<T>,
+			tip_asset_amount: AssetBalanceOf<T>,
 		},
 		/// A swap of the refund in native currency back to asset failed.
 		AssetRefundFailed { native_amount_kept: BalanceOf<T> },
+		/// The fee paid by `who` in asset `asset_id` was swapped at a price `slippage` worse than
+		/// the pool's spot price, which is above [`Config::SlippageWarnThreshold`].
+		HighSlippageFee { who: T::AccountId, asset_id: AssetIdOf<T>, slippage: Permill },
+	}
+}
+
+impl<T: Config> Pallet<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	BalanceOf<T>: Into<ChargeAssetBalanceOf<T>>,
+{
+	/// Quote the fee of a call of `call_len` bytes with the given dispatch `info`, without a tip,
+	/// in the native currency and, if given, in `asset`.
+	///
+	/// The native fee is the one computed by [`pallet_transaction_payment`]. The asset fee is
+	/// quoted at the current price, so it may have changed by the time the call is submitted.
+	pub fn query_fee_in(
+		call_len: u32,
+		info: &DispatchInfo,
+		asset: Option<ChargeAssetIdOf<T>>,
+	) -> FeeQuote<BalanceOf<T>, AssetBalanceOf<T>> {
+		let native_fee =
+			pallet_transaction_payment::Pallet::<T>::compute_fee(call_len, info, Zero::zero());
+		let asset_fee = asset
+			.and_then(|asset| T::OnChargeAssetTransaction::quote_fee(asset, native_fee.into()));
+		FeeQuote { native_fee, asset_fee }
+	}
+
+	/// The optional asset fee features supported with this pallet's configuration.
+	///
+	/// [`ChargeAssetTxPayment`] supports neither a fallback list of assets nor sponsored fees.
+	/// Whether the fee is charged through an exact output swap depends on
+	/// [`Config::OnChargeAssetTransaction`].
+	pub fn asset_fee_capabilities() -> AssetFeeCapabilities {
+		AssetFeeCapabilities {
+			multi_asset_fallback: false,
+			exact_output_swaps: T::OnChargeAssetTransaction::EXACT_OUTPUT_SWAPS,
+			sponsorship: false,
+		}
 	}
 }
 
@@ -176,13 +291,19 @@ pub mod pallet {
 ///
 /// Wraps the transaction logic in [`pallet_transaction_payment`] and extends it with assets.
 /// An asset ID of `None` falls back to the underlying transaction payment logic via the native
-/// currency.
+/// currency, as does a call not allowed by [`Config::AssetFeeAllowedFor`] or a transaction beyond
+/// the [`Config::MaxFeeSwapsPerBlock`] fee swaps of its block.
+///
+/// A fee paid in an asset can be bounded with `max_asset_fee`, an absolute amount of the asset,
+/// and `max_slippage`, relative to the spot price of the asset. Both are enforced if both are set.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct ChargeAssetTxPayment<T: Config> {
 	#[codec(compact)]
 	tip: BalanceOf<T>,
 	asset_id: Option<ChargeAssetIdOf<T>>,
+	max_asset_fee: Option<AssetBalanceOf<T>>,
+	max_slippage: Option<Permill>,
 }
 
 impl<T: Config> ChargeAssetTxPayment<T>
@@ -194,22 +315,48 @@ where
 {
 	/// Utility constructor. Used only in client/factory code.
 	pub fn from(tip: BalanceOf<T>, asset_id: Option<ChargeAssetIdOf<T>>) -> Self {
-		Self { tip, asset_id }
+		Self { tip, asset_id, max_asset_fee: None, max_slippage: None }
+	}
+
+	/// Refuse to pay more than `max_asset_fee` of the asset for the fee.
+	///
+	/// The transaction is invalid if the fee in the asset is above it.
+	pub fn with_max_asset_fee(mut self, max_asset_fee: Option<AssetBalanceOf<T>>) -> Self {
+		self.max_asset_fee = max_asset_fee;
+		self
+	}
+
+	/// Refuse to swap the asset for the fee at a price worse than the pool's spot price by more
+	/// than `max_slippage`.
+	///
+	/// The transaction is invalid if the slippage of the fee swap is above it.
+	pub fn with_max_slippage(mut self, max_slippage: Option<Permill>) -> Self {
+		self.max_slippage = max_slippage;
+		self
 	}
 
-	/// Fee withdrawal logic that dispatches to either `OnChargeAssetTransaction` or
-	/// `OnChargeTransaction`.
+	/// The asset the fee of `call` is paid in, or `None` if it is paid in the native currency.
+	fn call_payment_asset(&self, call: &T::RuntimeCall) -> Option<&ChargeAssetIdOf<T>> {
+		self.asset_id
+			.as_ref()
+			.filter(|_| T::AssetFeeAllowedFor::contains(call))
+			.filter(|_| FeeSwapsInBlock::<T>::get() < T::MaxFeeSwapsPerBlock::get())
+	}
+
+	/// Fee withdrawal logic that dispatches to either `OnChargeAssetTransaction`, if `asset_id` is
+	/// given, or `OnChargeTransaction`.
 	fn withdraw_fee(
 		&self,
 		who: &T::AccountId,
 		call: &T::RuntimeCall,
 		info: &DispatchInfoOf<T::RuntimeCall>,
 		fee: BalanceOf<T>,
+		asset_id: Option<&ChargeAssetIdOf<T>>,
 	) -> Result<(BalanceOf<T>, InitialPayment<T>), TransactionValidityError> {
 		debug_assert!(self.tip <= fee, "tip should be included in the computed fee");
 		if fee.is_zero() {
 			Ok((fee, InitialPayment::Nothing))
-		} else if let Some(asset_id) = &self.asset_id {
+		} else if let Some(asset_id) = asset_id {
 			T::OnChargeAssetTransaction::withdraw_fee(
 				who,
 				call,
@@ -217,8 +364,11 @@ where
 				asset_id.clone(),
 				fee.into(),
 				self.tip.into(),
+				self.max_asset_fee,
+				self.max_slippage,
 			)
 			.map(|(used_for_fee, received_exchanged, asset_consumed)| {
+				FeeSwapsInBlock::<T>::mutate(|swaps| swaps.saturating_inc());
 				(
 					fee,
 					InitialPayment::Asset((
@@ -236,12 +386,33 @@ where
 			.map_err(|_| -> TransactionValidityError { InvalidTransaction::Payment.into() })
 		}
 	}
+
+	/// Check that the fee can be withdrawn in the native currency, without withdrawing it.
+	fn can_withdraw_native_fee(
+		&self,
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		fee: BalanceOf<T>,
+	) -> Result<(), TransactionValidityError> {
+		<OnChargeTransactionOf<T> as OnChargeTransaction<T>>::can_withdraw_fee(
+			who, call, info, fee, self.tip,
+		)
+		.map_err(|_| -> TransactionValidityError { InvalidTransaction::Payment.into() })
+	}
 }
 
 impl<T: Config> sp_std::fmt::Debug for ChargeAssetTxPayment<T> {
 	#[cfg(feature = "std")]
 	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
-		write!(f, "ChargeAssetTxPayment<{:?}, {:?}>", self.tip, self.asset_id.encode())
+		write!(
+			f,
+			"ChargeAssetTxPayment<{:?}, {:?}, {:?}, {:?}>",
+			self.tip,
+			self.asset_id.encode(),
+			self.max_asset_fee,
+			self.max_slippage
+		)
 	}
 	#[cfg(not(feature = "std"))]
 	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
@@ -264,6 +435,7 @@ where
 	type Implicit = ();
 
 	fn weight(&self) -> Weight {
+		// The call is not known here, so any selected asset is assumed to be used.
 		if self.asset_id.is_some() {
 			<T as Config>::WeightInfo::charge_asset_tx_payment_asset()
 		} else {
@@ -307,7 +479,7 @@ where
 	fn validate(
 		&self,
 		origin: <T::RuntimeCall as Dispatchable>::RuntimeOrigin,
-		_call: &T::RuntimeCall,
+		call: &T::RuntimeCall,
 		info: &DispatchInfoOf<T::RuntimeCall>,
 		len: usize,
 		_context: &mut Context,
@@ -317,6 +489,10 @@ where
 		let who = origin.as_system_origin_signer().ok_or(InvalidTransaction::BadSigner)?;
 		// Non-mutating call of `compute_fee` to calculate the fee used in the transaction priority.
 		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+		// A fee paid in an asset is only checked when it is swapped, in `prepare`.
+		if !fee.is_zero() && self.call_payment_asset(call).is_none() {
+			self.can_withdraw_native_fee(who, call, info, fee)?;
+		}
 		let priority = ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee);
 		let validity = ValidTransaction { priority, ..Default::default() };
 		let val = (self.tip, who.clone(), fee);
@@ -333,9 +509,12 @@ where
 		_context: &Context,
 	) -> Result<Self::Pre, TransactionValidityError> {
 		let (tip, who, fee) = val;
+		// The asset is selected before charging, which counts towards the fee swaps of the block.
+		let asset_id = self.call_payment_asset(call).cloned();
 		// Mutating call of `withdraw_fee` to actually charge for the transaction.
-		let (_fee, initial_payment) = self.withdraw_fee(&who, call, info, fee)?;
-		Ok((tip, who, initial_payment, self.asset_id.clone()))
+		let (_fee, initial_payment) =
+			self.withdraw_fee(&who, call, info, fee, asset_id.as_ref())?;
+		Ok((tip, who, initial_payment, asset_id))
 	}
 
 	fn post_dispatch(
@@ -367,6 +546,8 @@ where
 					asset_id.is_some(),
 					"For that payment type the `asset_id` should be set"
 				);
+				// The fee only depends on the actual weight, so the unused weight of a failed call
+				// is refunded just like for a successful one and for native payments.
 				let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
 					len as u32, info, post_info, tip,
 				);
@@ -385,11 +566,18 @@ where
 						asset_consumed.into(),
 					)?;
 
+					CollectedAssetFees::<T>::mutate(&asset_id, |collected| {
+						collected.saturating_accrue(converted_fee)
+					});
+
+					let tip_asset_amount = split_tip_in_asset::<T>(converted_fee, actual_fee, tip);
 					Pallet::<T>::deposit_event(Event::<T>::AssetTxFeePaid {
 						who,
 						actual_fee: converted_fee,
 						tip,
 						asset_id,
+						fee_asset_amount: converted_fee.saturating_sub(tip_asset_amount),
+						tip_asset_amount,
 					});
 				}
 			},
@@ -405,3 +593,46 @@ where
 		Ok(())
 	}
 }
+
+/// Returns the part of `converted_fee`, the asset amount paid for the native `actual_fee`, that
+/// paid for `tip`. The tip's share is rounded down, leaving any remainder to the net fee.
+fn split_tip_in_asset<T: Config>(
+	converted_fee: AssetBalanceOf<T>,
+	actual_fee: BalanceOf<T>,
+	tip: BalanceOf<T>,
+) -> AssetBalanceOf<T> {
+	if actual_fee.is_zero() {
+		return Zero::zero()
+	}
+	helpers_128bit::multiply_by_rational_with_rounding(
+		converted_fee.saturated_into(),
+		tip.min(actual_fee).saturated_into(),
+		actual_fee.saturated_into(),
+		Rounding::Down,
+	)
+	.map(|tip_in_asset| tip_in_asset.saturated_into())
+	.unwrap_or_else(Zero::zero)
+}
+
+sp_api::decl_runtime_apis! {
+	/// This runtime api allows wallets to quote transaction fees in both the native currency and
+	/// an asset.
+	pub trait AssetConversionTxPaymentApi<Balance, AssetBalance, AssetId>
+	where
+		Balance: Codec,
+		AssetBalance: Codec,
+		AssetId: Codec,
+	{
+		/// Returns the fee of a call of `call_len` bytes with the given dispatch `info` in the
+		/// native currency and, if given, in `asset`. See [`Pallet::query_fee_in`].
+		fn query_fee_in(
+			call_len: u32,
+			info: DispatchInfo,
+			asset: Option<AssetId>,
+		) -> FeeQuote<Balance, AssetBalance>;
+
+		/// Returns the optional asset fee features supported by the runtime. See
+		/// [`Pallet::asset_fee_capabilities`].
+		fn asset_fee_capabilities() -> AssetFeeCapabilities;
+	}
+}
@@ -18,17 +18,19 @@ use crate as pallet_asset_conversion_tx_payment;
 
 use frame_support::{
 	derive_impl,
-	dispatch::DispatchClass,
+	dispatch::{DispatchClass, GetDispatchInfo},
 	instances::Instance2,
 	ord_parameter_types,
 	pallet_prelude::*,
 	parameter_types,
 	traits::{
 		tokens::{
-			fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+			fungible::{ItemOf, NativeFromLeft, NativeOrWithId, UnionOf},
+			fungibles,
 			imbalance::ResolveAssetTo,
+			Fortitude, Precision, Preservation, WithdrawConsequence,
 		},
-		AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, Imbalance, OnUnbalanced,
+		AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, Contains, SameOrOther,
 	},
 	weights::{Weight, WeightToFee as WeightToFeeT},
 	PalletId,
@@ -36,12 +38,12 @@ use frame_support::{
 use frame_system as system;
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use pallet_asset_conversion::{Ascending, Chain, WithFirstAsset};
-use pallet_transaction_payment::CurrencyAdapter;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup, SaturatedConversion},
 	Permill,
 };
+use std::collections::HashMap;
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
 type Balance = u64;
@@ -154,24 +156,114 @@ parameter_types! {
 	pub(crate) static FeeUnbalancedAmount: u64 = 0;
 }
 
-pub struct DealWithFees;
-impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for DealWithFees {
-	fn on_unbalanceds<B>(
-		mut fees_then_tips: impl Iterator<Item = pallet_balances::NegativeImbalance<Runtime>>,
-	) {
-		if let Some(fees) = fees_then_tips.next() {
-			FeeUnbalancedAmount::mutate(|a| *a += fees.peek());
-			if let Some(tips) = fees_then_tips.next() {
-				TipUnbalancedAmount::mutate(|a| *a += tips.peek());
-			}
+type NativeAndAssets = UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, AccountId>;
+type FeeCredit = fungibles::Credit<AccountId, NativeAndAssets>;
+
+/// Charges the transaction fees in [`FeeAsset`], the native currency unless a test sets another
+/// asset.
+///
+/// The fee and the tip are burned, after being added to [`FeeUnbalancedAmount`] and
+/// [`TipUnbalancedAmount`].
+pub struct FeeAssetAdapter;
+impl pallet_transaction_payment::OnChargeTransaction<Runtime> for FeeAssetAdapter {
+	type Balance = Balance;
+	type LiquidityInfo = Option<FeeCredit>;
+
+	fn withdraw_fee(
+		who: &AccountId,
+		_call: &RuntimeCall,
+		_dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		fee: Balance,
+		_tip: Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		if fee.is_zero() {
+			return Ok(None)
+		}
+
+		<NativeAndAssets as fungibles::Balanced<AccountId>>::withdraw(
+			FeeAsset::get(),
+			who,
+			fee,
+			Precision::Exact,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)
+		.map(Some)
+		.map_err(|_| InvalidTransaction::Payment.into())
+	}
+
+	fn can_withdraw_fee(
+		who: &AccountId,
+		_call: &RuntimeCall,
+		_dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		fee: Balance,
+		_tip: Balance,
+	) -> Result<(), TransactionValidityError> {
+		if fee.is_zero() {
+			return Ok(())
+		}
+
+		match <NativeAndAssets as fungibles::Inspect<AccountId>>::can_withdraw(
+			FeeAsset::get(),
+			who,
+			fee,
+		) {
+			WithdrawConsequence::Success | WithdrawConsequence::ReducedToZero(_) => Ok(()),
+			_ => Err(InvalidTransaction::Payment.into()),
 		}
 	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		_dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		_post_info: &PostDispatchInfoOf<RuntimeCall>,
+		corrected_fee: Balance,
+		tip: Balance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let Some(paid) = already_withdrawn else { return Ok(()) };
+
+		let fee_asset = FeeAsset::get();
+		let refund_amount = paid.peek().saturating_sub(corrected_fee);
+		let refund = <NativeAndAssets as fungibles::Balanced<AccountId>>::deposit(
+			fee_asset.clone(),
+			who,
+			refund_amount,
+			Precision::BestEffort,
+		)
+		.unwrap_or_else(|_| fungibles::Debt::<AccountId, NativeAndAssets>::zero(fee_asset.clone()));
+		let adjusted_paid = match paid.offset(refund) {
+			Ok(SameOrOther::Same(adjusted_paid)) => adjusted_paid,
+			Ok(SameOrOther::None) => FeeCredit::zero(fee_asset),
+			_ => return Err(InvalidTransaction::Payment.into()),
+		};
+
+		let (tip, fee) = adjusted_paid.split(tip);
+		FeeUnbalancedAmount::mutate(|a| *a += fee.peek());
+		TipUnbalancedAmount::mutate(|a| *a += tip.peek());
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn endow_account(who: &AccountId, amount: Balance) {
+		let _ = <NativeAndAssets as fungibles::Balanced<AccountId>>::deposit(
+			FeeAsset::get(),
+			who,
+			amount,
+			Precision::BestEffort,
+		);
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn minimum_balance() -> Balance {
+		<NativeAndAssets as fungibles::Inspect<AccountId>>::minimum_balance(FeeAsset::get())
+	}
 }
 
 #[derive_impl(pallet_transaction_payment::config_preludes::TestDefaultConfig as pallet_transaction_payment::DefaultConfig)]
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
+	type OnChargeTransaction = FeeAssetAdapter;
 	type WeightToFee = WeightToFee;
 	type LengthToFee = TransactionByteFee;
 	type OperationalFeeMultiplier = ConstU8<5>;
@@ -193,7 +285,7 @@ impl pallet_assets::Config for Runtime {
 	type MetadataDepositPerByte = ConstU64<0>;
 	type ApprovalDeposit = ConstU64<0>;
 	type StringLimit = ConstU32<20>;
-	type Freezer = ();
+	type Freezer = TestFreezer;
 	type Extra = ();
 	type CallbackHandle = ();
 	type WeightInfo = ();
@@ -203,6 +295,25 @@ impl pallet_assets::Config for Runtime {
 	}
 }
 
+parameter_types! {
+	static Frozen: HashMap<(AssetId, AccountId), Balance> = Default::default();
+}
+
+pub struct TestFreezer;
+impl pallet_assets::FrozenBalance<AssetId, AccountId, Balance> for TestFreezer {
+	fn frozen_balance(asset: AssetId, who: &AccountId) -> Option<Balance> {
+		Frozen::get().get(&(asset, *who)).cloned()
+	}
+
+	fn died(_: AssetId, _: &AccountId) {}
+}
+
+pub(crate) fn set_frozen_balance(asset: AssetId, who: AccountId, amount: Balance) {
+	Frozen::mutate(|v| {
+		v.insert((asset, who), amount);
+	});
+}
+
 impl pallet_assets::Config<Instance2> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u64;
@@ -231,7 +342,13 @@ parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub storage LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
 	pub const MaxSwapPathLength: u32 = 4;
+	pub const MaxPoolFee: Permill = Permill::from_percent(10);
 	pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
+	pub static FeeAsset: NativeOrWithId<u32> = NativeOrWithId::Native;
+	pub const SlippageWarnThreshold: Permill = Permill::from_percent(1);
+	pub static MinAssetFee: u64 = 0;
+	pub static OnBelowMinAssetFee: MinAssetFeePolicy = MinAssetFeePolicy::ChargeMinimum;
+	pub static MaxFeeSwapsPerBlock: u32 = 100;
 }
 
 ord_parameter_types! {
@@ -243,7 +360,7 @@ impl pallet_asset_conversion::Config for Runtime {
 	type Balance = Balance;
 	type HigherPrecisionBalance = u128;
 	type AssetKind = NativeOrWithId<u32>;
-	type Assets = UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, AccountId>;
+	type Assets = NativeAndAssets;
 	type PoolId = (Self::AssetKind, Self::AssetKind);
 	type PoolLocator = Chain<
 		WithFirstAsset<Native, AccountId, NativeOrWithId<u32>>,
@@ -255,20 +372,43 @@ impl pallet_asset_conversion::Config for Runtime {
 	type PoolSetupFeeAsset = Native;
 	type PoolSetupFeeTarget = ResolveAssetTo<AssetConversionOrigin, Self::Assets>;
 	type PalletId = AssetConversionPalletId;
+	type MaxLpSupplyOrigin = EnsureRoot<AccountId>;
+	type PoolFeeOrigin = EnsureRoot<AccountId>;
 	type LPFee = ConstU32<3>; // means 0.3%
+	type MaxPoolFee = MaxPoolFee;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = MaxSwapPathLength;
 	type MintMinLiquidity = ConstU64<100>; // 100 is good enough when the main currency has 12 decimals.
+	type TrackPoolVolume = ConstBool<true>;
+	type TrackPriceMoves = ConstBool<false>;
+	type OnPriceMove = ();
 	type WeightInfo = ();
 	pallet_asset_conversion::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
 }
 
+/// Allows paying the fee in an asset for calls of the `Normal` dispatch class only.
+pub struct NormalCalls;
+impl Contains<RuntimeCall> for NormalCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		call.get_dispatch_info().class == DispatchClass::Normal
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Fungibles = Assets;
-	type OnChargeAssetTransaction = AssetConversionAdapter<Balances, AssetConversion, Native>;
+	type OnChargeAssetTransaction = AssetConversionAdapter<
+		ItemOf<NativeAndAssets, FeeAsset, AccountId>,
+		AssetConversion,
+		FeeAsset,
+	>;
+	type SlippageWarnThreshold = SlippageWarnThreshold;
+	type MinAssetFee = MinAssetFee;
+	type OnBelowMinAssetFee = OnBelowMinAssetFee;
+	type AssetFeeAllowedFor = NormalCalls;
+	type MaxFeeSwapsPerBlock = MaxFeeSwapsPerBlock;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = Helper;
@@ -19,17 +19,60 @@ use crate::Config;
 
 use frame_support::{
 	ensure,
-	traits::{fungible::Inspect, tokens::Balance},
+	traits::{
+		fungible::Inspect,
+		tokens::{Balance, Fortitude, Precision, Preservation},
+	},
 	unsigned::TransactionValidityError,
 };
-use pallet_asset_conversion::Swap;
+use pallet_asset_conversion::{QuotePrice, Swap};
 use sp_runtime::{
 	traits::{DispatchInfoOf, Get, PostDispatchInfoOf, Zero},
 	transaction_validity::InvalidTransaction,
-	Saturating,
+	Permill, Saturating,
 };
 use sp_std::marker::PhantomData;
 
+/// Custom validity errors returned by [`AssetConversionAdapter`] when the fee can not be charged.
+///
+/// Encoded as [`InvalidTransaction::Custom`] with the discriminant as the inner code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AssetPaymentError {
+	/// The account holds enough of the asset to pay the fee, but part of the balance is frozen
+	/// and the remaining spendable balance does not cover it.
+	AssetBalanceFrozen = 0,
+	/// The fee in the asset is below [`Config::MinAssetFee`] and
+	/// [`MinAssetFeePolicy::Reject`] is configured.
+	FeeBelowMinimum = 1,
+	/// The fee in the asset is above the `max_asset_fee` set by the payer.
+	FeeAboveMaximum = 2,
+	/// The price of the fee swap is worse than the pool's spot price by more than the
+	/// `max_slippage` set by the payer.
+	SlippageTooHigh = 3,
+}
+
+/// What to do with a transaction whose fee in the asset is below [`Config::MinAssetFee`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MinAssetFeePolicy {
+	/// Charge [`Config::MinAssetFee`] instead. The difference to the fee is burned.
+	ChargeMinimum,
+	/// Reject the transaction with [`AssetPaymentError::FeeBelowMinimum`].
+	Reject,
+}
+
+impl From<AssetPaymentError> for u8 {
+	fn from(err: AssetPaymentError) -> Self {
+		err as u8
+	}
+}
+
+impl From<AssetPaymentError> for TransactionValidityError {
+	fn from(err: AssetPaymentError) -> Self {
+		InvalidTransaction::Custom(err.into()).into()
+	}
+}
+
 /// Handle withdrawing, refunding and depositing of transaction fees.
 pub trait OnChargeAssetTransaction<T: Config> {
 	/// The underlying integer type in which fees are calculated.
@@ -39,8 +82,18 @@ pub trait OnChargeAssetTransaction<T: Config> {
 	/// The type used to store the intermediate values between pre- and post-dispatch.
 	type LiquidityInfo;
 
+	/// Whether [`Self::withdraw_fee`] swaps for exactly the fee in the native currency.
+	const EXACT_OUTPUT_SWAPS: bool = false;
+
+	/// The amount of `asset_id` that would currently be charged for a `fee` in the native
+	/// currency, or `None` if the fee cannot be paid in `asset_id`.
+	fn quote_fee(asset_id: Self::AssetId, fee: Self::Balance) -> Option<AssetBalanceOf<T>>;
+
 	/// Secure the payment of the transaction fees before the transaction is executed.
 	///
+	/// The payment must be refused if it would take more than `max_asset_fee` of the asset, or if
+	/// its price is worse than the spot price by more than `max_slippage`.
+	///
 	/// Note: The `fee` already includes the `tip`.
 	fn withdraw_fee(
 		who: &T::AccountId,
@@ -49,6 +102,8 @@ pub trait OnChargeAssetTransaction<T: Config> {
 		asset_id: Self::AssetId,
 		fee: Self::Balance,
 		tip: Self::Balance,
+		max_asset_fee: Option<AssetBalanceOf<T>>,
+		max_slippage: Option<Permill>,
 	) -> Result<
 		(LiquidityInfoOf<T>, Self::LiquidityInfo, AssetBalanceOf<T>),
 		TransactionValidityError,
@@ -75,16 +130,20 @@ pub trait OnChargeAssetTransaction<T: Config> {
 
 /// Implements the asset transaction for a balance to asset converter (implementing [`Swap`]).
 ///
-/// The converter is given the complete fee in terms of the asset used for the transaction.
+/// The converter is given the complete fee in terms of the asset used for the transaction, and
+/// swaps it for the fee asset `N`. This is usually the native currency, but can be any asset the
+/// runtime charges its transaction fees in. Either way, `C` must hold the balances of `N` and the
+/// `OnChargeTransaction` of `pallet_transaction_payment` must collect the fees in `N`.
 pub struct AssetConversionAdapter<C, CON, N>(PhantomData<(C, CON, N)>);
 
 /// Default implementation for a runtime instantiating this pallet, an asset to native swapper.
 impl<T, C, CON, N> OnChargeAssetTransaction<T> for AssetConversionAdapter<C, CON, N>
 where
-	N: Get<CON::AssetKind>,
+	N: Get<T::AssetKind>,
 	T: Config,
 	C: Inspect<<T as frame_system::Config>::AccountId>,
-	CON: Swap<T::AccountId, Balance = BalanceOf<T>, AssetKind = T::AssetKind>,
+	CON: Swap<T::AccountId, Balance = BalanceOf<T>, AssetKind = T::AssetKind>
+		+ QuotePrice<Balance = BalanceOf<T>, AssetKind = T::AssetKind>,
 	BalanceOf<T>: Into<AssetBalanceOf<T>>,
 	T::AssetKind: From<AssetIdOf<T>>,
 	BalanceOf<T>: IsType<<C as Inspect<<T as frame_system::Config>::AccountId>>::Balance>,
@@ -93,12 +152,23 @@ where
 	type AssetId = AssetIdOf<T>;
 	type LiquidityInfo = BalanceOf<T>;
 
+	const EXACT_OUTPUT_SWAPS: bool = true;
+
+	/// Quote the fee at the current price of the pool between `asset_id` and the fee asset `N`.
+	///
+	/// Note: This doesn't account for the existential deposit that may additionally have to be
+	/// swapped for in [`Self::withdraw_fee`].
+	fn quote_fee(asset_id: Self::AssetId, fee: BalanceOf<T>) -> Option<AssetBalanceOf<T>> {
+		CON::quote_price_tokens_for_exact_tokens(asset_id.into(), N::get(), fee, true)
+			.map(Into::into)
+	}
+
 	/// Swap & withdraw the predicted fee from the transaction origin.
 	///
 	/// Note: The `fee` already includes the `tip`.
 	///
-	/// Returns the total amount in native currency received by exchanging the `asset_id` and the
-	/// amount in native currency used to pay the fee.
+	/// Returns the total amount of the fee asset received by exchanging the `asset_id` and the
+	/// amount of the fee asset used to pay the fee.
 	fn withdraw_fee(
 		who: &T::AccountId,
 		call: &T::RuntimeCall,
@@ -106,30 +176,84 @@ where
 		asset_id: Self::AssetId,
 		fee: BalanceOf<T>,
 		tip: BalanceOf<T>,
+		max_asset_fee: Option<AssetBalanceOf<T>>,
+		max_slippage: Option<Permill>,
 	) -> Result<
 		(LiquidityInfoOf<T>, Self::LiquidityInfo, AssetBalanceOf<T>),
 		TransactionValidityError,
 	> {
-		// convert the asset into native currency
+		// convert the asset into the fee asset
 		let ed = C::minimum_balance();
 		let native_asset_required =
 			if C::balance(&who) >= ed.saturating_add(fee.into()) { fee } else { fee + ed.into() };
 
+		// the amount of the asset needed at the current spot price, to measure the slippage of
+		// the swap against.
+		let spot_asset_required = CON::quote_price_tokens_for_exact_tokens(
+			asset_id.clone().into(),
+			N::get(),
+			native_asset_required,
+			false,
+		);
+
+		// refuse the payment before swapping if the swap would exceed the limits of the payer.
+		if max_asset_fee.is_some() || max_slippage.is_some() {
+			let asset_required = CON::quote_price_tokens_for_exact_tokens(
+				asset_id.clone().into(),
+				N::get(),
+				native_asset_required,
+				true,
+			)
+			.ok_or(InvalidTransaction::Payment)?;
+			if let Some(max_asset_fee) = max_asset_fee {
+				let asset_fee: AssetBalanceOf<T> = asset_required.into();
+				ensure!(asset_fee <= max_asset_fee, AssetPaymentError::FeeAboveMaximum);
+			}
+			if let (Some(max_slippage), Some(spot_asset_required)) =
+				(max_slippage, spot_asset_required.filter(|a| !a.is_zero()))
+			{
+				let slippage = Permill::from_rational(
+					asset_required.saturating_sub(spot_asset_required),
+					spot_asset_required,
+				);
+				ensure!(slippage <= max_slippage, AssetPaymentError::SlippageTooHigh);
+			}
+		}
+
 		let asset_consumed = CON::swap_tokens_for_exact_tokens(
 			who.clone(),
-			vec![asset_id.into(), N::get()],
+			vec![asset_id.clone().into(), N::get()],
 			native_asset_required,
 			None,
 			who.clone(),
 			true,
 		)
-		.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))?;
+		.map_err(|_| {
+			Self::swap_failure_reason::<T>(who, asset_id.clone(), native_asset_required)
+		})?;
 
 		ensure!(asset_consumed > Zero::zero(), InvalidTransaction::Payment);
 
-		// charge the fee in native currency
-		<T::OnChargeTransaction>::withdraw_fee(who, call, info, fee, tip)
-			.map(|r| (r, native_asset_required, asset_consumed.into()))
+		let asset_charged = Self::apply_min_asset_fee::<T>(who, asset_id.clone(), asset_consumed)?;
+
+		// charge the fee in the fee asset
+		let fee_paid = <T::OnChargeTransaction>::withdraw_fee(who, call, info, fee, tip)?;
+
+		if let Some(spot_asset_required) = spot_asset_required.filter(|a| !a.is_zero()) {
+			let slippage = Permill::from_rational(
+				asset_consumed.saturating_sub(spot_asset_required),
+				spot_asset_required,
+			);
+			if slippage > T::SlippageWarnThreshold::get() {
+				Pallet::<T>::deposit_event(Event::<T>::HighSlippageFee {
+					who: who.clone(),
+					asset_id,
+					slippage,
+				});
+			}
+		}
+
+		Ok((fee_paid, native_asset_required, asset_charged))
 	}
 
 	/// Correct the fee and swap the refund back to asset.
@@ -169,8 +293,8 @@ where
 			match CON::swap_exact_tokens_for_tokens(
 				who.clone(), // we already deposited the native to `who`
 				vec![
-					N::get(),        // we provide the native
-					asset_id.into(), // we want asset_id back
+					N::get(),                // we provide the native
+					asset_id.clone().into(), // we want asset_id back
 				],
 				swap_back,   // amount of the native asset to convert to `asset_id`
 				None,        // no minimum amount back
@@ -192,7 +316,122 @@ where
 			}
 		}
 
-		let actual_paid = initial_asset_consumed.saturating_sub(asset_refund);
+		let mut actual_paid = initial_asset_consumed.saturating_sub(asset_refund);
+
+		// Don't let the refund take the fee below the minimum. This is best effort, as the
+		// transaction has been executed already.
+		let min_asset_fee = T::MinAssetFee::get();
+		if actual_paid < min_asset_fee &&
+			T::OnBelowMinAssetFee::get() == MinAssetFeePolicy::ChargeMinimum
+		{
+			actual_paid += Self::burn_asset_fee::<T>(
+				who,
+				asset_id,
+				min_asset_fee - actual_paid,
+				Precision::BestEffort,
+			)?;
+		}
+
 		Ok(actual_paid)
 	}
 }
+
+impl<C, CON, N> AssetConversionAdapter<C, CON, N> {
+	/// Apply [`Config::OnBelowMinAssetFee`] to the `asset_consumed` by the fee swap.
+	///
+	/// Returns the amount of the asset charged in total.
+	fn apply_min_asset_fee<T: Config>(
+		who: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		asset_consumed: BalanceOf<T>,
+	) -> Result<AssetBalanceOf<T>, TransactionValidityError>
+	where
+		BalanceOf<T>: Into<AssetBalanceOf<T>>,
+	{
+		let asset_consumed: AssetBalanceOf<T> = asset_consumed.into();
+		let min_asset_fee = T::MinAssetFee::get();
+		if asset_consumed >= min_asset_fee {
+			return Ok(asset_consumed)
+		}
+
+		match T::OnBelowMinAssetFee::get() {
+			MinAssetFeePolicy::Reject => Err(AssetPaymentError::FeeBelowMinimum.into()),
+			MinAssetFeePolicy::ChargeMinimum => {
+				let burned = Self::burn_asset_fee::<T>(
+					who,
+					asset_id,
+					min_asset_fee - asset_consumed,
+					Precision::Exact,
+				)?;
+				Ok(asset_consumed + burned)
+			},
+		}
+	}
+
+	/// Burn `amount` of `asset_id` from `who` as part of the transaction fee.
+	///
+	/// Returns the amount actually burned, which may be less than `amount` with
+	/// [`Precision::BestEffort`].
+	fn burn_asset_fee<T: Config>(
+		who: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		amount: AssetBalanceOf<T>,
+		precision: Precision,
+	) -> Result<AssetBalanceOf<T>, TransactionValidityError> {
+		let credit = T::Fungibles::withdraw(
+			asset_id,
+			who,
+			amount,
+			precision,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)
+		.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))?;
+		// Dropping the credit burns it.
+		Ok(credit.peek())
+	}
+
+	/// Explain why swapping `asset_id` for `native_required` of the native asset failed.
+	///
+	/// Returns [`AssetPaymentError::AssetBalanceFrozen`] if the account would have been able to
+	/// afford the swap were it not for a freeze on its asset balance, and
+	/// [`InvalidTransaction::Payment`] otherwise.
+	fn swap_failure_reason<T>(
+		who: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		native_required: BalanceOf<T>,
+	) -> TransactionValidityError
+	where
+		N: Get<T::AssetKind>,
+		T: Config,
+		CON: QuotePrice<Balance = BalanceOf<T>, AssetKind = T::AssetKind>,
+		BalanceOf<T>: Into<AssetBalanceOf<T>>,
+		T::AssetKind: From<AssetIdOf<T>>,
+	{
+		let Some(asset_required) = CON::quote_price_tokens_for_exact_tokens(
+			asset_id.clone().into(),
+			N::get(),
+			native_required,
+			true,
+		) else {
+			return InvalidTransaction::Payment.into()
+		};
+		let asset_required: AssetBalanceOf<T> = asset_required.into();
+
+		// the amount that would be spendable if no freeze was in place.
+		let unfrozen = T::Fungibles::balance(asset_id.clone(), who)
+			.saturating_sub(T::Fungibles::minimum_balance(asset_id.clone()));
+		let spendable = T::Fungibles::reducible_balance(
+			asset_id,
+			who,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		);
+
+		if unfrozen >= asset_required && spendable < asset_required {
+			AssetPaymentError::AssetBalanceFrozen.into()
+		} else {
+			InvalidTransaction::Payment.into()
+		}
+	}
+}
@@ -266,6 +266,349 @@ fn transaction_payment_in_asset_possible() {
 		});
 }
 
+#[test]
+fn query_fee_in_quotes_native_and_asset() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// one asset with a pool to the native asset, one without
+			let (asset_id, no_pool_asset_id) = (1, 2);
+			for id in [asset_id, no_pool_asset_id] {
+				assert_ok!(Assets::force_create(
+					RuntimeOrigin::root(),
+					id.into(),
+					42,   /* owner */
+					true, /* is_sufficient */
+					2     /* min_balance */
+				));
+			}
+			setup_lp(asset_id, balance_factor);
+
+			let len = 10;
+			let info = info_from_weight(WEIGHT_5);
+			let fee_in_native = base_weight + 5 + len as u64;
+			assert_eq!(TransactionPayment::compute_fee(len, &info, 0), fee_in_native);
+
+			assert_eq!(
+				AssetTxPayment::query_fee_in(len, &info, None),
+				FeeQuote { native_fee: fee_in_native, asset_fee: None }
+			);
+			assert_eq!(
+				AssetTxPayment::query_fee_in(len, &info, Some(asset_id)),
+				FeeQuote { native_fee: fee_in_native, asset_fee: Some(201) }
+			);
+			assert_eq!(
+				AssetTxPayment::query_fee_in(len, &info, Some(no_pool_asset_id)),
+				FeeQuote { native_fee: fee_in_native, asset_fee: None }
+			);
+		});
+}
+
+#[test]
+fn asset_fee_capabilities_match_the_config() {
+	ExtBuilder::default().build().execute_with(|| {
+		// The mock charges fees through the `AssetConversionAdapter`.
+		assert_eq!(
+			AssetTxPayment::asset_fee_capabilities(),
+			AssetFeeCapabilities {
+				multi_asset_fallback: false,
+				exact_output_swaps: true,
+				sponsorship: false,
+			}
+		);
+	});
+}
+
+#[test]
+fn asset_fee_falls_back_to_native_once_max_fee_swaps_are_reached() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			MaxFeeSwapsPerBlock::set(2);
+
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			let len = 10;
+			let tx_weight = 5;
+
+			setup_lp(asset_id, balance_factor);
+
+			let fee_in_native = base_weight + tx_weight + len as u64;
+			let pay_in_asset = || {
+				let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+					.validate_and_prepare(
+						Some(caller).into(),
+						CALL,
+						&info_from_weight(WEIGHT_5),
+						len,
+					)
+					.unwrap();
+				let paid_in_asset = pre.3.is_some();
+				assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+					pre,
+					&info_from_weight(WEIGHT_5),
+					&default_post_info(),
+					len,
+					&Ok(()),
+					&()
+				));
+				paid_in_asset
+			};
+
+			// the first two fees are swapped from the asset
+			for swaps in 1..=2 {
+				let asset_balance = Assets::balance(asset_id, caller);
+				assert!(pay_in_asset());
+				assert!(Assets::balance(asset_id, caller) < asset_balance);
+				assert_eq!(Balances::free_balance(caller), 10 * balance_factor);
+				assert_eq!(AssetTxPayment::fee_swaps_in_block(), swaps);
+			}
+
+			// the cap is reached, so the next fee is charged in native
+			let asset_balance = Assets::balance(asset_id, caller);
+			assert!(!pay_in_asset());
+			assert_eq!(Assets::balance(asset_id, caller), asset_balance);
+			assert_eq!(Balances::free_balance(caller), 10 * balance_factor - fee_in_native);
+			assert_eq!(AssetTxPayment::fee_swaps_in_block(), 2);
+
+			// the count is reset in the next block
+			AssetTxPayment::on_initialize(2);
+			assert_eq!(AssetTxPayment::fee_swaps_in_block(), 0);
+			assert!(pay_in_asset());
+			assert!(Assets::balance(asset_id, caller) < asset_balance);
+			assert_eq!(Balances::free_balance(caller), 10 * balance_factor - fee_in_native);
+		});
+}
+
+#[test]
+fn asset_fee_only_for_allowed_calls() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			let len = 10;
+			let tx_weight = 5;
+
+			setup_lp(asset_id, balance_factor);
+
+			let fee_in_native = base_weight + tx_weight + len as u64;
+
+			// an operational call is charged in native, ignoring the selected asset
+			let operational_call = RuntimeCall::System(system::Call::set_heap_pages { pages: 64 });
+			let operational_info = DispatchInfo {
+				weight: WEIGHT_5,
+				class: DispatchClass::Operational,
+				..Default::default()
+			};
+			let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(
+					Some(caller).into(),
+					&operational_call,
+					&operational_info,
+					len,
+				)
+				.unwrap();
+			assert_eq!(Balances::free_balance(caller), 10 * balance_factor - fee_in_native);
+			assert_eq!(Assets::balance(asset_id, caller), balance);
+
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+				pre,
+				&operational_info,
+				&default_post_info(),
+				len,
+				&Ok(()),
+				&()
+			));
+			assert_eq!(Balances::free_balance(caller), 10 * balance_factor - fee_in_native);
+			assert_eq!(Assets::balance(asset_id, caller), balance);
+			assert_eq!(CollectedAssetFees::<Runtime>::get(asset_id), 0);
+
+			// a normal call is charged in the selected asset
+			let fee_in_asset = AssetConversion::quote_price_tokens_for_exact_tokens(
+				NativeOrWithId::WithId(asset_id),
+				NativeOrWithId::Native,
+				fee_in_native,
+				true,
+			)
+			.unwrap();
+			let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len)
+				.unwrap();
+			assert_eq!(Balances::free_balance(caller), 10 * balance_factor - fee_in_native);
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+				pre,
+				&info_from_weight(WEIGHT_5),
+				&default_post_info(),
+				len,
+				&Ok(()),
+				&()
+			));
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+		});
+}
+
+#[test]
+fn transaction_payment_in_asset_charges_minimum_fee() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			let len = 10;
+			let tx_weight = 5;
+
+			setup_lp(asset_id, balance_factor);
+
+			let fee_in_native = base_weight + tx_weight + len as u64;
+			let fee_in_asset = AssetConversion::quote_price_tokens_for_exact_tokens(
+				NativeOrWithId::WithId(asset_id),
+				NativeOrWithId::Native,
+				fee_in_native,
+				true,
+			)
+			.unwrap();
+			assert_eq!(fee_in_asset, 201);
+
+			let min_asset_fee = 250;
+			MinAssetFee::set(min_asset_fee);
+			OnBelowMinAssetFee::set(MinAssetFeePolicy::ChargeMinimum);
+			let total_supply = Assets::total_supply(asset_id);
+
+			let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len)
+				.unwrap();
+			// the minimum is charged, the part above the natural fee is burned.
+			assert_eq!(Assets::balance(asset_id, caller), balance - min_asset_fee);
+			assert_eq!(
+				Assets::total_supply(asset_id),
+				total_supply - (min_asset_fee - fee_in_asset)
+			);
+
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+				pre,
+				&info_from_weight(WEIGHT_5),
+				&default_post_info(),
+				len,
+				&Ok(()),
+				&()
+			));
+
+			assert_eq!(Assets::balance(asset_id, caller), balance - min_asset_fee);
+			assert_eq!(FeeUnbalancedAmount::get(), fee_in_native);
+		});
+}
+
+#[test]
+fn transaction_payment_in_asset_rejects_fee_below_minimum() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			setup_lp(asset_id, balance_factor);
+
+			// the natural fee of the call is 201 in the asset.
+			MinAssetFee::set(250);
+			OnBelowMinAssetFee::set(MinAssetFeePolicy::Reject);
+
+			let len = 10;
+			let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len);
+
+			assert_eq!(
+				pre.err(),
+				Some(TransactionValidityError::from(AssetPaymentError::FeeBelowMinimum))
+			);
+		});
+}
+
 #[test]
 fn transaction_payment_in_asset_fails_if_no_pool_for_that_asset() {
 	let base_weight = 5;
@@ -290,16 +633,516 @@ fn transaction_payment_in_asset_fails_if_no_pool_for_that_asset() {
 			let caller = 1;
 			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
 			let balance = 1000;
-
+
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+			assert_eq!(Assets::balance(asset_id, caller), balance);
+
+			let len = 10;
+			let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len);
+
+			// As there is no pool in the dex set up for this asset, conversion should fail.
+			assert!(pre.is_err());
+		});
+}
+
+#[test]
+fn transaction_payment_in_asset_fails_if_balance_is_frozen() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+			assert_eq!(Assets::balance(asset_id, caller), balance);
+
+			setup_lp(asset_id, balance_factor);
+
+			// the account holds far more than the fee, but all of it is frozen.
+			set_frozen_balance(asset_id, caller, balance);
+
+			let len = 10;
+			let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len);
+
+			assert_eq!(
+				pre.err(),
+				Some(TransactionValidityError::from(AssetPaymentError::AssetBalanceFrozen))
+			);
+			assert_eq!(Assets::balance(asset_id, caller), balance);
+		});
+}
+
+#[test]
+fn missing_native_balance_is_reported_on_the_native_path() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// the caller holds the asset, but no native currency.
+			let caller = 7;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+			assert_eq!(Balances::free_balance(caller), 0);
+
+			setup_lp(asset_id, balance_factor);
+
+			// the asset can't pay for an operational call, which is charged in native.
+			let operational_call = RuntimeCall::System(system::Call::set_heap_pages { pages: 64 });
+			let operational_info = DispatchInfo {
+				weight: WEIGHT_5,
+				class: DispatchClass::Operational,
+				..Default::default()
+			};
+			let len = 10;
+			let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(
+					Some(caller).into(),
+					&operational_call,
+					&operational_info,
+					len,
+				);
+
+			assert_eq!(
+				pre.err(),
+				Some(TransactionValidityError::from(InvalidTransaction::Payment))
+			);
+			assert_eq!(Assets::balance(asset_id, caller), balance);
+		});
+}
+
+#[test]
+fn insufficient_native_balance_is_rejected_in_validate() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// the caller holds no native currency.
+			let caller = 7;
+			assert_eq!(Balances::free_balance(caller), 0);
+
+			let len = 10;
+			assert_eq!(
+				ChargeAssetTxPayment::<Runtime>::from(0, None)
+					.validate_only(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len)
+					.map(|_| ()),
+				Err(TransactionValidityError::from(InvalidTransaction::Payment))
+			);
+
+			// a call that pays no fee is still valid.
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::from(0, None).validate_only(
+				Some(caller).into(),
+				CALL,
+				&info_from_pays(Pays::No),
+				len
+			));
+		});
+}
+
+#[test]
+fn missing_asset_balance_is_reported_on_the_asset_path() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			setup_lp(asset_id, balance_factor);
+
+			// the caller holds native currency, but none of the asset.
+			let caller = 2;
+			assert_eq!(Assets::balance(asset_id, caller), 0);
+
+			let len = 10;
+			let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len);
+
+			assert_eq!(
+				pre.err(),
+				Some(TransactionValidityError::from(InvalidTransaction::Payment))
+			);
+			assert_eq!(Balances::free_balance(caller), 20 * balance_factor);
+		});
+}
+
+#[test]
+fn transaction_payment_in_asset_possible_if_balance_is_partially_frozen() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+			assert_eq!(Assets::balance(asset_id, caller), balance);
+
+			setup_lp(asset_id, balance_factor);
+
+			// half of the balance is frozen, the unfrozen half still covers the fee.
+			set_frozen_balance(asset_id, caller, balance / 2);
+
+			let len = 10;
+			let tx_weight = 5;
+			let fee_in_native = base_weight + tx_weight + len as u64;
+			let fee_in_asset = AssetConversion::quote_price_tokens_for_exact_tokens(
+				NativeOrWithId::WithId(asset_id),
+				NativeOrWithId::Native,
+				fee_in_native,
+				true,
+			)
+			.unwrap();
+			assert!(fee_in_asset < balance / 2);
+
+			let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len)
+				.unwrap();
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+				pre,
+				&info_from_weight(WEIGHT_5),
+				&default_post_info(),
+				len,
+				&Ok(()),
+				&()
+			));
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+			assert_eq!(FeeUnbalancedAmount::get(), fee_in_native);
+		});
+}
+
+fn high_slippage_fee_events() -> Vec<Event<Runtime>> {
+	System::events()
+		.into_iter()
+		.filter_map(|r| match r.event {
+			RuntimeEvent::AssetTxPayment(e @ Event::HighSlippageFee { .. }) => Some(e),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn transaction_payment_in_asset_warns_about_high_slippage() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			// a thin pool, the fee of 20 moves the price noticeably.
+			setup_lp(asset_id, 1);
+
+			let len = 10;
+			let fee_in_native = base_weight + 5 + len as u64;
+			let spot_quote = AssetConversion::quote_price_tokens_for_exact_tokens(
+				NativeOrWithId::WithId(asset_id),
+				NativeOrWithId::Native,
+				fee_in_native,
+				false,
+			);
+			assert_eq!(spot_quote, Some(200));
+
+			let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len)
+				.unwrap();
+
+			// the fee is charged, but the slippage is reported.
+			let fee_in_asset = 205;
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+			assert_eq!(
+				high_slippage_fee_events(),
+				vec![Event::HighSlippageFee {
+					who: caller,
+					asset_id,
+					slippage: Permill::from_rational(fee_in_asset - 200, 200),
+				}]
+			);
+
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+				pre,
+				&info_from_weight(WEIGHT_5),
+				&default_post_info(),
+				len,
+				&Ok(()),
+				&()
+			));
+		});
+}
+
+#[test]
+fn transaction_payment_in_asset_does_not_warn_about_low_slippage() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			// a deep pool, the slippage of the fee swap stays below the threshold.
+			setup_lp(asset_id, balance_factor);
+
+			let len = 10;
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len));
+
+			assert_eq!(Assets::balance(asset_id, caller), balance - 201);
+			assert_eq!(high_slippage_fee_events(), vec![]);
+		});
+}
+
+#[test]
+fn transaction_payment_in_asset_respects_max_asset_fee() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
 			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			// a thin pool, the fee of 20 costs 205 of the asset at a spot price of 200.
+			setup_lp(asset_id, 1);
+			let len = 10;
+
+			let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.with_max_asset_fee(Some(204))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len);
+			assert_eq!(
+				pre.err(),
+				Some(TransactionValidityError::from(AssetPaymentError::FeeAboveMaximum))
+			);
 			assert_eq!(Assets::balance(asset_id, caller), balance);
 
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.with_max_asset_fee(Some(205))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len));
+			assert_eq!(Assets::balance(asset_id, caller), balance - 205);
+		});
+}
+
+#[test]
+fn transaction_payment_in_asset_respects_max_slippage() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			// a thin pool, the fee of 20 costs 205 of the asset at a spot price of 200.
+			setup_lp(asset_id, 1);
 			let len = 10;
+
+			// the slippage of the fee swap is 2.5%.
 			let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.with_max_slippage(Some(Permill::from_percent(2)))
 				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len);
+			assert_eq!(
+				pre.err(),
+				Some(TransactionValidityError::from(AssetPaymentError::SlippageTooHigh))
+			);
+			assert_eq!(Assets::balance(asset_id, caller), balance);
 
-			// As there is no pool in the dex set up for this asset, conversion should fail.
-			assert!(pre.is_err());
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.with_max_slippage(Some(Permill::from_percent(3)))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len));
+			assert_eq!(Assets::balance(asset_id, caller), balance - 205);
+		});
+}
+
+#[test]
+fn transaction_payment_in_asset_respects_max_asset_fee_and_max_slippage() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+
+			// mint into the caller account
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			// a thin pool, the fee of 20 costs 205 of the asset at a spot price of 200.
+			setup_lp(asset_id, 1);
+			let len = 10;
+
+			let charge = |max_asset_fee, max_slippage| {
+				ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+					.with_max_asset_fee(Some(max_asset_fee))
+					.with_max_slippage(Some(Permill::from_percent(max_slippage)))
+					.validate_and_prepare(
+						Some(caller).into(),
+						CALL,
+						&info_from_weight(WEIGHT_5),
+						len,
+					)
+					.err()
+			};
+
+			// each bound is enforced even if the other one is met.
+			assert_eq!(
+				charge(205, 2),
+				Some(TransactionValidityError::from(AssetPaymentError::SlippageTooHigh))
+			);
+			assert_eq!(
+				charge(204, 3),
+				Some(TransactionValidityError::from(AssetPaymentError::FeeAboveMaximum))
+			);
+			assert_eq!(Assets::balance(asset_id, caller), balance);
+
+			assert_eq!(charge(205, 3), None);
+			assert_eq!(Assets::balance(asset_id, caller), balance - 205);
 		});
 }
 
@@ -455,6 +1298,156 @@ fn asset_transaction_payment_with_tip_and_refund() {
 				balance - fee_in_asset + expected_token_refund
 			);
 			assert_eq!(Balances::free_balance(caller), 20 * balance_factor);
+
+			// only the fee net of the refund is accounted for
+			assert_eq!(
+				Pallet::<Runtime>::collected_asset_fees(asset_id),
+				fee_in_asset - expected_token_refund
+			);
+		});
+}
+
+#[test]
+fn asset_transaction_payment_refunds_unused_weight_of_failed_call() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance,
+			));
+
+			setup_lp(asset_id, balance_factor);
+
+			// mint into the caller account
+			let caller = 2;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 10000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			let weight = 100;
+			let len = 10;
+			let fee_in_native = base_weight + weight + len as u64;
+			let fee_in_asset = AssetConversion::quote_price_tokens_for_exact_tokens(
+				NativeOrWithId::WithId(asset_id),
+				NativeOrWithId::Native,
+				fee_in_native,
+				true,
+			)
+			.unwrap();
+
+			let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_100), len)
+				.unwrap();
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+
+			// the call fails after using only half of its weight
+			let final_weight = 50;
+			let expected_fee = fee_in_native - final_weight;
+			let expected_token_refund = AssetConversion::quote_price_exact_tokens_for_tokens(
+				NativeOrWithId::Native,
+				NativeOrWithId::WithId(asset_id),
+				fee_in_native - expected_fee,
+				true,
+			)
+			.unwrap();
+			assert!(expected_token_refund > 0);
+
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+				pre,
+				&info_from_weight(WEIGHT_100),
+				&post_info_from_weight(WEIGHT_50),
+				len,
+				&Err(DispatchError::BadOrigin),
+				&()
+			));
+
+			assert_eq!(FeeUnbalancedAmount::get(), expected_fee);
+			// caller should get refunded regardless of the failure
+			assert_eq!(
+				Assets::balance(asset_id, caller),
+				balance - fee_in_asset + expected_token_refund
+			);
+		});
+}
+
+#[test]
+fn asset_fee_paid_event_splits_fee_and_tip() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance,
+			));
+
+			setup_lp(asset_id, balance_factor);
+
+			// mint into the caller account
+			let caller = 2;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 10000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			let tip = 50;
+			let len = 10;
+			let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(tip, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_100), len)
+				.unwrap();
+
+			// the corrected fee is only known after dispatch
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+				pre,
+				&info_from_weight(WEIGHT_100),
+				&post_info_from_weight(WEIGHT_50),
+				len,
+				&Ok(()),
+				&()
+			));
+
+			let actual_fee_in_native = base_weight + 50 + len as u64 + tip;
+			let paid_in_asset = Pallet::<Runtime>::collected_asset_fees(asset_id);
+			let expected_tip_in_asset = paid_in_asset * tip / actual_fee_in_native;
+			assert!(expected_tip_in_asset > 0);
+
+			let events: Vec<_> = System::events()
+				.into_iter()
+				.filter_map(|r| match r.event {
+					RuntimeEvent::AssetTxPayment(Event::AssetTxFeePaid {
+						actual_fee,
+						fee_asset_amount,
+						tip_asset_amount,
+						..
+					}) => Some((actual_fee, fee_asset_amount, tip_asset_amount)),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(
+				events,
+				vec![(paid_in_asset, paid_in_asset - expected_tip_in_asset, expected_tip_in_asset)]
+			);
 		});
 }
 
@@ -535,6 +1528,108 @@ fn payment_from_account_with_only_assets() {
 		});
 }
 
+#[test]
+fn payment_in_asset_is_routed_to_a_non_native_fee_asset() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// the fee is paid in `asset_id`, and charged in `fee_asset_id`.
+			let asset_id = 1;
+			let fee_asset_id = 2;
+			let min_balance = 2;
+			for id in [asset_id, fee_asset_id] {
+				assert_ok!(Assets::force_create(
+					RuntimeOrigin::root(),
+					id.into(),
+					42,   /* owner */
+					true, /* is_sufficient */
+					min_balance,
+				));
+			}
+
+			// a pool between both assets, without any native currency involved.
+			let lp_provider = 5;
+			let lp_provider_account = <Runtime as system::Config>::Lookup::unlookup(lp_provider);
+			for id in [asset_id, fee_asset_id] {
+				assert_ok!(Assets::mint_into(
+					id.into(),
+					&lp_provider_account,
+					10_000 * balance_factor + min_balance
+				));
+			}
+			let token_1 = NativeOrWithId::WithId(asset_id);
+			let token_2 = NativeOrWithId::WithId(fee_asset_id);
+			assert_ok!(AssetConversion::create_pool(
+				RuntimeOrigin::signed(lp_provider),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone())
+			));
+			assert_ok!(AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(lp_provider),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				10_000 * balance_factor, // 1 desired
+				1_000 * balance_factor,  // 2 desired
+				1,                       // 1 min
+				1,                       // 2 min
+				lp_provider_account,
+			));
+			FeeAsset::set(token_2.clone());
+
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			let weight = 5;
+			let len = 10;
+			let fee = base_weight + weight + len as u64;
+			// the caller holds none of the fee asset, so its minimum balance is swapped for too.
+			let fee_in_asset = AssetConversion::quote_price_tokens_for_exact_tokens(
+				token_1.clone(),
+				token_2.clone(),
+				fee + min_balance,
+				true,
+			)
+			.unwrap();
+
+			let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+				.validate_and_prepare(Some(caller).into(), CALL, &info_from_weight(WEIGHT_5), len)
+				.unwrap();
+			// the native currency is not touched, the fee was collected in the fee asset.
+			assert_eq!(Balances::free_balance(caller), 10 * balance_factor);
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+			assert_eq!(Assets::balance(fee_asset_id, caller), min_balance);
+
+			let refund = AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_2,
+				token_1,
+				min_balance,
+				true,
+			)
+			.unwrap();
+
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+				pre,
+				&info_from_weight(WEIGHT_5),
+				&default_post_info(),
+				len,
+				&Ok(()),
+				&()
+			));
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset + refund);
+			assert_eq!(Assets::balance(fee_asset_id, caller), 0);
+			assert_eq!(Balances::free_balance(caller), 10 * balance_factor);
+
+			assert_eq!(TipUnbalancedAmount::get(), 0);
+			assert_eq!(FeeUnbalancedAmount::get(), fee);
+		});
+}
+
 #[test]
 fn converted_fee_is_never_zero_if_input_fee_is_not() {
 	let base_weight = 1;
@@ -92,14 +92,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Authorship::Author` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `System::Digest` (r:1 w:0)
 	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetConversionTxPayment::CollectedAssetFees` (r:1 w:1)
+	/// Proof: `AssetConversionTxPayment::CollectedAssetFees` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversionTxPayment::FeeSwapsInBlock` (r:1 w:1)
+	/// Proof: `AssetConversionTxPayment::FeeSwapsInBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn charge_asset_tx_payment_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `888`
 		//  Estimated: `6208`
 		// Minimum execution time: 112_432_000 picoseconds.
 		Weight::from_parts(113_992_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 }
 
@@ -138,13 +142,17 @@ impl WeightInfo for () {
 	/// Proof: `Authorship::Author` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `System::Digest` (r:1 w:0)
 	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetConversionTxPayment::CollectedAssetFees` (r:1 w:1)
+	/// Proof: `AssetConversionTxPayment::CollectedAssetFees` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversionTxPayment::FeeSwapsInBlock` (r:1 w:1)
+	/// Proof: `AssetConversionTxPayment::FeeSwapsInBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn charge_asset_tx_payment_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `888`
 		//  Estimated: `6208`
 		// Minimum execution time: 112_432_000 picoseconds.
 		Weight::from_parts(113_992_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 }
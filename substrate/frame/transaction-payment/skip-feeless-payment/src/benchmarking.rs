@@ -0,0 +1,81 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for Skip Feeless Payment Pallet's transaction extension
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::v2::*;
+use frame_support::dispatch::{DispatchInfo, PostDispatchInfo};
+use frame_system::{pallet_prelude::OriginFor, EventRecord, RawOrigin};
+use sp_runtime::traits::{DispatchTransaction, Dispatchable};
+use sp_std::vec;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	let events = frame_system::Pallet::<T>::events();
+	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+	// compare to the last event record
+	let EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+#[benchmarks(where
+	T: Send + Sync,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
+		+ CheckIfFeeless<Origin = OriginFor<T>>,
+)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn feeless_call_skipped() -> Result<(), BenchmarkError> {
+		let (call, origin) =
+			T::BenchmarkHelper::feeless_call().ok_or(BenchmarkError::Weightless)?;
+		let caller = origin.caller().clone();
+		// The wrapped extension is a no-op, so only the overhead of the check is measured.
+		let ext = SkipCheckIfFeeless::<T, ()>::from(());
+		let info = DispatchInfo::default();
+		let post_info = PostDispatchInfo::default();
+
+		#[block]
+		{
+			assert!(ext.test_run(origin, &call, &info, 0, |_| Ok(post_info)).unwrap().is_ok());
+		}
+
+		assert_last_event::<T>(Event::<T>::FeeSkipped { origin: caller }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fee_charged_call_applied() {
+		let caller: T::AccountId = whitelisted_caller();
+		let call = T::RuntimeCall::from(frame_system::Call::remark { remark: vec![] });
+		let ext = SkipCheckIfFeeless::<T, ()>::from(());
+		let info = DispatchInfo::default();
+		let post_info = PostDispatchInfo::default();
+
+		#[block]
+		{
+			assert!(ext
+				.test_run(RawOrigin::Signed(caller).into(), &call, &info, 0, |_| Ok(post_info))
+				.unwrap()
+				.is_ok());
+		}
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
+}
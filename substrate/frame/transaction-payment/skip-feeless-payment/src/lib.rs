@@ -33,6 +33,9 @@
 //! in your `construct_runtime` macro and include this pallet's
 //! [`TransactionExtension`] ([`SkipCheckIfFeeless`]) that would accept the existing one as an
 //! argument.
+//!
+//! The overhead of checking whether a dispatchable is feeless is weighted by the pallet's
+//! [`Config::WeightInfo`] and added on top of the weight of the wrapped extension.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -55,7 +58,13 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -65,6 +74,28 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for the checks of [`SkipCheckIfFeeless`].
+		type WeightInfo: WeightInfo;
+
+		/// Helper for benchmarking.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::RuntimeCall, Self::RuntimeOrigin>;
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	/// Helper trait to benchmark the [`SkipCheckIfFeeless`] transaction extension.
+	pub trait BenchmarkHelper<RuntimeCall, RuntimeOrigin> {
+		/// Returns a call along with an origin for which the call is feeless, if the runtime has
+		/// any. Without one the feeless branch cannot be benchmarked.
+		fn feeless_call() -> Option<(RuntimeCall, RuntimeOrigin)>;
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<RuntimeCall, RuntimeOrigin> BenchmarkHelper<RuntimeCall, RuntimeOrigin> for () {
+		fn feeless_call() -> Option<(RuntimeCall, RuntimeOrigin)> {
+			None
+		}
 	}
 
 	#[pallet::pallet]
@@ -130,7 +161,11 @@ impl<T: Config + Send + Sync, S: TransactionExtensionBase> TransactionExtensionB
 	}
 
 	fn weight(&self) -> frame_support::weights::Weight {
-		self.0.weight()
+		// Whether the call is feeless is only known once it is checked, so account for the more
+		// expensive branch.
+		self.0.weight().saturating_add(
+			T::WeightInfo::feeless_call_skipped().max(T::WeightInfo::fee_charged_call_applied()),
+		)
 	}
 }
 
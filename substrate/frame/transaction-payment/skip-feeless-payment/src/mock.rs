@@ -21,6 +21,7 @@ use frame_system as system;
 use sp_runtime::{
 	impl_tx_ext_default,
 	traits::{OriginOf, TransactionExtension},
+	BuildStorage,
 };
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -32,6 +33,19 @@ impl frame_system::Config for Runtime {
 
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = FeelessCallHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct FeelessCallHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<RuntimeCall, RuntimeOrigin> for FeelessCallHelper {
+	fn feeless_call() -> Option<(RuntimeCall, RuntimeOrigin)> {
+		Some((RuntimeCall::DummyPallet(pallet_dummy::Call::aux { data: 0 }), Some(0).into()))
+	}
 }
 
 parameter_types! {
@@ -94,3 +108,13 @@ frame_support::construct_runtime!(
 		DummyPallet: pallet_dummy,
 	}
 );
+
+pub fn new_test_ext() -> frame_support::sp_io::TestExternalities {
+	let mut ext: frame_support::sp_io::TestExternalities =
+		frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -32,3 +32,13 @@ fn skip_feeless_payment_works() {
 		.unwrap();
 	assert_eq!(PreDispatchCount::get(), 1);
 }
+
+#[test]
+fn skip_feeless_payment_weight_includes_the_check() {
+	let check_weight = <() as WeightInfo>::feeless_call_skipped()
+		.max(<() as WeightInfo>::fee_charged_call_applied());
+	assert_eq!(
+		SkipCheckIfFeeless::<Runtime, DummyExtension>::from(DummyExtension).weight(),
+		DummyExtension.weight().saturating_add(check_weight)
+	);
+}
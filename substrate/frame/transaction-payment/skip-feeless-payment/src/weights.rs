@@ -0,0 +1,74 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for `pallet_skip_feeless_payment`
+//!
+//! THESE WEIGHTS WERE NOT GENERATED BY THE BENCHMARK CLI. They are estimates until the file is
+//! regenerated with the command below.
+
+// Command to generate:
+// ./target/production/substrate-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_skip_feeless_payment
+// --no-storage-info
+// --no-median-slopes
+// --no-min-squares
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./substrate/frame/transaction-payment/skip-feeless-payment/src/weights.rs
+// --header=./substrate/HEADER-APACHE2
+// --template=./substrate/.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_skip_feeless_payment`.
+pub trait WeightInfo {
+	fn feeless_call_skipped() -> Weight;
+	fn fee_charged_call_applied() -> Weight;
+}
+
+/// Weights for `pallet_skip_feeless_payment` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn feeless_call_skipped() -> Weight {
+		Weight::from_parts(3_302_000, 0)
+	}
+	fn fee_charged_call_applied() -> Weight {
+		Weight::from_parts(671_000, 0)
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn feeless_call_skipped() -> Weight {
+		Weight::from_parts(3_302_000, 0)
+	}
+	fn fee_charged_call_applied() -> Weight {
+		Weight::from_parts(671_000, 0)
+	}
+}